@@ -2,10 +2,11 @@ use bencher::{benchmark_group, benchmark_main, Bencher};
 use rand::Rng;
 use tars::indicators::{
     AverageTrueRange, BollingerBands, ChandelierExit, CommodityChannelIndex, EfficiencyRatio,
-    ExponentialMovingAverage, FastStochastic, KeltnerChannel, Maximum, MeanAbsoluteDeviation,
-    Minimum, MoneyFlowIndex, MovingAverageConvergenceDivergence, OnBalanceVolume,
-    PercentagePriceOscillator, RateOfChange, RelativeStrengthIndex, SimpleMovingAverage,
-    SlowStochastic, StandardDeviation, TrueRange, WindowedExponentialMovingAverage
+    ExponentialMovingAverage, FastStochastic, GopalakrishnanRangeIndex, KeltnerChannel,
+    LinearRegression, MarketFacilitationIndex, Maximum, MeanAbsoluteDeviation, Median, Minimum,
+    MoneyFlowIndex, MovingAverageConvergenceDivergence, OnBalanceVolume, PercentagePriceOscillator,
+    PrettyGoodOscillator, RateOfChange, RelativeStrengthIndex, SimpleMovingAverage, SlowStochastic,
+    StandardDeviation, TillsonT3, TrueRange, WindowedExponentialMovingAverage,
 };
 
 use tars::{DataItema, Nexta};
@@ -55,7 +56,7 @@ macro_rules! bench_indicators {
 bench_indicators!(
     AverageTrueRange,
     ExponentialMovingAverage,
-    WWMA,
+    WindowedExponentialMovingAverage,
     MeanAbsoluteDeviation,
     BollingerBands,
     ChandelierExit,
@@ -63,6 +64,7 @@ bench_indicators!(
     FastStochastic,
     KeltnerChannel,
     Maximum,
+    Median,
     Minimum,
     MoneyFlowIndex,
     MovingAverageConvergenceDivergence,
@@ -74,5 +76,10 @@ bench_indicators!(
     SimpleMovingAverage,
     SlowStochastic,
     StandardDeviation,
-    TrueRange
+    TrueRange,
+    PrettyGoodOscillator,
+    GopalakrishnanRangeIndex,
+    TillsonT3,
+    MarketFacilitationIndex,
+    LinearRegression
 );
@@ -0,0 +1,4 @@
+//! Transformations of a price stream into alternative bar types.
+
+mod renko;
+pub use self::renko::{RenkoBrick, RenkoBrickSize, RenkoBuilder};
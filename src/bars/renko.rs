@@ -0,0 +1,326 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::AverageTrueRange as Atr;
+use crate::{Close, High, Low, Nexta, Open, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Renko bar builder.
+///
+/// Converts a stream of prices into Renko bricks. A new brick is formed every time the price moves
+/// by at least the brick size beyond the previous brick, so every input produces zero or more
+/// bricks. Continuing the trend requires a move of one brick size beyond the top (bottom) of the
+/// last brick, while a reversal requires a move of one brick size beyond its bottom (top), i.e. two
+/// brick sizes from its close.
+///
+/// The first input only sets the reference price.
+///
+/// # Brick size
+///
+/// * [Fixed](enum.RenkoBrickSize.html#variant.Fixed) - constant brick size.
+/// * [Atr](enum.RenkoBrickSize.html#variant.Atr) - the current value of
+///   [ATR](../indicators/struct.AverageTrueRange.html) with the given period. Until _period_ inputs
+///   are received no bricks are formed and the reference price follows the input.
+///
+/// # Example
+///
+/// ```
+/// use tars::bars::{RenkoBrickSize, RenkoBuilder};
+/// use tars::Nexta;
+///
+/// let mut renko = RenkoBuilder::new(RenkoBrickSize::Fixed(1.0)).unwrap();
+///
+/// assert!(renko.nexta(10.0).is_empty());
+///
+/// let bricks = renko.nexta(12.5);
+/// assert_eq!(bricks.len(), 2);
+/// assert_eq!(bricks[1].open, 11.0);
+/// assert_eq!(bricks[1].close, 12.0);
+/// assert!(bricks[1].is_up());
+/// ```
+///
+/// # Links
+///
+/// * [Renko chart, Investopedia](https://www.investopedia.com/terms/r/renkochart.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RenkoBuilder {
+    brick_size: RenkoBrickSize,
+    atr: Option<Atr>,
+    count: usize,
+    reference: Option<f64>,
+    last: Option<RenkoBrick>,
+}
+
+/// Brick size of [RenkoBuilder](struct.RenkoBuilder.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RenkoBrickSize {
+    /// Constant brick size (number greater than 0).
+    Fixed(f64),
+    /// Brick size equal to ATR of the given period (integer greater than 0).
+    Atr(usize),
+}
+
+/// A single Renko brick.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenkoBrick {
+    pub open: f64,
+    pub close: f64,
+}
+
+impl RenkoBrick {
+    pub fn is_up(&self) -> bool {
+        self.close > self.open
+    }
+}
+
+impl RenkoBuilder {
+    pub fn new(brick_size: RenkoBrickSize) -> Result<Self> {
+        let atr = match brick_size {
+            RenkoBrickSize::Fixed(size) => {
+                if !(size.is_finite() && size > 0.0) {
+                    return Err(TaError::InvalidParameter);
+                }
+                None
+            }
+            RenkoBrickSize::Atr(period) => Some(Atr::new(period)?),
+        };
+
+        Ok(Self {
+            brick_size,
+            atr,
+            count: 0,
+            reference: None,
+            last: None,
+        })
+    }
+
+    pub fn brick_size(&self) -> RenkoBrickSize {
+        self.brick_size
+    }
+
+    /// Returns the last formed brick.
+    pub fn last_brick(&self) -> Option<&RenkoBrick> {
+        self.last.as_ref()
+    }
+
+    fn build(&mut self, price: f64, size: Option<f64>) -> Vec<RenkoBrick> {
+        let mut bricks = Vec::new();
+
+        let (size, reference) = match (size, self.reference) {
+            (Some(size), Some(reference)) if size > 0.0 => (size, reference),
+            _ => {
+                if self.last.is_none() {
+                    self.reference = Some(price);
+                }
+                return bricks;
+            }
+        };
+
+        loop {
+            let (top, bottom) = match &self.last {
+                Some(brick) => (brick.high(), brick.low()),
+                None => (reference, reference),
+            };
+
+            let brick = if price >= top + size {
+                RenkoBrick {
+                    open: top,
+                    close: top + size,
+                }
+            } else if price <= bottom - size {
+                RenkoBrick {
+                    open: bottom,
+                    close: bottom - size,
+                }
+            } else {
+                break;
+            };
+
+            bricks.push(brick.clone());
+            self.last = Some(brick);
+        }
+
+        bricks
+    }
+
+    fn fixed_size(&self) -> Option<f64> {
+        match self.brick_size {
+            RenkoBrickSize::Fixed(size) => Some(size),
+            RenkoBrickSize::Atr(_) => None,
+        }
+    }
+
+    fn atr_size(&mut self, value: f64) -> Option<f64> {
+        self.count += 1;
+        match self.brick_size {
+            RenkoBrickSize::Atr(period) if self.count >= period => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl Nexta<f64> for RenkoBuilder {
+    type Output = Vec<RenkoBrick>;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let size = match self.atr.as_mut().map(|atr| atr.nexta(input)) {
+            Some(value) => self.atr_size(value),
+            None => self.fixed_size(),
+        };
+        self.build(input, size)
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for RenkoBuilder {
+    type Output = Vec<RenkoBrick>;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let size = match self.atr.as_mut().map(|atr| atr.nexta(input)) {
+            Some(value) => self.atr_size(value),
+            None => self.fixed_size(),
+        };
+        self.build(input.close(), size)
+    }
+}
+
+impl Reset for RenkoBuilder {
+    fn reset(&mut self) {
+        if let Some(atr) = self.atr.as_mut() {
+            atr.reset();
+        }
+        self.count = 0;
+        self.reference = None;
+        self.last = None;
+    }
+}
+
+impl fmt::Display for RenkoBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.brick_size {
+            RenkoBrickSize::Fixed(size) => write!(f, "RENKO({})", size),
+            RenkoBrickSize::Atr(period) => write!(f, "RENKO(ATR({}))", period),
+        }
+    }
+}
+
+impl Open for RenkoBrick {
+    fn open(&self) -> f64 {
+        self.open
+    }
+}
+
+impl High for RenkoBrick {
+    fn high(&self) -> f64 {
+        self.open.max(self.close)
+    }
+}
+
+impl Low for RenkoBrick {
+    fn low(&self) -> f64 {
+        self.open.min(self.close)
+    }
+}
+
+impl Close for RenkoBrick {
+    fn close(&self) -> f64 {
+        self.close
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn pairs(bricks: Vec<RenkoBrick>) -> Vec<(f64, f64)> {
+        bricks
+            .into_iter()
+            .map(|b| (round(b.open), round(b.close)))
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RenkoBuilder::new(RenkoBrickSize::Fixed(0.0)).is_err());
+        assert!(RenkoBuilder::new(RenkoBrickSize::Fixed(-1.0)).is_err());
+        assert!(RenkoBuilder::new(RenkoBrickSize::Fixed(f64::NAN)).is_err());
+        assert!(RenkoBuilder::new(RenkoBrickSize::Atr(0)).is_err());
+        assert!(RenkoBuilder::new(RenkoBrickSize::Fixed(0.5)).is_ok());
+        assert!(RenkoBuilder::new(RenkoBrickSize::Atr(14)).is_ok());
+    }
+
+    #[test]
+    fn test_next_fixed() {
+        let mut renko = RenkoBuilder::new(RenkoBrickSize::Fixed(1.0)).unwrap();
+
+        assert_eq!(pairs(renko.nexta(10.0)), vec![]);
+        assert_eq!(pairs(renko.nexta(10.5)), vec![]);
+        assert_eq!(pairs(renko.nexta(11.2)), vec![(10.0, 11.0)]);
+        assert_eq!(pairs(renko.nexta(13.1)), vec![(11.0, 12.0), (12.0, 13.0)]);
+        assert_eq!(pairs(renko.nexta(12.5)), vec![]);
+        // a reversal needs a move of one brick below the bottom of the last brick
+        assert_eq!(pairs(renko.nexta(11.5)), vec![]);
+        assert_eq!(pairs(renko.nexta(10.9)), vec![(12.0, 11.0)]);
+        assert_eq!(pairs(renko.nexta(9.0)), vec![(11.0, 10.0), (10.0, 9.0)]);
+        assert_eq!(renko.last_brick().map(|b| b.is_up()), Some(false));
+    }
+
+    #[test]
+    fn test_next_atr() {
+        let mut renko = RenkoBuilder::new(RenkoBrickSize::Atr(2)).unwrap();
+
+        assert_eq!(pairs(renko.nexta(10.0)), vec![]);
+        // ATR = 1.333
+        assert_eq!(pairs(renko.nexta(12.0)), vec![(10.0, 11.333)]);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut renko = RenkoBuilder::new(RenkoBrickSize::Fixed(2.0)).unwrap();
+
+        let bar1 = Bar::new().high(11).low(9).close(10);
+        let bar2 = Bar::new().high(16).low(10).close(15);
+
+        assert_eq!(pairs(renko.nexta(&bar1)), vec![]);
+        assert_eq!(pairs(renko.nexta(&bar2)), vec![(10.0, 12.0), (12.0, 14.0)]);
+    }
+
+    #[test]
+    fn test_brick_as_indicator_input() {
+        use crate::indicators::SimpleMovingAverage as Sma;
+
+        let mut renko = RenkoBuilder::new(RenkoBrickSize::Fixed(1.0)).unwrap();
+        let mut sma = Sma::new(2).unwrap();
+
+        renko.nexta(10.0);
+        let closes: Vec<f64> = renko.nexta(12.0).iter().map(|b| sma.nexta(b)).collect();
+        assert_eq!(closes, vec![11.0, 11.5]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut renko = RenkoBuilder::new(RenkoBrickSize::Fixed(1.0)).unwrap();
+
+        renko.nexta(10.0);
+        renko.nexta(12.0);
+
+        renko.reset();
+        assert_eq!(renko.last_brick(), None);
+        assert_eq!(pairs(renko.nexta(20.0)), vec![]);
+        assert_eq!(pairs(renko.nexta(21.0)), vec![(20.0, 21.0)]);
+    }
+
+    #[test]
+    fn test_display() {
+        let renko = RenkoBuilder::new(RenkoBrickSize::Fixed(0.5)).unwrap();
+        assert_eq!(format!("{}", renko), "RENKO(0.5)");
+
+        let renko = RenkoBuilder::new(RenkoBrickSize::Atr(14)).unwrap();
+        assert_eq!(format!("{}", renko), "RENKO(ATR(14))");
+    }
+}
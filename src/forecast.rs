@@ -0,0 +1,559 @@
+//! Online forecasts of a series.
+//!
+//! [Forecaster](struct.Forecaster.html) wraps an indicator with a scalar output and forecasts the
+//! output a number of bars ahead with one of the simple models of
+//! [ForecastModel](enum.ForecastModel.html). It also tracks the errors of its past forecasts, so
+//! that several models can be run side by side and compared. To forecast the input series itself,
+//! e.g. the price, wrap [Identity](struct.Identity.html).
+//!
+//! # Example
+//!
+//! ```
+//! use tars::forecast::{ForecastModel, Forecaster, Identity};
+//! use tars::indicators::SimpleMovingAverage;
+//! use tars::Nexta;
+//!
+//! let mut price = Forecaster::new(Identity, ForecastModel::Drift { period: 3 }, 1, 10).unwrap();
+//! let sma = SimpleMovingAverage::new(2).unwrap();
+//! let mut smoothed = Forecaster::new(sma, ForecastModel::Drift { period: 3 }, 1, 10).unwrap();
+//!
+//! for &close in &[10.0, 12.0, 11.0, 13.0, 12.0] {
+//!     price.nexta(close);
+//!     smoothed.nexta(close);
+//! }
+//! assert!(smoothed.mae().unwrap() < price.mae().unwrap());
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Model of [Forecaster](struct.Forecaster.html).
+///
+/// Every model is fitted on the last _period_ values (integer greater than 1). While fewer than
+/// two values, or for AR(1) a degenerate window, are available the forecast is the last value.
+///
+/// AR(1) and Theta refit the whole window on every bar, O(_period_), rather than sliding running
+/// sums of it: those would pick up rounding residue and keep AR(1) from seeing a constant window.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ForecastModel {
+    /// Naive forecast with drift: extends the line from the first to the last value of the
+    /// window.
+    ///
+    /// F<sub>t+h</sub> = Y<sub>t</sub> + h × (Y<sub>t</sub> - Y<sub>t-n+1</sub>) / (n - 1)
+    Drift { period: usize },
+    /// First-order autoregressive model Y<sub>t</sub> = c + φ × Y<sub>t-1</sub>, fitted by least
+    /// squares over the pairs of consecutive values in the window and iterated _h_ times.
+    Ar1 { period: usize },
+    /// Theta method of Assimakopoulos and Nikolopoulos in the form of Hyndman and Billah: simple
+    /// exponential smoothing with smoothing factor _alpha_ (number greater than 0, at most 1)
+    /// plus half the least-squares slope _b_ of the window.
+    ///
+    /// F<sub>t+h</sub> = SES<sub>t</sub> + b / 2 × (h - 1 + 1 / α - (1 - α)<sup>t</sup> / α)
+    Theta { period: usize, alpha: f64 },
+}
+
+impl ForecastModel {
+    fn period(&self) -> usize {
+        match *self {
+            ForecastModel::Drift { period }
+            | ForecastModel::Ar1 { period }
+            | ForecastModel::Theta { period, .. } => period,
+        }
+    }
+}
+
+impl fmt::Display for ForecastModel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ForecastModel::Drift { period } => write!(f, "DRIFT({})", period),
+            ForecastModel::Ar1 { period } => write!(f, "AR1({})", period),
+            ForecastModel::Theta { period, alpha } => write!(f, "THETA({}, {})", period, alpha),
+        }
+    }
+}
+
+/// Passes the input through, to forecast the input series with a
+/// [Forecaster](struct.Forecaster.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Identity;
+
+impl Nexta<f64> for Identity {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        input
+    }
+}
+
+impl<T: Close> Nexta<&T> for Identity {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        input.close()
+    }
+}
+
+impl Reset for Identity {
+    fn reset(&mut self) {}
+}
+
+impl fmt::Display for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ID")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ForecastOutput {
+    /// Output of the wrapped indicator.
+    pub value: f64,
+    /// Forecast of the output _horizon_ bars ahead.
+    pub forecast: f64,
+}
+
+/// Forecast wrapper.
+///
+/// Feeds every input to the wrapped indicator, forecasts its output _horizon_ bars ahead and
+/// returns both. When the value a forecast was made for arrives, the forecast error is recorded;
+/// [mae](#method.mae) and [mape](#method.mape) are the mean absolute error and the mean absolute
+/// percentage error of the last _error_period_ of them. Every bar takes O(_period_ + _horizon_).
+///
+/// Resetting the wrapper resets the wrapped indicator, the fitted model and the recorded errors.
+///
+/// # Parameters
+///
+/// * _inner_ - indicator whose output is forecast.
+/// * _model_ - forecast model.
+/// * _horizon_ - number of bars ahead (integer greater than 0).
+/// * _error_period_ - number of forecast errors tracked (integer greater than 0).
+///
+/// # Example
+///
+/// ```
+/// use tars::forecast::{ForecastModel, Forecaster, Identity};
+/// use tars::Nexta;
+///
+/// let mut forecaster = Forecaster::new(Identity, ForecastModel::Drift { period: 3 }, 2, 10).unwrap();
+///
+/// forecaster.nexta(10.0);
+/// forecaster.nexta(11.0);
+/// let out = forecaster.nexta(12.0);
+/// assert_eq!(out.forecast, 14.0);
+/// assert_eq!(forecaster.mae(), Some(2.0));
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Forecaster<I> {
+    inner: I,
+    model: ForecastModel,
+    horizon: usize,
+    error_period: usize,
+    index: usize,
+    count: usize,
+    values: Box<[f64]>,
+    // smoothed level and (1 - alpha)^t of the Theta model
+    level: f64,
+    decay: f64,
+    // forecasts waiting for their values, the oldest first
+    pending: VecDeque<f64>,
+    error_index: usize,
+    error_count: usize,
+    // absolute errors and absolute percentage errors, `None` for a value of 0
+    errors: Box<[(f64, Option<f64>)]>,
+}
+
+impl<I> Forecaster<I> {
+    pub fn new(
+        inner: I,
+        model: ForecastModel,
+        horizon: usize,
+        error_period: usize,
+    ) -> Result<Self> {
+        let period = model.period();
+        let valid_alpha = match model {
+            ForecastModel::Theta { alpha, .. } => alpha > 0.0 && alpha <= 1.0,
+            _ => true,
+        };
+        if period < 2 || !valid_alpha || horizon == 0 || error_period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            inner,
+            model,
+            horizon,
+            error_period,
+            index: 0,
+            count: 0,
+            values: vec![0.0; period].into_boxed_slice(),
+            level: 0.0,
+            decay: 1.0,
+            pending: VecDeque::with_capacity(horizon + 1),
+            error_index: 0,
+            error_count: 0,
+            errors: vec![(0.0, None); error_period].into_boxed_slice(),
+        })
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+
+    /// Consumes the wrapper, returning the wrapped indicator.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+
+    pub fn model(&self) -> ForecastModel {
+        self.model
+    }
+
+    pub fn horizon(&self) -> usize {
+        self.horizon
+    }
+
+    pub fn error_period(&self) -> usize {
+        self.error_period
+    }
+
+    /// Mean absolute error of the tracked forecasts, `None` until the first forecast is due.
+    pub fn mae(&self) -> Option<f64> {
+        if self.error_count == 0 {
+            return None;
+        }
+        let errors = &self.errors[..self.error_count];
+        Some(errors.iter().map(|e| e.0).sum::<f64>() / self.error_count as f64)
+    }
+
+    /// Mean absolute percentage error of the tracked forecasts in percent, leaving out the values
+    /// of 0. `None` until a forecast of a non-zero value is due.
+    pub fn mape(&self) -> Option<f64> {
+        let errors = &self.errors[..self.error_count];
+        let (sum, count) = errors
+            .iter()
+            .filter_map(|e| e.1)
+            .fold((0.0, 0), |(sum, count), pct| (sum + pct, count + 1));
+        if count == 0 {
+            None
+        } else {
+            Some(sum / count as f64)
+        }
+    }
+
+    // value `back` bars ago
+    fn get(&self, back: usize) -> f64 {
+        let len = self.values.len();
+        self.values[(self.index + len - 1 - back) % len]
+    }
+
+    fn record_error(&mut self, value: f64) {
+        if self.pending.len() < self.horizon {
+            return;
+        }
+        if let Some(forecast) = self.pending.pop_front() {
+            let error = (value - forecast).abs();
+            let pct = if value != 0.0 {
+                Some(error / value.abs() * 100.0)
+            } else {
+                None
+            };
+            self.errors[self.error_index] = (error, pct);
+            self.error_index = if self.error_index + 1 < self.error_period {
+                self.error_index + 1
+            } else {
+                0
+            };
+            if self.error_count < self.error_period {
+                self.error_count += 1;
+            }
+        }
+    }
+
+    fn forecast(&self) -> f64 {
+        let last = self.get(0);
+        let n = self.count;
+        let h = self.horizon as f64;
+        if n < 2 {
+            return last;
+        }
+
+        match self.model {
+            ForecastModel::Drift { .. } => last + h * (last - self.get(n - 1)) / (n - 1) as f64,
+            ForecastModel::Ar1 { .. } => {
+                // pairs (Y[i + 1], Y[i]) with Y[i] i bars ago
+                let pairs = (n - 1) as f64;
+                let (mut mean_x, mut mean_y) = (0.0, 0.0);
+                for i in 0..n - 1 {
+                    mean_x += self.get(i + 1);
+                    mean_y += self.get(i);
+                }
+                mean_x /= pairs;
+                mean_y /= pairs;
+
+                let (mut sxx, mut sxy) = (0.0, 0.0);
+                for i in 0..n - 1 {
+                    let dx = self.get(i + 1) - mean_x;
+                    sxx += dx * dx;
+                    sxy += dx * (self.get(i) - mean_y);
+                }
+                if sxx <= 0.0 {
+                    return last;
+                }
+
+                let phi = sxy / sxx;
+                let c = mean_y - phi * mean_x;
+                (0..self.horizon).fold(last, |f, _| c + phi * f)
+            }
+            ForecastModel::Theta { alpha, .. } => {
+                // least-squares slope against the bar index, oldest value at index 0
+                let mean_t = (n - 1) as f64 / 2.0;
+                let mean_y = (0..n).map(|i| self.get(i)).sum::<f64>() / n as f64;
+                let (mut stt, mut sty) = (0.0, 0.0);
+                for i in 0..n {
+                    let dt = (n - 1 - i) as f64 - mean_t;
+                    stt += dt * dt;
+                    sty += dt * (self.get(i) - mean_y);
+                }
+                let slope = sty / stt;
+                self.level + slope / 2.0 * (h - 1.0 + (1.0 - self.decay) / alpha)
+            }
+        }
+    }
+}
+
+impl<I: Period> Period for Forecaster<I> {
+    fn period(&self) -> usize {
+        self.inner.period()
+    }
+}
+
+impl<T, I: Nexta<T, Output = f64>> Nexta<T> for Forecaster<I> {
+    type Output = ForecastOutput;
+
+    fn nexta(&mut self, input: T) -> Self::Output {
+        let value = self.inner.nexta(input);
+        self.record_error(value);
+
+        self.values[self.index] = value;
+        self.index = if self.index + 1 < self.values.len() {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.values.len() {
+            self.count += 1;
+        }
+
+        if let ForecastModel::Theta { alpha, .. } = self.model {
+            self.level = if self.count == 1 {
+                value
+            } else {
+                alpha * value + (1.0 - alpha) * self.level
+            };
+            self.decay *= 1.0 - alpha;
+        }
+
+        let forecast = self.forecast();
+        self.pending.push_back(forecast);
+
+        ForecastOutput { value, forecast }
+    }
+}
+
+impl<I: Reset> Reset for Forecaster<I> {
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.values.len() {
+            self.values[i] = 0.0;
+        }
+        self.level = 0.0;
+        self.decay = 1.0;
+        self.pending.clear();
+        self.error_index = 0;
+        self.error_count = 0;
+        for i in 0..self.error_period {
+            self.errors[i] = (0.0, None);
+        }
+    }
+}
+
+impl<I: Default> Default for Forecaster<I> {
+    fn default() -> Self {
+        Self::new(I::default(), ForecastModel::Drift { period: 20 }, 1, 20).unwrap()
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Forecaster<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "FORECAST({}, {}, {})",
+            self.inner, self.model, self.horizon
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage;
+    use crate::test_helper::*;
+
+    fn forecaster(model: ForecastModel, horizon: usize) -> Forecaster<Identity> {
+        Forecaster::new(Identity, model, horizon, 10).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let drift = ForecastModel::Drift { period: 20 };
+        assert!(Forecaster::new(Identity, ForecastModel::Drift { period: 1 }, 1, 10).is_err());
+        assert!(Forecaster::new(Identity, ForecastModel::Ar1 { period: 1 }, 1, 10).is_err());
+        let theta = |alpha: f64| ForecastModel::Theta { period: 20, alpha };
+        assert!(Forecaster::new(Identity, theta(0.0), 1, 10).is_err());
+        assert!(Forecaster::new(Identity, theta(1.1), 1, 10).is_err());
+        assert!(Forecaster::new(Identity, drift, 0, 10).is_err());
+        assert!(Forecaster::new(Identity, drift, 1, 0).is_err());
+        assert!(Forecaster::new(Identity, theta(1.0), 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_drift() {
+        let mut f = forecaster(ForecastModel::Drift { period: 3 }, 1);
+
+        assert_eq!(f.nexta(1.0).forecast, 1.0);
+        assert_eq!(f.mae(), None);
+        assert_eq!(f.nexta(2.0).forecast, 3.0);
+        assert_eq!(f.mae(), Some(1.0));
+        assert_eq!(f.mape(), Some(50.0));
+        assert_eq!(f.nexta(4.0).forecast, 5.5);
+        assert_eq!(f.mae(), Some(1.0));
+        assert_eq!(f.mape(), Some(37.5));
+        // the window drops the first value
+        assert_eq!(f.nexta(5.0).forecast, 6.5);
+        assert_eq!(f.mae(), Some(2.5 / 3.0));
+    }
+
+    #[test]
+    fn test_ar1() {
+        let mut f = forecaster(ForecastModel::Ar1 { period: 5 }, 2);
+
+        // Y = 0.5 × Y[1] + 1
+        let mut out = f.nexta(10.0);
+        for &y in &[6.0, 4.0, 3.0, 2.5] {
+            out = f.nexta(y);
+        }
+        assert_eq!(out.value, 2.5);
+        assert_eq!(round(out.forecast), 2.125);
+        // the forecasts of 4 and 3 were made from too short windows
+        assert_eq!(f.mae(), Some(3.0));
+    }
+
+    #[test]
+    fn test_ar1_constant() {
+        let mut f = forecaster(ForecastModel::Ar1 { period: 3 }, 1);
+
+        f.nexta(5.0);
+        f.nexta(5.0);
+        assert_eq!(f.nexta(5.0).forecast, 5.0);
+    }
+
+    #[test]
+    fn test_theta() {
+        // with alpha 1 the level is the last value
+        let mut f = forecaster(
+            ForecastModel::Theta {
+                period: 3,
+                alpha: 1.0,
+            },
+            2,
+        );
+        f.nexta(1.0);
+        f.nexta(2.0);
+        assert_eq!(f.nexta(3.0).forecast, 4.0);
+
+        let mut f = forecaster(
+            ForecastModel::Theta {
+                period: 3,
+                alpha: 0.5,
+            },
+            1,
+        );
+        assert_eq!(f.nexta(1.0).forecast, 1.0);
+        // level 1.5, slope 1: 1.5 + 0.5 × (1 - 0.25) / 0.5
+        assert_eq!(f.nexta(2.0).forecast, 2.25);
+
+        // 1 - alpha rounds to 1, the level still keeps the first value
+        let mut f = forecaster(
+            ForecastModel::Theta {
+                period: 3,
+                alpha: 1e-17,
+            },
+            1,
+        );
+        f.nexta(10.0);
+        assert_eq!(f.nexta(20.0).forecast, 10.0);
+    }
+
+    #[test]
+    fn test_mape_zero_values() {
+        let mut f = forecaster(ForecastModel::Drift { period: 2 }, 1);
+
+        f.nexta(1.0);
+        f.nexta(0.0);
+        assert_eq!(f.mae(), Some(1.0));
+        assert_eq!(f.mape(), None);
+    }
+
+    #[test]
+    fn test_wrapped_indicator() {
+        let sma = SimpleMovingAverage::new(2).unwrap();
+        let mut f = Forecaster::new(sma, ForecastModel::Drift { period: 2 }, 1, 10).unwrap();
+
+        assert_eq!(f.nexta(&Bar::new().close(10)).value, 10.0);
+        let out = f.nexta(&Bar::new().close(12));
+        assert_eq!(out.value, 11.0);
+        assert_eq!(out.forecast, 12.0);
+        assert_eq!(f.period(), 2);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut f = forecaster(ForecastModel::Drift { period: 3 }, 1);
+
+        f.nexta(1.0);
+        f.nexta(2.0);
+        f.reset();
+        assert_eq!(f.mae(), None);
+        assert_eq!(f.nexta(7.0).forecast, 7.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Forecaster::<Identity>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let sma = SimpleMovingAverage::new(5).unwrap();
+        let model = ForecastModel::Theta {
+            period: 20,
+            alpha: 0.3,
+        };
+        let f = Forecaster::new(sma, model, 3, 10).unwrap();
+        assert_eq!(format!("{}", f), "FORECAST(SMA(5), THETA(20, 0.3), 3)");
+
+        let f = forecaster(ForecastModel::Ar1 { period: 10 }, 1);
+        assert_eq!(format!("{}", f), "FORECAST(ID, AR1(10), 1)");
+    }
+}
@@ -0,0 +1,252 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::StandardDeviation as Sd;
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Adaptive overbought/oversold zones for any oscillator.
+///
+/// Fixed thresholds such as 70/30 for RSI do not adapt to the volatility of the oscillator itself.
+/// This wrapper feeds every input to the wrapped oscillator and derives dynamic thresholds from the
+/// rolling mean and standard deviation of the oscillator's own output, similarly to how
+/// [Bollinger Bands](struct.BollingerBands.html) are derived from price.
+///
+/// # Formula
+///
+///  * _Middle_ = SMA(_period_) of oscillator value
+///  * _Upper_ = Middle + SD(_period_) of oscillator value * _multiplier_
+///  * _Lower_ = Middle - SD(_period_) of oscillator value * _multiplier_
+///
+/// The value is [Overbought](enum.AdaptiveZoneState.html#variant.Overbought) when it is above the
+/// upper threshold, [Oversold](enum.AdaptiveZoneState.html#variant.Oversold) when it is below the
+/// lower threshold and [Neutral](enum.AdaptiveZoneState.html#variant.Neutral) otherwise.
+///
+/// # Parameters
+///
+/// * _oscillator_ - any indicator with `f64` output. Default is RSI(14).
+/// * _period_ - number of oscillator values used for the thresholds (integer greater than 0). Default is 20.
+/// * _multiplier_ - standard deviation factor (non-negative number). Default is 2.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{AdaptiveZones, AdaptiveZoneState, RelativeStrengthIndex};
+/// use tars::Nexta;
+///
+/// let rsi = RelativeStrengthIndex::new(3).unwrap();
+/// let mut zones = AdaptiveZones::new(rsi, 5, 1.0).unwrap();
+///
+/// zones.nexta(10.0);
+/// zones.nexta(10.1);
+/// zones.nexta(10.0);
+/// let out = zones.nexta(11.0);
+///
+/// assert!(out.value > out.upper);
+/// assert_eq!(out.state, AdaptiveZoneState::Overbought);
+/// ```
+///
+/// # Links
+///
+/// * [Adaptive RSI zones, TradingView](https://www.tradingview.com/script/2BDN3OCu-RSI-Adaptive-Zones/)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AdaptiveZones<I> {
+    oscillator: I,
+    multiplier: f64,
+    sd: Sd,
+}
+
+/// Position of an oscillator value relative to its adaptive thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdaptiveZoneState {
+    Overbought,
+    Neutral,
+    Oversold,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdaptiveZonesOutput {
+    pub value: f64,
+    pub average: f64,
+    pub upper: f64,
+    pub lower: f64,
+    pub state: AdaptiveZoneState,
+}
+
+impl<I> AdaptiveZones<I> {
+    pub fn new(oscillator: I, period: usize, multiplier: f64) -> Result<Self> {
+        if !(multiplier.is_finite() && multiplier >= 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            oscillator,
+            multiplier,
+            sd: Sd::new(period)?,
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    /// Returns the wrapped oscillator.
+    pub fn oscillator(&self) -> &I {
+        &self.oscillator
+    }
+
+    fn calculate(&mut self, value: f64) -> AdaptiveZonesOutput {
+        let sd = self.sd.nexta(value);
+        let average = self.sd.mean();
+        let upper = average + sd * self.multiplier;
+        let lower = average - sd * self.multiplier;
+
+        let state = if value > upper {
+            AdaptiveZoneState::Overbought
+        } else if value < lower {
+            AdaptiveZoneState::Oversold
+        } else {
+            AdaptiveZoneState::Neutral
+        };
+
+        AdaptiveZonesOutput {
+            value,
+            average,
+            upper,
+            lower,
+            state,
+        }
+    }
+}
+
+impl<I> Period for AdaptiveZones<I> {
+    fn period(&self) -> usize {
+        self.sd.period()
+    }
+}
+
+impl<I: Nexta<f64, Output = f64>> Nexta<f64> for AdaptiveZones<I> {
+    type Output = AdaptiveZonesOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let value = self.oscillator.nexta(input);
+        self.calculate(value)
+    }
+}
+
+impl<'a, T, I: Nexta<&'a T, Output = f64>> Nexta<&'a T> for AdaptiveZones<I> {
+    type Output = AdaptiveZonesOutput;
+
+    fn nexta(&mut self, input: &'a T) -> Self::Output {
+        let value = self.oscillator.nexta(input);
+        self.calculate(value)
+    }
+}
+
+impl<I: Reset> Reset for AdaptiveZones<I> {
+    fn reset(&mut self) {
+        self.oscillator.reset();
+        self.sd.reset();
+    }
+}
+
+impl<I: Default> Default for AdaptiveZones<I> {
+    fn default() -> Self {
+        Self::new(I::default(), 20, 2.0).unwrap()
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for AdaptiveZones<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "AZ({}, {}, {})",
+            self.oscillator,
+            self.sd.period(),
+            self.multiplier
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{MoneyFlowIndex, RelativeStrengthIndex as Rsi};
+    use crate::test_helper::*;
+
+    type Az = AdaptiveZones<Rsi>;
+
+    test_indicator!(Az);
+
+    #[test]
+    fn test_new() {
+        assert!(Az::new(Rsi::default(), 0, 2.0).is_err());
+        assert!(Az::new(Rsi::default(), 1, -2.0).is_err());
+        assert!(Az::new(Rsi::default(), 1, f64::NAN).is_err());
+        assert!(Az::new(Rsi::default(), 1, f64::INFINITY).is_err());
+        assert!(Az::new(Rsi::default(), 1, 2.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut zones = Az::new(Rsi::new(3).unwrap(), 3, 1.0).unwrap();
+
+        let out = zones.nexta(10.0);
+        assert_eq!(out.value, 50.0);
+        assert_eq!(out.upper, 50.0);
+        assert_eq!(out.lower, 50.0);
+        assert_eq!(out.state, AdaptiveZoneState::Neutral);
+
+        // RSI = 85.714
+        let out = zones.nexta(10.5);
+        assert_eq!(round(out.average), 67.857);
+        assert_eq!(round(out.upper), 85.714);
+        assert_eq!(round(out.lower), 50.0);
+        assert_eq!(out.state, AdaptiveZoneState::Neutral);
+
+        // RSI = 35.294
+        let out = zones.nexta(10.0);
+        assert_eq!(round(out.value), 35.294);
+        assert_eq!(round(out.average), 57.003);
+        assert_eq!(round(out.lower), 35.832);
+        assert_eq!(out.state, AdaptiveZoneState::Oversold);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut zones = AdaptiveZones::new(MoneyFlowIndex::new(3).unwrap(), 3, 1.0).unwrap();
+
+        let bar1 = Bar::new().high(3).low(1).close(2).volume(500.0);
+        let bar2 = Bar::new().high(2.3).low(2.0).close(2.3).volume(1000.0);
+
+        assert_eq!(zones.nexta(&bar1).value, 50.0);
+        assert_eq!(zones.nexta(&bar2).value, 100.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut zones = Az::new(Rsi::new(3).unwrap(), 3, 1.0).unwrap();
+
+        zones.nexta(10.0);
+        zones.nexta(10.5);
+
+        zones.reset();
+        let out = zones.nexta(10.0);
+        assert_eq!(out.value, 50.0);
+        assert_eq!(out.average, 50.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Az::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let zones = Az::new(Rsi::new(9).unwrap(), 10, 1.5).unwrap();
+        assert_eq!(format!("{}", zones), "AZ(RSI(9), 10, 1.5)");
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::AverageDirectionalIndex;
+use crate::{Close, High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Average Directional Movement Index Rating (ADXR).
+///
+/// Developed by J. Welles Wilder, the ADXR is the average of the current
+/// [ADX](struct.AverageDirectionalIndex.html) and the ADX of _lag_ bars ago. It reacts slower
+/// than the ADX and is used as a smoother filter of trend strength.
+///
+/// # Formula
+///
+/// ADXR = (ADX<sub>t</sub> + ADX<sub>t-lag</sub>) / 2
+///
+/// Until _lag_ bars were received, the oldest ADX available is used instead of
+/// ADX<sub>t-lag</sub>.
+///
+/// # Parameters
+///
+/// * _period_ - period of the ADX (integer greater than 0). Default is 14.
+/// * _lag_ - number of bars between the two ADX values (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::Adxr;
+/// use tars::{DataItema, Nexta};
+///
+/// let mut adxr = Adxr::new(14, 14).unwrap();
+///
+/// let bar = DataItema::builder()
+///     .open(9.0)
+///     .high(10.0)
+///     .low(8.0)
+///     .close(9.0)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(adxr.nexta(&bar), 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Average directional movement index, Wikipedia](https://en.wikipedia.org/wiki/Average_directional_movement_index)
+///
+#[doc(alias = "ADXR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Adxr {
+    adx: AverageDirectionalIndex,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+impl Adxr {
+    pub fn new(period: usize, lag: usize) -> Result<Self> {
+        if lag == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            adx: AverageDirectionalIndex::new(period)?,
+            index: 0,
+            count: 0,
+            deque: vec![0.0; lag + 1].into_boxed_slice(),
+        })
+    }
+
+    pub fn lag(&self) -> usize {
+        self.deque.len() - 1
+    }
+}
+
+impl Period for Adxr {
+    fn period(&self) -> usize {
+        self.adx.period()
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for Adxr {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let adx = self.adx.nexta(input).adx;
+        let len = self.deque.len();
+
+        self.deque[self.index] = adx;
+        self.index = if self.index + 1 < len {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < len {
+            self.count += 1;
+        }
+
+        // once the buffer is full the next slot holds the ADX of lag bars ago
+        let oldest = if self.count < len {
+            self.deque[0]
+        } else {
+            self.deque[self.index]
+        };
+        (adx + oldest) / 2.0
+    }
+}
+
+impl Reset for Adxr {
+    fn reset(&mut self) {
+        self.adx.reset();
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.deque.len() {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for Adxr {
+    fn default() -> Self {
+        Self::new(14, 14).unwrap()
+    }
+}
+
+impl fmt::Display for Adxr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ADXR({}, {})", self.adx.period(), self.lag())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Adxr::new(0, 1).is_err());
+        assert!(Adxr::new(1, 0).is_err());
+        assert!(Adxr::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut adxr = Adxr::new(2, 2).unwrap();
+
+        // ADX: 0, 100, 100, 66.667, 54.762
+        assert_eq!(adxr.nexta(&bar(10.0, 8.0, 9.0)), 0.0);
+        assert_eq!(adxr.nexta(&bar(11.0, 9.0, 10.5)), 50.0);
+        assert_eq!(adxr.nexta(&bar(12.0, 10.0, 11.0)), 50.0);
+        assert_eq!(round(adxr.nexta(&bar(11.5, 9.5, 10.0))), 83.333);
+        assert_eq!(round(adxr.nexta(&bar(11.0, 8.5, 9.0))), 77.381);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut adxr = Adxr::new(2, 2).unwrap();
+
+        adxr.nexta(&bar(10.0, 8.0, 9.0));
+        adxr.nexta(&bar(11.0, 9.0, 10.5));
+        adxr.reset();
+        assert_eq!(adxr.nexta(&bar(11.0, 9.0, 10.5)), 0.0);
+        assert_eq!(adxr.nexta(&bar(12.0, 10.0, 11.0)), 50.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Adxr::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let adxr = Adxr::new(14, 13).unwrap();
+        assert_eq!(format!("{}", adxr), "ADXR(14, 13)");
+    }
+}
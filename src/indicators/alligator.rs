@@ -0,0 +1,273 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{wilder, ExponentialMovingAverage as Ema};
+use crate::{High, Low, Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams Alligator.
+///
+/// Developed by Bill Williams, the indicator consists of three smoothed moving averages of the
+/// median price, each displaced into the future: the jaw (slowest), the teeth and the lips
+/// (fastest). Intertwined lines indicate a range ("the alligator sleeps"), lines fanning out in
+/// order indicate a trend.
+///
+/// # Formula
+///
+/// * _Jaw_ = SMMA(_jaw period_) of Median Price, displaced by _jaw shift_ bars
+/// * _Teeth_ = SMMA(_teeth period_) of Median Price, displaced by _teeth shift_ bars
+/// * _Lips_ = SMMA(_lips period_) of Median Price, displaced by _lips shift_ bars
+///
+/// Where:
+///
+/// * _Median Price_ = (High + Low) / 2
+/// * _SMMA(n)_ - smoothed moving average, an EMA with smoothing factor 1 / n
+///
+/// Displaced by _shift_ bars means that every output contains the values computed _shift_ bars
+/// ago. Until that many bars have been processed, the value of the first bar is returned.
+///
+/// # Parameters
+///
+/// * _jaw_period_ - jaw period (integer greater than 0). Default is 13.
+/// * _jaw_shift_ - jaw displacement (integer). Default is 8.
+/// * _teeth_period_ - teeth period (integer greater than 0). Default is 8.
+/// * _teeth_shift_ - teeth displacement (integer). Default is 5.
+/// * _lips_period_ - lips period (integer greater than 0). Default is 5.
+/// * _lips_shift_ - lips displacement (integer). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{Alligator, AlligatorOutput};
+/// use tars::Nexta;
+///
+/// let mut alligator = Alligator::new(3, 2, 2, 1, 1, 0).unwrap();
+///
+/// alligator.nexta(10.0);
+/// alligator.nexta(13.0);
+/// let out = alligator.nexta(16.0);
+///
+/// assert_eq!(out, AlligatorOutput { jaw: 10.0, teeth: 11.5, lips: 16.0 });
+/// ```
+///
+/// # Links
+///
+/// * [Alligator indicator, Wikipedia](https://en.wikipedia.org/wiki/Alligator_indicator)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Alligator {
+    jaw: DisplacedSmma,
+    teeth: DisplacedSmma,
+    lips: DisplacedSmma,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlligatorOutput {
+    pub jaw: f64,
+    pub teeth: f64,
+    pub lips: f64,
+}
+
+impl Alligator {
+    pub fn new(
+        jaw_period: usize,
+        jaw_shift: usize,
+        teeth_period: usize,
+        teeth_shift: usize,
+        lips_period: usize,
+        lips_shift: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            jaw: DisplacedSmma::new(jaw_period, jaw_shift)?,
+            teeth: DisplacedSmma::new(teeth_period, teeth_shift)?,
+            lips: DisplacedSmma::new(lips_period, lips_shift)?,
+        })
+    }
+}
+
+impl Nexta<f64> for Alligator {
+    type Output = AlligatorOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        AlligatorOutput {
+            jaw: self.jaw.nexta(input),
+            teeth: self.teeth.nexta(input),
+            lips: self.lips.nexta(input),
+        }
+    }
+}
+
+impl<T: High + Low> Nexta<&T> for Alligator {
+    type Output = AlligatorOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta((input.high() + input.low()) / 2.0)
+    }
+}
+
+impl Reset for Alligator {
+    fn reset(&mut self) {
+        self.jaw.reset();
+        self.teeth.reset();
+        self.lips.reset();
+    }
+}
+
+impl Default for Alligator {
+    fn default() -> Self {
+        Self::new(13, 8, 8, 5, 5, 3).unwrap()
+    }
+}
+
+impl fmt::Display for Alligator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ALLIGATOR({}, {}, {}, {}, {}, {})",
+            self.jaw.period,
+            self.jaw.shift(),
+            self.teeth.period,
+            self.teeth.shift(),
+            self.lips.period,
+            self.lips.shift()
+        )
+    }
+}
+
+/// Smoothed moving average whose output is delayed by a number of bars.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct DisplacedSmma {
+    period: usize,
+    smma: Ema,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+impl DisplacedSmma {
+    fn new(period: usize, shift: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            smma: wilder(period)?,
+            index: 0,
+            count: 0,
+            deque: vec![0.0; shift + 1].into_boxed_slice(),
+        })
+    }
+
+    fn shift(&self) -> usize {
+        self.deque.len() - 1
+    }
+
+    fn nexta(&mut self, input: f64) -> f64 {
+        let value = self.smma.nexta(input);
+
+        self.deque[self.index] = value;
+        self.index = if self.index + 1 < self.deque.len() {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.deque.len() {
+            self.count += 1;
+            // not displaced by the full shift yet
+            self.deque[0]
+        } else {
+            // the next slot holds the oldest value
+            self.deque[self.index]
+        }
+    }
+
+    fn reset(&mut self) {
+        self.smma.reset();
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.deque.len() {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Alligator);
+
+    #[test]
+    fn test_new() {
+        assert!(Alligator::new(0, 8, 8, 5, 5, 3).is_err());
+        assert!(Alligator::new(13, 8, 0, 5, 5, 3).is_err());
+        assert!(Alligator::new(13, 8, 8, 5, 0, 3).is_err());
+        assert!(Alligator::new(1, 0, 1, 0, 1, 0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut alligator = Alligator::new(3, 2, 2, 1, 1, 0).unwrap();
+
+        let mut next = |input: f64| {
+            let out = alligator.nexta(input);
+            (round(out.jaw), round(out.teeth), round(out.lips))
+        };
+
+        assert_eq!(next(10.0), (10.0, 10.0, 10.0));
+        assert_eq!(next(13.0), (10.0, 10.0, 13.0));
+        assert_eq!(next(16.0), (10.0, 11.5, 16.0));
+        assert_eq!(next(7.0), (11.0, 13.75, 7.0));
+        assert_eq!(next(7.0), (12.667, 10.375, 7.0));
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut alligator = Alligator::new(2, 1, 2, 0, 1, 0).unwrap();
+
+        let bar1 = Bar::new().high(12).low(8);
+        let bar2 = Bar::new().high(16).low(12);
+
+        assert_eq!(
+            alligator.nexta(&bar1),
+            AlligatorOutput {
+                jaw: 10.0,
+                teeth: 10.0,
+                lips: 10.0
+            }
+        );
+        assert_eq!(
+            alligator.nexta(&bar2),
+            AlligatorOutput {
+                jaw: 10.0,
+                teeth: 12.0,
+                lips: 14.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut alligator = Alligator::new(3, 2, 2, 1, 1, 0).unwrap();
+
+        alligator.nexta(10.0);
+        alligator.nexta(13.0);
+        alligator.reset();
+
+        let out = alligator.nexta(4.0);
+        assert_eq!((out.jaw, out.teeth, out.lips), (4.0, 4.0, 4.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Alligator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let alligator = Alligator::default();
+        assert_eq!(format!("{}", alligator), "ALLIGATOR(13, 8, 8, 5, 5, 3)");
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{wilder, ExponentialMovingAverage as Ema, TrueRange};
+use crate::{Close, High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Average Directional Index (ADX).
+///
+/// Developed by J. Welles Wilder, the ADX measures the strength of a trend regardless of its
+/// direction. The directional indicators +DI and -DI compare the upward and the downward moves
+/// of the bar range with the [True Range](struct.TrueRange.html); the ADX is the smoothed
+/// relative difference between the two. An ADX above 25 usually indicates a trend.
+///
+/// # Formula
+///
+/// * _+DM_ = High - High<sub>prev</sub> if it is greater than Low<sub>prev</sub> - Low and 0,
+///   otherwise 0
+/// * _-DM_ = Low<sub>prev</sub> - Low if it is greater than High - High<sub>prev</sub> and 0,
+///   otherwise 0
+/// * _+DI_ = 100 × W(+DM) / W(TR), _-DI_ = 100 × W(-DM) / W(TR)
+/// * _DX_ = 100 × |+DI - -DI| / (+DI + -DI)
+/// * _ADX_ = W(DX)
+///
+/// Where _W_ is Wilder's smoothing over _period_ bars, an EMA with α = 1 / _period_. All the
+/// values are 0 on the first bar, which has no previous bar to compare with, and the ratios are
+/// 0 while their denominator is 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::AverageDirectionalIndex as Adx;
+/// use tars::{DataItema, Nexta};
+///
+/// let mut adx = Adx::new(14).unwrap();
+///
+/// let bar = |high: f64, low: f64, close: f64| {
+///     DataItema::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// adx.nexta(&bar(10.0, 8.0, 9.0));
+/// let out = adx.nexta(&bar(11.0, 9.0, 10.5));
+/// assert_eq!((out.plus_di, out.minus_di, out.adx), (50.0, 0.0, 100.0));
+/// ```
+///
+/// # Links
+///
+/// * [Average directional movement index, Wikipedia](https://en.wikipedia.org/wiki/Average_directional_movement_index)
+///
+#[doc(alias = "ADX")]
+#[doc(alias = "DMI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AverageDirectionalIndex {
+    period: usize,
+    true_range: TrueRange,
+    plus_dm: Ema,
+    minus_dm: Ema,
+    tr: Ema,
+    adx: Ema,
+    prev: Option<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AverageDirectionalIndexOutput {
+    pub plus_di: f64,
+    pub minus_di: f64,
+    pub adx: f64,
+}
+
+impl From<AverageDirectionalIndexOutput> for (f64, f64, f64) {
+    fn from(o: AverageDirectionalIndexOutput) -> Self {
+        (o.plus_di, o.minus_di, o.adx)
+    }
+}
+
+impl AverageDirectionalIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            true_range: TrueRange::new(),
+            plus_dm: wilder(period)?,
+            minus_dm: wilder(period)?,
+            tr: wilder(period)?,
+            adx: wilder(period)?,
+            prev: None,
+        })
+    }
+}
+
+impl Period for AverageDirectionalIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for AverageDirectionalIndex {
+    type Output = AverageDirectionalIndexOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let (high, low) = (input.high(), input.low());
+        let tr = self.true_range.nexta(input);
+
+        let (prev_high, prev_low) = match self.prev.replace((high, low)) {
+            Some(prev) => prev,
+            None => {
+                return AverageDirectionalIndexOutput {
+                    plus_di: 0.0,
+                    minus_di: 0.0,
+                    adx: 0.0,
+                }
+            }
+        };
+
+        let up = high - prev_high;
+        let down = prev_low - low;
+        let plus_dm = if up > down && up > 0.0 { up } else { 0.0 };
+        let minus_dm = if down > up && down > 0.0 { down } else { 0.0 };
+
+        let plus_dm = self.plus_dm.nexta(plus_dm);
+        let minus_dm = self.minus_dm.nexta(minus_dm);
+        let tr = self.tr.nexta(tr);
+
+        let (plus_di, minus_di) = if tr > 0.0 {
+            (100.0 * plus_dm / tr, 100.0 * minus_dm / tr)
+        } else {
+            (0.0, 0.0)
+        };
+        let sum = plus_di + minus_di;
+        let dx = if sum > 0.0 {
+            100.0 * (plus_di - minus_di).abs() / sum
+        } else {
+            0.0
+        };
+
+        AverageDirectionalIndexOutput {
+            plus_di,
+            minus_di,
+            adx: self.adx.nexta(dx),
+        }
+    }
+}
+
+impl Reset for AverageDirectionalIndex {
+    fn reset(&mut self) {
+        self.true_range.reset();
+        self.plus_dm.reset();
+        self.minus_dm.reset();
+        self.tr.reset();
+        self.adx.reset();
+        self.prev = None;
+    }
+}
+
+impl Default for AverageDirectionalIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for AverageDirectionalIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ADX({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Adx = AverageDirectionalIndex;
+
+    fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        let n2 = (nums.2 * 1000.0).round() / 1000.0;
+        (n0, n1, n2)
+    }
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Adx::new(0).is_err());
+        assert!(Adx::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut adx = Adx::new(2).unwrap();
+
+        assert_eq!(
+            round(adx.nexta(&bar(10.0, 8.0, 9.0)).into()),
+            (0.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            round(adx.nexta(&bar(11.0, 9.0, 10.5)).into()),
+            (50.0, 0.0, 100.0)
+        );
+        assert_eq!(
+            round(adx.nexta(&bar(12.0, 10.0, 11.0)).into()),
+            (50.0, 0.0, 100.0)
+        );
+        assert_eq!(
+            round(adx.nexta(&bar(11.5, 9.5, 10.0)).into()),
+            (25.0, 12.5, 66.667)
+        );
+        assert_eq!(
+            round(adx.nexta(&bar(11.0, 8.5, 9.0)).into()),
+            (11.111, 27.778, 54.762)
+        );
+    }
+
+    #[test]
+    fn test_inside_bars() {
+        let mut adx = Adx::new(2).unwrap();
+
+        adx.nexta(&bar(10.0, 8.0, 9.0));
+        // neither a higher high nor a lower low
+        let out = adx.nexta(&bar(9.5, 8.5, 9.0));
+        assert_eq!(round(out.into()), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut adx = Adx::new(2).unwrap();
+
+        adx.nexta(&bar(10.0, 8.0, 9.0));
+        adx.nexta(&bar(11.0, 9.0, 10.5));
+        adx.reset();
+        assert_eq!(
+            round(adx.nexta(&bar(11.0, 9.0, 10.5)).into()),
+            (0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        Adx::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let adx = Adx::new(7).unwrap();
+        assert_eq!(format!("{}", adx), "ADX(7)");
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage;
+use crate::{Close, High, Low, Nexta, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Balance of Power (BOP).
+///
+/// Developed by Igor Livshin, the indicator measures the strength of buyers against sellers by
+/// relating the distance between close and open to the range of the bar. The raw value is
+/// noisy, so it is usually smoothed with a moving average.
+///
+/// # Formula
+///
+/// BOP = SMA(_period_) of ((Close - Open) / (High - Low))
+///
+/// When high equals low the raw value of the bar is 0. With _period_ 1 no smoothing is applied.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::BalanceOfPower;
+/// use tars::{Nexta, DataItema};
+///
+/// let mut bop = BalanceOfPower::new(1).unwrap();
+///
+/// let di = DataItema::builder()
+///             .open(10.0)
+///             .high(12.0)
+///             .low(8.0)
+///             .close(11.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// assert_eq!(bop.nexta(&di), 0.25);
+/// ```
+///
+#[doc(alias = "BOP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BalanceOfPower {
+    sma: SimpleMovingAverage,
+}
+
+impl BalanceOfPower {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: SimpleMovingAverage::new(period)?,
+        })
+    }
+}
+
+impl Period for BalanceOfPower {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl<T: Open + High + Low + Close> Nexta<&T> for BalanceOfPower {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let range = input.high() - input.low();
+        let raw = if range == 0.0 {
+            0.0
+        } else {
+            (input.close() - input.open()) / range
+        };
+        self.sma.nexta(raw)
+    }
+}
+
+impl Reset for BalanceOfPower {
+    fn reset(&mut self) {
+        self.sma.reset();
+    }
+}
+
+impl Default for BalanceOfPower {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for BalanceOfPower {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BOP({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(BalanceOfPower::new(0).is_err());
+        assert!(BalanceOfPower::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut bop = BalanceOfPower::new(2).unwrap();
+
+        let bar1 = Bar::new().open(10).high(12).low(8).close(11);
+        let bar2 = Bar::new().open(11).high(11.5).low(9.5).close(9.5);
+        let bar3 = Bar::new().open(10).high(10).low(10).close(10);
+
+        // raw = 0.25
+        assert_eq!(bop.nexta(&bar1), 0.25);
+        // raw = -0.75
+        assert_eq!(bop.nexta(&bar2), -0.25);
+        // raw = 0
+        assert_eq!(bop.nexta(&bar3), -0.375);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bop = BalanceOfPower::new(2).unwrap();
+
+        let bar1 = Bar::new().open(10).high(12).low(8).close(11);
+        let bar2 = Bar::new().open(11).high(11.5).low(9.5).close(9.5);
+
+        bop.nexta(&bar1);
+        bop.reset();
+        assert_eq!(bop.nexta(&bar2), -0.75);
+    }
+
+    #[test]
+    fn test_default() {
+        BalanceOfPower::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let bop = BalanceOfPower::new(10).unwrap();
+        assert_eq!(format!("{}", bop), "BOP(10)");
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::errors::Result;
+use crate::errors::{Result, TaError};
 use crate::indicators::StandardDeviation as Sd;
 use crate::{Close, Nexta, Period, Reset};
 #[cfg(feature = "serde")]
@@ -21,6 +21,13 @@ use serde::{Deserialize, Serialize};
 ///  * _BB<sub>Upper Band</sub>_ = SMA + SD of observation * multipler (usually 2.0)
 ///  * _BB<sub>Lower Band</sub>_ = SMA - SD of observation * multipler (usually 2.0)
 ///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). With a period of 1 the SD is 0 and
+///   all three bands equal the input.
+/// * _multiplier_ - SD multiplier (non-negative number). A multiplier of 0 collapses both bands
+///   onto the middle band.
+///
 /// # Example
 ///
 ///```
@@ -63,6 +70,10 @@ pub struct BollingerBandsOutput {
 
 impl BollingerBands {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        if !(multiplier.is_finite() && multiplier >= 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
         Ok(Self {
             period,
             multiplier,
@@ -134,6 +145,10 @@ mod tests {
         assert!(BollingerBands::new(0, 2_f64).is_err());
         assert!(BollingerBands::new(1, 2_f64).is_ok());
         assert!(BollingerBands::new(2, 2_f64).is_ok());
+        assert!(BollingerBands::new(2, 0.0).is_ok());
+        assert!(BollingerBands::new(2, -1.0).is_err());
+        assert!(BollingerBands::new(2, f64::NAN).is_err());
+        assert!(BollingerBands::new(2, f64::INFINITY).is_err());
     }
 
     #[test]
@@ -161,6 +176,20 @@ mod tests {
         assert_eq!(round(d.lower), -0.395);
     }
 
+    #[test]
+    fn test_degenerate() {
+        let mut bb = BollingerBands::new(1, 2.0).unwrap();
+        let out = bb.nexta(2.0);
+        assert_eq!((out.average, out.upper, out.lower), (2.0, 2.0, 2.0));
+        let out = bb.nexta(5.0);
+        assert_eq!((out.average, out.upper, out.lower), (5.0, 5.0, 5.0));
+
+        let mut bb = BollingerBands::new(3, 0.0).unwrap();
+        bb.nexta(2.0);
+        let out = bb.nexta(5.0);
+        assert_eq!((out.average, out.upper, out.lower), (3.5, 3.5, 3.5));
+    }
+
     #[test]
     fn test_reset() {
         let mut bb = BollingerBands::new(5, 2.0_f64).unwrap();
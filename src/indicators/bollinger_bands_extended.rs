@@ -0,0 +1,251 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::StandardDeviation as Sd;
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bollinger Bands with %B and Bandwidth.
+///
+/// Same bands as [BollingerBands](crate::indicators::BollingerBands), with a separate
+/// standard deviation multiplier for each band, and two derived values: %B tells where the
+/// price is relative to the bands, and Bandwidth measures how wide the bands are relative to the
+/// average, narrowing in a squeeze and widening in an expansion.
+///
+/// # Formula
+///
+/// See SMA, SD documentation.
+///
+///  * _Middle Band_ - Simple Moving Average (SMA).
+///  * _Upper Band_ = SMA + SD × _upper multiplier_
+///  * _Lower Band_ = SMA - SD × _lower multiplier_
+///  * _%B_ = (Price - Lower Band) / (Upper Band - Lower Band)
+///  * _Bandwidth_ = (Upper Band - Lower Band) / Middle Band
+///
+/// When the bands coincide %B is 0.5, and when the middle band is 0 Bandwidth is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+/// * _upper_multiplier_ - SD multiplier of the upper band (non-negative number). Default is 2.
+/// * _lower_multiplier_ - SD multiplier of the lower band (non-negative number). Default is 2.
+///
+/// A multiplier of 0 puts that band on the middle band; with both at 0 the bands coincide.
+///
+/// # Example
+///
+///```
+/// use tars::indicators::BollingerBandsExtended;
+/// use tars::Nexta;
+///
+/// let mut bb = BollingerBandsExtended::new(3, 2.0, 1.0).unwrap();
+///
+/// bb.nexta(2.0);
+/// let out = bb.nexta(5.0);
+///
+/// assert_eq!(out.average, 3.5);
+/// assert_eq!(out.upper, 6.5);
+/// assert_eq!(out.lower, 2.0);
+/// assert_eq!(out.percent_b, 3.0 / 4.5);
+/// assert_eq!(out.bandwidth, 4.5 / 3.5);
+/// ```
+///
+/// # Links
+///
+/// * [Bollinger Bands, Wikipedia](https://en.wikipedia.org/wiki/Bollinger_Bands)
+///
+#[doc(alias = "BB")]
+#[doc(alias = "%B")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BollingerBandsExtended {
+    period: usize,
+    upper_multiplier: f64,
+    lower_multiplier: f64,
+    sd: Sd,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BollingerBandsExtendedOutput {
+    pub average: f64,
+    pub upper: f64,
+    pub lower: f64,
+    pub percent_b: f64,
+    pub bandwidth: f64,
+}
+
+impl BollingerBandsExtended {
+    pub fn new(period: usize, upper_multiplier: f64, lower_multiplier: f64) -> Result<Self> {
+        if !(upper_multiplier.is_finite()
+            && upper_multiplier >= 0.0
+            && lower_multiplier.is_finite()
+            && lower_multiplier >= 0.0)
+        {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            period,
+            upper_multiplier,
+            lower_multiplier,
+            sd: Sd::new(period)?,
+        })
+    }
+
+    pub fn upper_multiplier(&self) -> f64 {
+        self.upper_multiplier
+    }
+
+    pub fn lower_multiplier(&self) -> f64 {
+        self.lower_multiplier
+    }
+}
+
+impl Period for BollingerBandsExtended {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for BollingerBandsExtended {
+    type Output = BollingerBandsExtendedOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let sd = self.sd.nexta(input);
+        let average = self.sd.mean();
+        let upper = average + sd * self.upper_multiplier;
+        let lower = average - sd * self.lower_multiplier;
+        let width = upper - lower;
+
+        let percent_b = if width == 0.0 {
+            0.5
+        } else {
+            (input - lower) / width
+        };
+        let bandwidth = if average == 0.0 { 0.0 } else { width / average };
+
+        Self::Output {
+            average,
+            upper,
+            lower,
+            percent_b,
+            bandwidth,
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for BollingerBandsExtended {
+    type Output = BollingerBandsExtendedOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for BollingerBandsExtended {
+    fn reset(&mut self) {
+        self.sd.reset();
+    }
+}
+
+impl Default for BollingerBandsExtended {
+    fn default() -> Self {
+        Self::new(9, 2.0, 2.0).unwrap()
+    }
+}
+
+impl fmt::Display for BollingerBandsExtended {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "BBX({}, {}, {})",
+            self.period, self.upper_multiplier, self.lower_multiplier
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(BollingerBandsExtended);
+
+    #[test]
+    fn test_new() {
+        assert!(BollingerBandsExtended::new(0, 2.0, 2.0).is_err());
+        assert!(BollingerBandsExtended::new(1, 2.0, 2.0).is_ok());
+        assert!(BollingerBandsExtended::new(1, 0.0, 0.0).is_ok());
+        assert!(BollingerBandsExtended::new(1, -1.0, 2.0).is_err());
+        assert!(BollingerBandsExtended::new(1, 2.0, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut bb = BollingerBandsExtended::new(3, 2.0, 1.0).unwrap();
+
+        let mut next = |input: f64| {
+            let out = bb.nexta(input);
+            (
+                round(out.average),
+                round(out.upper),
+                round(out.lower),
+                round(out.percent_b),
+                round(out.bandwidth),
+            )
+        };
+
+        assert_eq!(next(2.0), (2.0, 2.0, 2.0, 0.5, 0.0));
+        assert_eq!(next(5.0), (3.5, 6.5, 2.0, 0.667, 1.286));
+        assert_eq!(next(1.0), (2.667, 6.066, 0.967, 0.006, 1.912));
+        assert_eq!(next(6.25), (4.083, 8.562, 1.844, 0.656, 1.645));
+    }
+
+    #[test]
+    fn test_same_multipliers_as_bollinger_bands() {
+        use crate::indicators::BollingerBands;
+
+        let mut bb = BollingerBands::new(4, 2.0).unwrap();
+        let mut bbx = BollingerBandsExtended::new(4, 2.0, 2.0).unwrap();
+
+        for &input in &[3.0, 2.5, 3.5, 4.0, 2.0, 6.0] {
+            let a = bb.nexta(input);
+            let b = bbx.nexta(input);
+            assert_eq!((a.average, a.upper, a.lower), (b.average, b.upper, b.lower));
+        }
+    }
+
+    #[test]
+    fn test_zero_average() {
+        let mut bb = BollingerBandsExtended::new(2, 2.0, 2.0).unwrap();
+
+        bb.nexta(-1.0);
+        let out = bb.nexta(1.0);
+        assert_eq!(out.bandwidth, 0.0);
+        assert_eq!(out.percent_b, 0.75);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bb = BollingerBandsExtended::new(3, 2.0, 1.0).unwrap();
+
+        bb.nexta(2.0);
+        bb.nexta(5.0);
+        bb.reset();
+
+        let out = bb.nexta(3.0);
+        assert_eq!((out.average, out.percent_b, out.bandwidth), (3.0, 0.5, 0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        BollingerBandsExtended::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let bb = BollingerBandsExtended::new(20, 2.0, 1.5).unwrap();
+        assert_eq!(format!("{}", bb), "BBX(20, 2, 1.5)");
+    }
+}
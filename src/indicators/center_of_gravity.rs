@@ -0,0 +1,215 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ehlers Center of Gravity (COG).
+///
+/// Developed by John Ehlers, the oscillator computes the "balance point" of the prices in the
+/// window, weighting each price by its position. It identifies turning points with very little
+/// lag. The signal line is the value of the previous bar, crosses of both lines are used as
+/// signals.
+///
+/// # Formula
+///
+/// COG = -Σ (i + 1) × Price<sub>t-i</sub> / Σ Price<sub>t-i</sub>, for i = 0 .. _period_ - 1
+///
+/// Until _period_ inputs have been processed the available ones are used. When the sum of the
+/// prices is 0 the COG is 0. The signal of the first input is the COG itself. Bars are
+/// processed by their median price (High + Low) / 2.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::CenterOfGravity;
+/// use tars::Nexta;
+///
+/// let mut cog = CenterOfGravity::new(3).unwrap();
+///
+/// assert_eq!(cog.nexta(10.0).cog, -1.0);
+///
+/// let out = cog.nexta(10.0);
+/// assert_eq!((out.cog, out.signal), (-1.5, -1.0));
+/// ```
+///
+#[doc(alias = "COG")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CenterOfGravity {
+    period: usize,
+    index: usize,
+    count: usize,
+    prev: Option<f64>,
+    deque: Box<[f64]>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CenterOfGravityOutput {
+    pub cog: f64,
+    pub signal: f64,
+}
+
+impl From<CenterOfGravityOutput> for (f64, f64) {
+    fn from(co: CenterOfGravityOutput) -> Self {
+        (co.cog, co.signal)
+    }
+}
+
+impl CenterOfGravity {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                prev: None,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for CenterOfGravity {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for CenterOfGravity {
+    type Output = CenterOfGravityOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        self.deque[self.index] = input;
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        let mut weighted = 0.0;
+        let mut sum = 0.0;
+        // walk from the newest value backwards
+        for i in 0..self.count {
+            let value = self.deque[(self.index + self.period - i) % self.period];
+            weighted += (i + 1) as f64 * value;
+            sum += value;
+        }
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let cog = if sum == 0.0 { 0.0 } else { -weighted / sum };
+        let signal = self.prev.unwrap_or(cog);
+        self.prev = Some(cog);
+
+        CenterOfGravityOutput { cog, signal }
+    }
+}
+
+impl<T: High + Low> Nexta<&T> for CenterOfGravity {
+    type Output = CenterOfGravityOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta((input.high() + input.low()) / 2.0)
+    }
+}
+
+impl Reset for CenterOfGravity {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.prev = None;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for CenterOfGravity {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for CenterOfGravity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "COG({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(CenterOfGravity);
+
+    fn round(nums: (f64, f64)) -> (f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        (n0, n1)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(CenterOfGravity::new(0).is_err());
+        assert!(CenterOfGravity::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cog = CenterOfGravity::new(3).unwrap();
+
+        assert_eq!(round(cog.nexta(10.0).into()), (-1.0, -1.0));
+        assert_eq!(round(cog.nexta(11.0).into()), (-1.476, -1.0));
+        assert_eq!(round(cog.nexta(12.0).into()), (-1.939, -1.476));
+        // 10 leaves the window
+        assert_eq!(round(cog.nexta(13.0).into()), (-1.944, -1.939));
+        assert_eq!(round(cog.nexta(10.0).into()), (-2.057, -1.944));
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut cog = CenterOfGravity::new(2).unwrap();
+
+        assert_eq!(cog.nexta(&Bar::new().high(12).low(8)).cog, -1.0);
+        assert_eq!(cog.nexta(&Bar::new().high(12).low(8)).cog, -1.5);
+    }
+
+    #[test]
+    fn test_zero_prices() {
+        let mut cog = CenterOfGravity::new(2).unwrap();
+        assert_eq!(cog.nexta(0.0).cog, 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cog = CenterOfGravity::new(3).unwrap();
+
+        cog.nexta(10.0);
+        cog.nexta(11.0);
+
+        cog.reset();
+        let out = cog.nexta(12.0);
+        assert_eq!((out.cog, out.signal), (-1.0, -1.0));
+    }
+
+    #[test]
+    fn test_default() {
+        CenterOfGravity::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cog = CenterOfGravity::new(10).unwrap();
+        assert_eq!(format!("{}", cog), "COG(10)");
+    }
+}
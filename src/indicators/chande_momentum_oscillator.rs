@@ -0,0 +1,245 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chande Momentum Oscillator (CMO).
+///
+/// Developed by Tushar Chande, the oscillator relates the sum of gains to the sum of losses over
+/// the period. Unlike RSI it uses unsmoothed sums and is bounded between -100 and +100; values
+/// above +50 are usually considered overbought and values below -50 oversold.
+///
+/// # Formula
+///
+/// CMO = 100 × (Σ Up - Σ Down) / (Σ Up + Σ Down) over the last _period_ changes
+///
+/// Where:
+///
+/// * _Up_ - positive change of the input to the previous one
+/// * _Down_ - absolute value of a negative change of the input to the previous one
+///
+/// When there was no change in the window the CMO is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of changes (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::ChandeMomentumOscillator;
+/// use tars::Nexta;
+///
+/// let mut cmo = ChandeMomentumOscillator::new(3).unwrap();
+/// assert_eq!(cmo.nexta(10.0), 0.0);
+/// assert_eq!(cmo.nexta(13.0), 100.0);
+/// assert_eq!(cmo.nexta(12.0), 50.0);
+/// ```
+///
+#[doc(alias = "CMO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ChandeMomentumOscillator {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum_up: f64,
+    sum_down: f64,
+    // number of up and down changes in the window, a sum is zeroed at 0 to drop float residue
+    ups: usize,
+    downs: usize,
+    prev: Option<f64>,
+    deque: Box<[f64]>,
+}
+
+impl ChandeMomentumOscillator {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum_up: 0.0,
+                sum_down: 0.0,
+                ups: 0,
+                downs: 0,
+                prev: None,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for ChandeMomentumOscillator {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for ChandeMomentumOscillator {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let change = match self.prev {
+            Some(prev) => input - prev,
+            None => {
+                self.prev = Some(input);
+                return 0.0;
+            }
+        };
+        self.prev = Some(input);
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old_val = self.deque[self.index];
+            if old_val > 0.0 {
+                self.ups -= 1;
+                self.sum_up = if self.ups > 0 {
+                    self.sum_up - old_val
+                } else {
+                    0.0
+                };
+            } else if old_val < 0.0 {
+                self.downs -= 1;
+                self.sum_down = if self.downs > 0 {
+                    self.sum_down + old_val
+                } else {
+                    0.0
+                };
+            }
+        }
+
+        if change > 0.0 {
+            self.ups += 1;
+            self.sum_up += change;
+        } else if change < 0.0 {
+            self.downs += 1;
+            self.sum_down -= change;
+        }
+
+        self.deque[self.index] = change;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let total = self.sum_up + self.sum_down;
+        if total == 0.0 {
+            0.0
+        } else {
+            100.0 * (self.sum_up - self.sum_down) / total
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for ChandeMomentumOscillator {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for ChandeMomentumOscillator {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum_up = 0.0;
+        self.sum_down = 0.0;
+        self.ups = 0;
+        self.downs = 0;
+        self.prev = None;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for ChandeMomentumOscillator {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for ChandeMomentumOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CMO({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(ChandeMomentumOscillator);
+
+    #[test]
+    fn test_new() {
+        assert!(ChandeMomentumOscillator::new(0).is_err());
+        assert!(ChandeMomentumOscillator::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cmo = ChandeMomentumOscillator::new(3).unwrap();
+
+        assert_eq!(cmo.nexta(10.0), 0.0);
+        assert_eq!(cmo.nexta(13.0), 100.0);
+        assert_eq!(cmo.nexta(12.0), 50.0);
+        assert_eq!(cmo.nexta(12.0), 50.0);
+        // +3 leaves the window
+        assert_eq!(cmo.nexta(10.0), -100.0);
+        assert_eq!(round(cmo.nexta(14.0)), 33.333);
+        assert_eq!(round(cmo.nexta(14.0)), 33.333);
+        assert_eq!(cmo.nexta(14.0), 100.0);
+        assert_eq!(cmo.nexta(14.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_flat_after_changes() {
+        let mut cmo = ChandeMomentumOscillator::new(3).unwrap();
+
+        for &close in &[0.1, 0.129, 0.429, 0.429, 0.429] {
+            cmo.nexta(close);
+        }
+        assert_eq!(cmo.nexta(0.429), 0.0);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut cmo = ChandeMomentumOscillator::new(2).unwrap();
+
+        assert_eq!(cmo.nexta(&Bar::new().close(10)), 0.0);
+        assert_eq!(cmo.nexta(&Bar::new().close(8)), -100.0);
+        assert_eq!(cmo.nexta(&Bar::new().close(14)), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cmo = ChandeMomentumOscillator::new(3).unwrap();
+
+        cmo.nexta(10.0);
+        cmo.nexta(13.0);
+
+        cmo.reset();
+        assert_eq!(cmo.nexta(13.0), 0.0);
+        assert_eq!(cmo.nexta(12.0), -100.0);
+    }
+
+    #[test]
+    fn test_default() {
+        ChandeMomentumOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cmo = ChandeMomentumOscillator::new(14).unwrap();
+        assert_eq!(format!("{}", cmo), "CMO(14)");
+    }
+}
@@ -0,0 +1,240 @@
+use crate::errors::{Result, TaError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Means, variances and covariance of the last _period_ pairs of values.
+///
+/// The sums of squared deviations and of the products of deviations are updated with Welford's
+/// algorithm, removing the oldest pair before adding the new one once the window is full, so
+/// every update is O(1). Runs of equal values are tracked as well, so that the moments of a
+/// constant series are exactly 0 instead of float residue of the removed pairs.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct RollingCoMoments {
+    period: usize,
+    index: usize,
+    count: usize,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c_xy: f64,
+    // number of consecutive equal values of X and Y
+    run_x: usize,
+    run_y: usize,
+    deque: Box<[(f64, f64)]>,
+}
+
+impl RollingCoMoments {
+    pub(crate) fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                mean_x: 0.0,
+                mean_y: 0.0,
+                m2_x: 0.0,
+                m2_y: 0.0,
+                c_xy: 0.0,
+                run_x: 0,
+                run_y: 0,
+                deque: vec![(0.0, 0.0); period].into_boxed_slice(),
+            }),
+        }
+    }
+
+    pub(crate) fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Adds a pair to the window, dropping the oldest one once the window is full.
+    pub(crate) fn push(&mut self, x: f64, y: f64) {
+        if self.count > 0 {
+            let (last_x, last_y) = self.deque[(self.index + self.period - 1) % self.period];
+            self.run_x = if last_x == x { self.run_x + 1 } else { 1 };
+            self.run_y = if last_y == y { self.run_y + 1 } else { 1 };
+        } else {
+            self.run_x = 1;
+            self.run_y = 1;
+        }
+
+        if self.count == self.period {
+            let (old_x, old_y) = self.deque[self.index];
+            self.remove(old_x, old_y);
+        }
+        self.add(x, y);
+
+        if self.run_x >= self.count {
+            self.mean_x = x;
+            self.m2_x = 0.0;
+            self.c_xy = 0.0;
+        }
+        if self.run_y >= self.count {
+            self.mean_y = y;
+            self.m2_y = 0.0;
+            self.c_xy = 0.0;
+        }
+
+        self.deque[self.index] = (x, y);
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+    }
+
+    fn add(&mut self, x: f64, y: f64) {
+        self.count += 1;
+        let n = self.count as f64;
+        let dx = x - self.mean_x;
+        self.mean_x += dx / n;
+        let dy = y - self.mean_y;
+        self.mean_y += dy / n;
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+        self.c_xy += dx * (y - self.mean_y);
+    }
+
+    fn remove(&mut self, x: f64, y: f64) {
+        if self.count == 1 {
+            self.reset_moments();
+            return;
+        }
+
+        let n = (self.count - 1) as f64;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        let mean_x = self.mean_x - dx / n;
+        let mean_y = self.mean_y - dy / n;
+        self.m2_x = (self.m2_x - dx * (x - mean_x)).max(0.0);
+        self.m2_y = (self.m2_y - dy * (y - mean_y)).max(0.0);
+        self.c_xy -= (x - mean_x) * dy;
+        self.mean_x = mean_x;
+        self.mean_y = mean_y;
+        self.count -= 1;
+    }
+
+    fn reset_moments(&mut self) {
+        self.count = 0;
+        self.mean_x = 0.0;
+        self.mean_y = 0.0;
+        self.m2_x = 0.0;
+        self.m2_y = 0.0;
+        self.c_xy = 0.0;
+    }
+
+    /// Number of pairs in the window.
+    pub(crate) fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Sum of the products of the deviations of X and Y from their means.
+    pub(crate) fn c_xy(&self) -> f64 {
+        self.c_xy
+    }
+
+    pub(crate) fn mean_x(&self) -> f64 {
+        self.mean_x
+    }
+
+    pub(crate) fn mean_y(&self) -> f64 {
+        self.mean_y
+    }
+
+    /// Slope of the least-squares regression of X on Y, 0 while Y is constant.
+    pub(crate) fn slope(&self) -> f64 {
+        if self.m2_y > 0.0 {
+            self.c_xy / self.m2_y
+        } else {
+            0.0
+        }
+    }
+
+    /// Pearson correlation of the window, 0 while either series is constant.
+    pub(crate) fn correlation(&self) -> f64 {
+        if self.m2_x > 0.0 && self.m2_y > 0.0 {
+            (self.c_xy / (self.m2_x * self.m2_y).sqrt()).clamp(-1.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.index = 0;
+        self.run_x = 0;
+        self.run_y = 0;
+        self.reset_moments();
+        for i in 0..self.period {
+            self.deque[i] = (0.0, 0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_push() {
+        let mut m = RollingCoMoments::new(3).unwrap();
+
+        m.push(1.0, 2.0);
+        m.push(2.0, 4.0);
+        m.push(3.0, 5.0);
+        assert_eq!((round(m.mean_x), round(m.mean_y)), (2.0, 3.667));
+        assert_eq!((round(m.m2_x), round(m.m2_y)), (2.0, 4.667));
+        assert_eq!(round(m.c_xy), 3.0);
+
+        // (1, 2) leaves the window
+        m.push(4.0, 1.0);
+        assert_eq!(m.count, 3);
+        assert_eq!((round(m.mean_x), round(m.mean_y)), (3.0, 3.333));
+        assert_eq!((round(m.m2_x), round(m.m2_y)), (2.0, 8.667));
+        assert_eq!(round(m.c_xy), -3.0);
+    }
+
+    #[test]
+    fn test_constant_after_varying() {
+        let mut m = RollingCoMoments::new(3).unwrap();
+
+        for &(x, y) in &[(0.013, 0.1), (0.029, 0.3), (0.3, 0.129), (0.429, 0.113)] {
+            m.push(x, y);
+        }
+        m.push(0.429, 0.142);
+        m.push(0.429, 0.442);
+        assert_eq!((m.mean_x, m.m2_x, m.c_xy), (0.429, 0.0, 0.0));
+        assert_eq!(m.correlation(), 0.0);
+
+        for &(x, y) in &[(0.1, 0.442), (0.3, 0.442), (0.013, 0.442)] {
+            m.push(x, y);
+        }
+        assert_eq!((m.m2_y, m.c_xy), (0.0, 0.0));
+        assert_eq!(m.slope(), 0.0);
+        assert_eq!(m.correlation(), 0.0);
+    }
+
+    #[test]
+    fn test_period_one() {
+        let mut m = RollingCoMoments::new(1).unwrap();
+
+        m.push(1.0, 2.0);
+        m.push(5.0, 7.0);
+        assert_eq!((m.mean_x, m.mean_y), (5.0, 7.0));
+        assert_eq!((m.m2_x, m.m2_y, m.c_xy), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut m = RollingCoMoments::new(3).unwrap();
+
+        m.push(1.0, 2.0);
+        m.push(2.0, 4.0);
+        m.reset();
+        assert_eq!(m.count, 0);
+        m.push(3.0, 3.0);
+        assert_eq!((m.mean_x, m.c_xy), (3.0, 0.0));
+    }
+}
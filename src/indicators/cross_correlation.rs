@@ -0,0 +1,318 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Lead-lag cross-correlation of two series.
+///
+/// Maintains the Pearson correlation of two series over the last _period_ pairs of values at every
+/// lag from -_max lag_ to _max lag_, and reports the lag with the strongest correlation. Every
+/// input is a pair of values _(X, Y)_, typically the returns of two instruments.
+///
+/// At a positive lag _k_, X leads Y: the values of X are paired with the values of Y _k_ bars
+/// later. At a negative lag Y leads X. The correlations are recomputed every bar, which takes
+/// O(_period_ × _max lag_).
+///
+/// # Formula
+///
+/// ρ<sub>k</sub> = corr(X<sub>t-k</sub>, Y<sub>t</sub>) for _k_ ≥ 0, and
+/// ρ<sub>k</sub> = corr(X<sub>t</sub>, Y<sub>t+k</sub>) for _k_ < 0
+///
+/// over the last _period_ pairs available at the lag. The best lag is the one with the greatest
+/// |ρ<sub>k</sub>|; on ties the one closest to 0, and the positive one of two opposite lags.
+/// A lag is skipped until it has two pairs, and while either of them is constant. When no lag has
+/// a correlation yet the output is lag 0 with a correlation of 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of pairs (integer greater than 1). Default is 20.
+/// * _max_lag_ - greatest lag in bars scanned in both directions (integer). Default is 5.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::CrossCorrelation;
+/// use tars::Nexta;
+///
+/// let mut xcorr = CrossCorrelation::new(3, 1).unwrap();
+///
+/// // y repeats x one bar later
+/// xcorr.nexta((1.0, 0.0));
+/// xcorr.nexta((3.0, 1.0));
+/// xcorr.nexta((2.0, 3.0));
+/// let out = xcorr.nexta((5.0, 2.0));
+/// assert_eq!(out.lag, 1);
+/// assert!(out.correlation > 0.999);
+/// ```
+///
+/// # Links
+///
+/// * [Cross-correlation, Wikipedia](https://en.wikipedia.org/wiki/Cross-correlation)
+///
+#[doc(alias = "XCORR")]
+#[doc(alias = "Lead-lag")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CrossCorrelation {
+    period: usize,
+    max_lag: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[(f64, f64)]>,
+    // correlations from lag -max_lag to max_lag
+    correlations: Box<[Option<f64>]>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrossCorrelationOutput {
+    /// Lag with the strongest correlation, positive when X leads Y.
+    pub lag: isize,
+    /// Correlation at the lag.
+    pub correlation: f64,
+}
+
+impl CrossCorrelation {
+    pub fn new(period: usize, max_lag: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            period,
+            max_lag,
+            index: 0,
+            count: 0,
+            deque: vec![(0.0, 0.0); period + max_lag].into_boxed_slice(),
+            correlations: vec![None; 2 * max_lag + 1].into_boxed_slice(),
+        })
+    }
+
+    pub fn max_lag(&self) -> usize {
+        self.max_lag
+    }
+
+    /// Correlation at the given lag after the last input, `None` when it is not defined yet or
+    /// the lag is out of range.
+    pub fn correlation(&self, lag: isize) -> Option<f64> {
+        if lag.unsigned_abs() > self.max_lag {
+            return None;
+        }
+        self.correlations[(lag + self.max_lag as isize) as usize]
+    }
+
+    // pair of values `back` bars ago
+    fn get(&self, back: usize) -> (f64, f64) {
+        let len = self.deque.len();
+        self.deque[(self.index + len - 1 - back) % len]
+    }
+
+    fn correlate(&self, lag: isize) -> Option<f64> {
+        let shift = lag.unsigned_abs();
+        let n = self.period.min(self.count.saturating_sub(shift));
+        if n < 2 {
+            return None;
+        }
+
+        let pair = |i: usize| -> (f64, f64) {
+            if lag >= 0 {
+                (self.get(i + shift).0, self.get(i).1)
+            } else {
+                (self.get(i).0, self.get(i + shift).1)
+            }
+        };
+
+        let (mut mean_x, mut mean_y) = (0.0, 0.0);
+        for i in 0..n {
+            let (x, y) = pair(i);
+            mean_x += x;
+            mean_y += y;
+        }
+        mean_x /= n as f64;
+        mean_y /= n as f64;
+
+        let (mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0);
+        for i in 0..n {
+            let (x, y) = pair(i);
+            let (dx, dy) = (x - mean_x, y - mean_y);
+            sxx += dx * dx;
+            syy += dy * dy;
+            sxy += dx * dy;
+        }
+
+        if sxx > 0.0 && syy > 0.0 {
+            Some((sxy / (sxx * syy).sqrt()).clamp(-1.0, 1.0))
+        } else {
+            None
+        }
+    }
+}
+
+impl Period for CrossCorrelation {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<(f64, f64)> for CrossCorrelation {
+    type Output = CrossCorrelationOutput;
+
+    fn nexta(&mut self, input: (f64, f64)) -> Self::Output {
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.deque.len() {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.deque.len() {
+            self.count += 1;
+        }
+
+        let max_lag = self.max_lag as isize;
+        for lag in -max_lag..=max_lag {
+            self.correlations[(lag + max_lag) as usize] = self.correlate(lag);
+        }
+
+        let mut best = CrossCorrelationOutput {
+            lag: 0,
+            correlation: 0.0,
+        };
+        let mut best_abs = f64::NEG_INFINITY;
+        // scan outwards from lag 0, strict comparison keeps the lag closest to 0 on ties
+        for shift in 0..=max_lag {
+            for &lag in &[shift, -shift] {
+                if let Some(correlation) = self.correlation(lag) {
+                    if correlation.abs() > best_abs {
+                        best_abs = correlation.abs();
+                        best = CrossCorrelationOutput { lag, correlation };
+                    }
+                }
+            }
+        }
+
+        best
+    }
+}
+
+impl Reset for CrossCorrelation {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.deque.len() {
+            self.deque[i] = (0.0, 0.0);
+        }
+        for i in 0..self.correlations.len() {
+            self.correlations[i] = None;
+        }
+    }
+}
+
+impl Default for CrossCorrelation {
+    fn default() -> Self {
+        Self::new(20, 5).unwrap()
+    }
+}
+
+impl fmt::Display for CrossCorrelation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "XCORR({}, {})", self.period, self.max_lag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    const X: [f64; 8] = [1.0, 3.0, 2.0, 5.0, 4.0, 6.0, 3.0, 7.0];
+
+    #[test]
+    fn test_new() {
+        assert!(CrossCorrelation::new(1, 5).is_err());
+        assert!(CrossCorrelation::new(2, 0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut xcorr = CrossCorrelation::new(4, 2).unwrap();
+
+        let first = xcorr.nexta((X[0], 0.0));
+        assert_eq!(
+            first,
+            CrossCorrelationOutput {
+                lag: 0,
+                correlation: 0.0
+            }
+        );
+        assert_eq!(xcorr.correlation(0), None);
+
+        // y repeats x two bars later
+        let mut out = first;
+        for t in 1..X.len() {
+            let y = if t >= 2 { X[t - 2] } else { 0.0 };
+            out = xcorr.nexta((X[t], y));
+        }
+        assert_eq!(out.lag, 2);
+        assert_eq!(round(out.correlation), 1.0);
+        assert_eq!(round(xcorr.correlation(0).unwrap()), 0.748);
+        assert_eq!(xcorr.correlation(3), None);
+    }
+
+    #[test]
+    fn test_next_y_leads() {
+        let mut xcorr = CrossCorrelation::new(4, 2).unwrap();
+
+        let mut out = xcorr.nexta((0.0, X[0]));
+        for t in 1..X.len() {
+            out = xcorr.nexta((X[t - 1], X[t]));
+        }
+        assert_eq!(out.lag, -1);
+        assert_eq!(round(out.correlation), 1.0);
+    }
+
+    #[test]
+    fn test_next_negative_correlation() {
+        let mut xcorr = CrossCorrelation::new(4, 1).unwrap();
+
+        let mut out = xcorr.nexta((X[0], -X[0]));
+        for &x in &X[1..] {
+            out = xcorr.nexta((x, -x));
+        }
+        assert_eq!(out.lag, 0);
+        assert_eq!(round(out.correlation), -1.0);
+    }
+
+    #[test]
+    fn test_constant_series() {
+        let mut xcorr = CrossCorrelation::new(3, 1).unwrap();
+
+        xcorr.nexta((1.0, 2.0));
+        xcorr.nexta((2.0, 2.0));
+        let out = xcorr.nexta((3.0, 2.0));
+        assert_eq!(out.correlation, 0.0);
+        assert_eq!(xcorr.correlation(0), None);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut xcorr = CrossCorrelation::new(3, 1).unwrap();
+
+        xcorr.nexta((1.0, 1.0));
+        xcorr.nexta((2.0, 3.0));
+        xcorr.reset();
+        assert_eq!(xcorr.correlation(0), None);
+        assert_eq!(xcorr.nexta((3.0, 1.0)).correlation, 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        CrossCorrelation::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let xcorr = CrossCorrelation::new(30, 3).unwrap();
+        assert_eq!(format!("{}", xcorr), "XCORR(30, 3)");
+    }
+}
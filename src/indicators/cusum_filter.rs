@@ -0,0 +1,238 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// CUSUM filter.
+///
+/// Symmetric cumulative sum filter for change-point detection and event-based sampling. Every
+/// log return is standardized with the mean and standard deviation of the returns before it, and
+/// the standardized returns are summed up separately in the upward and the downward direction.
+/// When one of the sums exceeds the threshold, `nexta` returns a
+/// [CusumEvent](enum.CusumEvent.html) and the sum starts over. Small moves that revert cancel
+/// out and produce no events, while a persistent drift does.
+///
+/// # Formula
+///
+/// * _z<sub>t</sub>_ = (r<sub>t</sub> - mean of r<sub>1</sub>..r<sub>t-1</sub>) / SD of
+///   r<sub>1</sub>..r<sub>t-1</sub>
+/// * _S<sup>+</sup><sub>t</sub>_ = max(0, S<sup>+</sup><sub>t-1</sub> + z<sub>t</sub>), an
+///   [Up](enum.CusumEvent.html#variant.Up) event when above _threshold_
+/// * _S<sup>-</sup><sub>t</sub>_ = min(0, S<sup>-</sup><sub>t-1</sub> + z<sub>t</sub>), a
+///   [Down](enum.CusumEvent.html#variant.Down) event when below -_threshold_
+///
+/// Where _r<sub>t</sub>_ = ln(Price<sub>t</sub> / Price<sub>t-1</sub>). The mean and the
+/// population standard deviation are computed over all returns seen so far. Until two returns
+/// are known, or while their standard deviation is 0, the sums are not updated.
+///
+/// # Parameters
+///
+/// * _threshold_ - threshold in standard deviations (number greater than 0). Default is 4.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{CusumEvent, CusumFilter};
+/// use tars::Nexta;
+///
+/// let mut cusum = CusumFilter::new(2.0).unwrap();
+///
+/// for &price in &[100.0, 101.0, 100.0, 101.0, 100.0, 101.0] {
+///     assert_eq!(cusum.nexta(price), None);
+/// }
+/// assert_eq!(cusum.nexta(103.0), Some(CusumEvent::Up));
+/// ```
+///
+/// # Links
+///
+/// * [CUSUM, Wikipedia](https://en.wikipedia.org/wiki/CUSUM)
+///
+#[doc(alias = "CUSUM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CusumFilter {
+    threshold: f64,
+    prev: Option<f64>,
+    count: usize,
+    mean: f64,
+    m2: f64,
+    upper: f64,
+    lower: f64,
+}
+
+/// Direction of a [CusumFilter](struct.CusumFilter.html) event.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CusumEvent {
+    Up,
+    Down,
+}
+
+impl CusumFilter {
+    pub fn new(threshold: f64) -> Result<Self> {
+        if !(threshold.is_finite() && threshold > 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            threshold,
+            prev: None,
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            upper: 0.0,
+            lower: 0.0,
+        })
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn update(&mut self, ret: f64) -> Option<CusumEvent> {
+        let mut event = None;
+
+        if self.count >= 2 && self.m2 > 0.0 {
+            let sd = (self.m2 / self.count as f64).sqrt();
+            let z = (ret - self.mean) / sd;
+
+            self.upper = (self.upper + z).max(0.0);
+            self.lower = (self.lower + z).min(0.0);
+            if self.upper > self.threshold {
+                self.upper = 0.0;
+                event = Some(CusumEvent::Up);
+            } else if self.lower < -self.threshold {
+                self.lower = 0.0;
+                event = Some(CusumEvent::Down);
+            }
+        }
+
+        // Welford's online algorithm
+        self.count += 1;
+        let delta = ret - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (ret - self.mean);
+
+        event
+    }
+}
+
+impl Nexta<f64> for CusumFilter {
+    type Output = Option<CusumEvent>;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        match self.prev.replace(input) {
+            Some(prev) => self.update((input / prev).ln()),
+            None => None,
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for CusumFilter {
+    type Output = Option<CusumEvent>;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for CusumFilter {
+    fn reset(&mut self) {
+        self.prev = None;
+        self.count = 0;
+        self.mean = 0.0;
+        self.m2 = 0.0;
+        self.upper = 0.0;
+        self.lower = 0.0;
+    }
+}
+
+impl Default for CusumFilter {
+    fn default() -> Self {
+        Self::new(4.0).unwrap()
+    }
+}
+
+impl fmt::Display for CusumFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CUSUM({})", self.threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(CusumFilter);
+
+    #[test]
+    fn test_new() {
+        assert!(CusumFilter::new(0.0).is_err());
+        assert!(CusumFilter::new(-1.0).is_err());
+        assert!(CusumFilter::new(f64::INFINITY).is_err());
+        assert!(CusumFilter::new(0.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cusum = CusumFilter::new(2.0).unwrap();
+
+        let prices = [
+            100.0, 101.0, 100.0, 101.0, 100.0, 101.0, 103.0, 105.0, 107.0, 106.0, 104.0, 102.0,
+            100.0,
+        ];
+        let events: Vec<_> = prices.iter().map(|&p| cusum.nexta(p)).collect();
+
+        let up = Some(CusumEvent::Up);
+        let down = Some(CusumEvent::Down);
+        assert_eq!(
+            events,
+            vec![None, None, None, None, None, None, up, None, up, None, down, None, down]
+        );
+    }
+
+    #[test]
+    fn test_flat_prices() {
+        let mut cusum = CusumFilter::new(1.0).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(cusum.nexta(100.0), None);
+        }
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut cusum = CusumFilter::new(2.0).unwrap();
+
+        for &close in &[100.0, 101.0, 100.0, 101.0, 100.0, 101.0] {
+            assert_eq!(cusum.nexta(&Bar::new().close(close)), None);
+        }
+        assert_eq!(cusum.nexta(&Bar::new().close(103.0)), Some(CusumEvent::Up));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cusum = CusumFilter::new(2.0).unwrap();
+
+        for &price in &[100.0, 101.0, 100.0, 101.0, 100.0, 101.0] {
+            cusum.nexta(price);
+        }
+        cusum.reset();
+        assert_eq!(cusum.nexta(103.0), None);
+        assert_eq!(cusum.nexta(90.0), None);
+    }
+
+    #[test]
+    fn test_default() {
+        CusumFilter::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cusum = CusumFilter::new(2.5).unwrap();
+        assert_eq!(format!("{}", cusum), "CUSUM(2.5)");
+    }
+}
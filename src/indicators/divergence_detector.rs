@@ -0,0 +1,440 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{PivotDetector, RelativeStrengthIndex};
+use crate::{High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Divergence between price and an oscillator.
+///
+/// Finds the swing points of the price with a [PivotDetector](struct.PivotDetector.html) and
+/// compares every confirmed pivot with the previous pivot of the same kind, both in price and in
+/// the value the wrapped oscillator had on the pivot bars. A divergence is reported when the two
+/// disagree:
+///
+/// * _Regular bullish_ - price makes a lower low, the oscillator a higher low.
+/// * _Hidden bullish_ - price makes a higher low, the oscillator a lower low.
+/// * _Regular bearish_ - price makes a higher high, the oscillator a lower high.
+/// * _Hidden bearish_ - price makes a lower high, the oscillator a higher high.
+///
+/// Regular divergences hint at a reversal, hidden divergences at a continuation of the trend.
+/// Since a pivot is confirmed _right_ bars after it happened, so is the divergence; `nexta`
+/// returns the divergences found on the bar, usually none or one.
+///
+/// Any indicator with `f64` output can be wrapped, e.g. RSI, CCI or MFI. For indicators with
+/// several outputs such as MACD, create the detector with
+/// [external](struct.DivergenceDetector.html#method.external) and feed the chosen line with
+/// [update](struct.DivergenceDetector.html#method.update) instead of `nexta`.
+///
+/// # Parameters
+///
+/// * _oscillator_ - any indicator with `f64` output. Default is RSI(14).
+/// * _left_ - number of bars before a pivot (integer greater than 0). Default is 5.
+/// * _right_ - number of bars after a pivot (integer greater than 0). Default is 5.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{
+///     DivergenceDetector, DivergenceKind, MovingAverageConvergenceDivergence as Macd,
+/// };
+/// use tars::Nexta;
+///
+/// let mut macd = Macd::new(3, 6, 4).unwrap();
+/// let mut divergences = DivergenceDetector::external(1, 1).unwrap();
+///
+/// let mut found = Vec::new();
+/// for &price in &[10.0, 11.0, 12.0, 13.0, 12.0, 11.0, 12.0, 13.5, 12.5] {
+///     let line = macd.nexta(price).macd;
+///     found.extend(divergences.update(price, price, line));
+/// }
+/// // a higher high in price with a lower high of the MACD line
+/// assert_eq!(found[0].kind, DivergenceKind::RegularBearish);
+/// ```
+///
+/// # Links
+///
+/// * [Divergence, Investopedia](https://www.investopedia.com/terms/d/divergence.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DivergenceDetector<I = RelativeStrengthIndex> {
+    oscillator: I,
+    pivots: PivotDetector,
+    count: usize,
+    values: Box<[f64]>,
+    last_high: Option<SwingValue>,
+    last_low: Option<SwingValue>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+struct SwingValue {
+    index: usize,
+    price: f64,
+    value: f64,
+}
+
+/// Oscillator of a [DivergenceDetector](struct.DivergenceDetector.html) whose values are passed
+/// to [update](struct.DivergenceDetector.html#method.update) by the caller.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExternalOscillator;
+
+impl Reset for ExternalOscillator {
+    fn reset(&mut self) {}
+}
+
+impl fmt::Display for ExternalOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EXTERNAL")
+    }
+}
+
+/// Kind of a [Divergence](struct.Divergence.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivergenceKind {
+    RegularBullish,
+    HiddenBullish,
+    RegularBearish,
+    HiddenBearish,
+}
+
+/// Confirmed divergence between two pivots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    pub kind: DivergenceKind,
+    /// Price of the pivot: its low for bullish and its high for bearish divergences.
+    pub price: f64,
+    /// Oscillator value on the pivot bar.
+    pub value: f64,
+    /// Price of the previous pivot of the same kind.
+    pub previous_price: f64,
+    /// Oscillator value on the previous pivot bar.
+    pub previous_value: f64,
+    /// Number of bars between the two pivots.
+    pub bars: usize,
+    /// Number of bars between the pivot and the bar that confirmed it, i.e. _right_.
+    pub offset: usize,
+}
+
+impl DivergenceDetector<ExternalOscillator> {
+    /// Creates a detector without an oscillator of its own, to be fed with
+    /// [update](struct.DivergenceDetector.html#method.update).
+    pub fn external(left: usize, right: usize) -> Result<Self> {
+        Self::new(ExternalOscillator, left, right)
+    }
+}
+
+impl<I> DivergenceDetector<I> {
+    pub fn new(oscillator: I, left: usize, right: usize) -> Result<Self> {
+        Ok(Self {
+            oscillator,
+            pivots: PivotDetector::new(left, right)?,
+            count: 0,
+            values: vec![0.0; right + 1].into_boxed_slice(),
+            last_high: None,
+            last_low: None,
+        })
+    }
+
+    /// Returns the wrapped oscillator.
+    pub fn oscillator(&self) -> &I {
+        &self.oscillator
+    }
+
+    pub fn left(&self) -> usize {
+        self.pivots.left()
+    }
+
+    pub fn right(&self) -> usize {
+        self.pivots.right()
+    }
+
+    /// Processes a bar given by its price range and the oscillator value calculated for it,
+    /// bypassing the wrapped oscillator.
+    pub fn update(&mut self, high: f64, low: f64, value: f64) -> Vec<Divergence> {
+        let len = self.values.len();
+        self.values[self.count % len] = value;
+        self.count += 1;
+
+        let pivot = match self.pivots.nexta(&Range(high, low)) {
+            Some(pivot) => pivot,
+            None => return Vec::new(),
+        };
+
+        let offset = pivot.offset;
+        // the pivot bar is the oldest value kept
+        let index = self.count - 1 - offset;
+        let value = self.values[index % len];
+        let mut divergences = Vec::new();
+
+        if let Some(price) = pivot.low {
+            let current = SwingValue {
+                index,
+                price,
+                value,
+            };
+            if let Some(previous) = self.last_low.replace(current) {
+                let kind = if price < previous.price && value > previous.value {
+                    Some(DivergenceKind::RegularBullish)
+                } else if price > previous.price && value < previous.value {
+                    Some(DivergenceKind::HiddenBullish)
+                } else {
+                    None
+                };
+                divergences.extend(kind.map(|kind| divergence(kind, &previous, &current, offset)));
+            }
+        }
+
+        if let Some(price) = pivot.high {
+            let current = SwingValue {
+                index,
+                price,
+                value,
+            };
+            if let Some(previous) = self.last_high.replace(current) {
+                let kind = if price > previous.price && value < previous.value {
+                    Some(DivergenceKind::RegularBearish)
+                } else if price < previous.price && value > previous.value {
+                    Some(DivergenceKind::HiddenBearish)
+                } else {
+                    None
+                };
+                divergences.extend(kind.map(|kind| divergence(kind, &previous, &current, offset)));
+            }
+        }
+
+        divergences
+    }
+}
+
+fn divergence(
+    kind: DivergenceKind,
+    previous: &SwingValue,
+    current: &SwingValue,
+    offset: usize,
+) -> Divergence {
+    Divergence {
+        kind,
+        price: current.price,
+        value: current.value,
+        previous_price: previous.price,
+        previous_value: previous.value,
+        bars: current.index - previous.index,
+        offset,
+    }
+}
+
+struct Range(f64, f64);
+
+impl High for Range {
+    fn high(&self) -> f64 {
+        self.0
+    }
+}
+
+impl Low for Range {
+    fn low(&self) -> f64 {
+        self.1
+    }
+}
+
+impl<I> Period for DivergenceDetector<I> {
+    fn period(&self) -> usize {
+        self.pivots.period()
+    }
+}
+
+impl<I: Nexta<f64, Output = f64>> Nexta<f64> for DivergenceDetector<I> {
+    type Output = Vec<Divergence>;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let value = self.oscillator.nexta(input);
+        self.update(input, input, value)
+    }
+}
+
+impl<'a, T: High + Low, I: Nexta<&'a T, Output = f64>> Nexta<&'a T> for DivergenceDetector<I> {
+    type Output = Vec<Divergence>;
+
+    fn nexta(&mut self, input: &'a T) -> Self::Output {
+        let value = self.oscillator.nexta(input);
+        self.update(input.high(), input.low(), value)
+    }
+}
+
+impl<I: Reset> Reset for DivergenceDetector<I> {
+    fn reset(&mut self) {
+        self.oscillator.reset();
+        self.pivots.reset();
+        self.count = 0;
+        for i in 0..self.values.len() {
+            self.values[i] = 0.0;
+        }
+        self.last_high = None;
+        self.last_low = None;
+    }
+}
+
+impl Default for DivergenceDetector {
+    fn default() -> Self {
+        Self::new(RelativeStrengthIndex::default(), 5, 5).unwrap()
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for DivergenceDetector<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "DIVERGENCE({}, {}, {})",
+            self.oscillator,
+            self.pivots.left(),
+            self.pivots.right()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{CommodityChannelIndex, RelativeStrengthIndex as Rsi};
+    use crate::test_helper::*;
+
+    type Dd = DivergenceDetector<Rsi>;
+
+    test_indicator!(Dd);
+
+    fn kinds(divergences: &[Divergence]) -> Vec<DivergenceKind> {
+        divergences.iter().map(|d| d.kind).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(DivergenceDetector::external(0, 1).is_err());
+        assert!(DivergenceDetector::external(1, 0).is_err());
+        assert!(DivergenceDetector::external(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_bullish() {
+        let mut dd = DivergenceDetector::external(1, 1).unwrap();
+
+        assert!(dd.update(10.0, 10.0, 40.0).is_empty());
+        assert!(dd.update(9.0, 9.0, 30.0).is_empty());
+        // low at 9.0 confirmed, no previous low
+        assert!(dd.update(10.0, 10.0, 45.0).is_empty());
+        dd.update(11.0, 11.0, 50.0);
+        dd.update(8.0, 8.0, 35.0);
+        // lower low in price, higher low in the oscillator
+        let found = dd.update(9.0, 9.0, 40.0);
+        assert_eq!(
+            found,
+            vec![Divergence {
+                kind: DivergenceKind::RegularBullish,
+                price: 8.0,
+                value: 35.0,
+                previous_price: 9.0,
+                previous_value: 30.0,
+                bars: 3,
+                offset: 1,
+            }]
+        );
+
+        dd.update(10.0, 10.0, 50.0);
+        dd.update(8.5, 8.5, 20.0);
+        // higher low in price, lower low in the oscillator
+        assert_eq!(
+            kinds(&dd.update(9.5, 9.5, 30.0)),
+            vec![DivergenceKind::HiddenBullish]
+        );
+    }
+
+    #[test]
+    fn test_bearish() {
+        let mut dd = DivergenceDetector::external(1, 1).unwrap();
+
+        dd.update(10.0, 10.0, 50.0);
+        dd.update(12.0, 12.0, 70.0);
+        dd.update(11.0, 11.0, 60.0);
+        dd.update(13.0, 13.0, 65.0);
+        // higher high in price, lower high in the oscillator
+        assert_eq!(
+            kinds(&dd.update(12.0, 12.0, 55.0)),
+            vec![DivergenceKind::RegularBearish]
+        );
+        dd.update(12.5, 12.5, 75.0);
+        // lower high in price, higher high in the oscillator
+        assert_eq!(
+            kinds(&dd.update(11.0, 11.0, 50.0)),
+            vec![DivergenceKind::HiddenBearish]
+        );
+    }
+
+    #[test]
+    fn test_no_divergence() {
+        let mut dd = DivergenceDetector::external(1, 1).unwrap();
+
+        let prices = [10.0, 12.0, 11.0, 13.0, 12.0, 14.0, 13.0];
+        for &price in &prices {
+            // the oscillator confirms every move of the price
+            assert!(dd.update(price, price, price * 5.0).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let cci = CommodityChannelIndex::new(3).unwrap();
+        let mut dd = DivergenceDetector::new(cci, 1, 1).unwrap();
+
+        let bar = |high: f64, low: f64| Bar::new().high(high).low(low).close((high + low) / 2.0);
+        for &(high, low) in &[(11.0, 9.0), (13.0, 11.0), (12.0, 10.0), (12.5, 10.5)] {
+            assert!(dd.nexta(&bar(high, low)).is_empty());
+        }
+        assert_eq!(dd.right(), 1);
+    }
+
+    #[test]
+    fn test_wide_pivot_bar() {
+        let mut dd = DivergenceDetector::external(1, 1).unwrap();
+
+        dd.update(10.0, 9.0, 50.0);
+        dd.update(11.0, 8.0, 60.0);
+        dd.update(10.0, 9.0, 50.0);
+        dd.update(12.0, 7.0, 55.0);
+        // higher high with a lower value, and lower low with a lower value
+        assert_eq!(
+            kinds(&dd.update(10.0, 9.0, 50.0)),
+            vec![DivergenceKind::RegularBearish]
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dd = DivergenceDetector::external(1, 1).unwrap();
+
+        dd.update(10.0, 10.0, 40.0);
+        dd.update(9.0, 9.0, 30.0);
+        dd.update(10.0, 10.0, 45.0);
+        dd.reset();
+        dd.update(11.0, 11.0, 50.0);
+        dd.update(8.0, 8.0, 35.0);
+        assert!(dd.update(9.0, 9.0, 40.0).is_empty());
+    }
+
+    #[test]
+    fn test_default() {
+        let dd = DivergenceDetector::default();
+        assert_eq!(format!("{}", dd), "DIVERGENCE(RSI(14), 5, 5)");
+    }
+
+    #[test]
+    fn test_display() {
+        let dd = DivergenceDetector::new(Rsi::new(7).unwrap(), 3, 2).unwrap();
+        assert_eq!(format!("{}", dd), "DIVERGENCE(RSI(7), 3, 2)");
+
+        let dd = DivergenceDetector::external(1, 1).unwrap();
+        assert_eq!(format!("{}", dd), "DIVERGENCE(EXTERNAL, 1, 1)");
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling downside deviation.
+///
+/// Measures the dispersion of the returns that fall short of a target return, ignoring the
+/// returns above it. It is the risk measure of the Sortino ratio and a downside-aware
+/// alternative to the standard deviation for position sizing. The inputs are returns, not
+/// prices.
+///
+/// # Formula
+///
+/// DD = √(Σ min(r<sub>i</sub> - _target_, 0)<sup>2</sup> / n)
+///
+/// Where:
+///
+/// * _r<sub>i</sub>_ - return in the window
+/// * _n_ - number of returns in the window, including those above the target
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 0). Default is 20.
+/// * _target_ - minimum acceptable return (number). Default is 0.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::DownsideDeviation;
+/// use tars::Nexta;
+///
+/// let mut dd = DownsideDeviation::new(2, 0.0).unwrap();
+///
+/// assert_eq!(dd.nexta(0.03), 0.0);
+/// assert_eq!(dd.nexta(-0.04), 0.0008f64.sqrt());
+/// ```
+///
+/// # Links
+///
+/// * [Downside risk, Wikipedia](https://en.wikipedia.org/wiki/Downside_risk)
+///
+#[doc(alias = "DD")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DownsideDeviation {
+    period: usize,
+    target: f64,
+    index: usize,
+    count: usize,
+    sum: f64,
+    // number of shortfalls in the window, the sum is zeroed at 0 to drop float residue
+    shortfalls: usize,
+    deque: Box<[f64]>,
+}
+
+impl DownsideDeviation {
+    pub fn new(period: usize, target: f64) -> Result<Self> {
+        if period == 0 || !target.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            period,
+            target,
+            index: 0,
+            count: 0,
+            sum: 0.0,
+            shortfalls: 0,
+            deque: vec![0.0; period].into_boxed_slice(),
+        })
+    }
+
+    pub fn target(&self) -> f64 {
+        self.target
+    }
+}
+
+impl Period for DownsideDeviation {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for DownsideDeviation {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let shortfall = (input - self.target).min(0.0);
+        let squared = shortfall * shortfall;
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old_val = self.deque[self.index];
+            if old_val > 0.0 {
+                self.shortfalls -= 1;
+            }
+            self.sum -= old_val;
+        }
+        if squared > 0.0 {
+            self.shortfalls += 1;
+        }
+        self.sum += squared;
+        if self.sum < 0.0 || self.shortfalls == 0 {
+            self.sum = 0.0;
+        }
+
+        self.deque[self.index] = squared;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        (self.sum / self.count as f64).sqrt()
+    }
+}
+
+impl Reset for DownsideDeviation {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        self.shortfalls = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for DownsideDeviation {
+    fn default() -> Self {
+        Self::new(20, 0.0).unwrap()
+    }
+}
+
+impl fmt::Display for DownsideDeviation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DD({}, {})", self.period, self.target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(DownsideDeviation::new(0, 0.0).is_err());
+        assert!(DownsideDeviation::new(1, f64::NAN).is_err());
+        assert!(DownsideDeviation::new(1, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut dd = DownsideDeviation::new(3, 0.0).unwrap();
+
+        assert_eq!(dd.nexta(0.02), 0.0);
+        assert_eq!(round(dd.nexta(-0.03)), 0.021);
+        assert_eq!(round(dd.nexta(-0.04)), 0.029);
+        // 0.02 leaves the window
+        assert_eq!(round(dd.nexta(0.01)), 0.029);
+        assert_eq!(round(dd.nexta(0.05)), 0.023);
+        assert_eq!(dd.nexta(0.01), 0.0);
+    }
+
+    #[test]
+    fn test_next_shortfalls_leave_window() {
+        let mut dd = DownsideDeviation::new(3, 0.0).unwrap();
+
+        for &x in &[-0.013, -0.029, -0.017, -0.3, 0.01, 0.02] {
+            dd.nexta(x);
+        }
+        assert_eq!(dd.nexta(0.03), 0.0);
+    }
+
+    #[test]
+    fn test_next_with_target() {
+        let mut dd = DownsideDeviation::new(2, 0.01).unwrap();
+
+        // shortfalls -0.01 and -0.03
+        assert_eq!(round(dd.nexta(0.0) * 1000.0), 10.0);
+        assert_eq!(round(dd.nexta(-0.02) * 1000.0), 22.361);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dd = DownsideDeviation::new(3, 0.0).unwrap();
+
+        dd.nexta(-0.03);
+        dd.reset();
+        assert_eq!(dd.nexta(0.01), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        DownsideDeviation::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let dd = DownsideDeviation::new(20, 0.005).unwrap();
+        assert_eq!(format!("{}", dd), "DD(20, 0.005)");
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage;
+use crate::{High, Low, Nexta, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ease of Movement (EMV).
+///
+/// Developed by Richard Arms, the indicator relates the change of price to volume. High positive
+/// values indicate that the price rises on low volume, high negative values that it falls on low
+/// volume.
+///
+/// # Formula
+///
+/// EMV = SMA(_period_) of (Distance / Box Ratio)
+///
+/// Where:
+///
+/// * _Distance_ = (High + Low) / 2 - (Prev High + Prev Low) / 2
+/// * _Box Ratio_ = Volume / (High - Low)
+///
+/// Distance is 0 for the first bar. When volume or range of a bar is 0, the raw value of that bar
+/// is 0. The raw value is often scaled up by a constant such as 100,000,000 to make it readable,
+/// which can be done by the caller.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::EaseOfMovement;
+/// use tars::{Nexta, DataItema};
+///
+/// let mut emv = EaseOfMovement::new(1).unwrap();
+///
+/// let di1 = DataItema::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// let di2 = DataItema::builder()
+///             .high(12.0)
+///             .low(10.0)
+///             .close(11.0)
+///             .open(11.0)
+///             .volume(200.0)
+///             .build().unwrap();
+///
+/// assert_eq!(emv.nexta(&di1), 0.0);
+/// assert_eq!(emv.nexta(&di2), 0.02);
+/// ```
+///
+/// # Links
+///
+/// * [Ease of Movement, Wikipedia](https://en.wikipedia.org/wiki/Ease_of_movement)
+///
+#[doc(alias = "EMV")]
+#[doc(alias = "EOM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct EaseOfMovement {
+    sma: SimpleMovingAverage,
+    prev_midpoint: Option<f64>,
+}
+
+impl EaseOfMovement {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: SimpleMovingAverage::new(period)?,
+            prev_midpoint: None,
+        })
+    }
+}
+
+impl Period for EaseOfMovement {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl<T: High + Low + Volume> Nexta<&T> for EaseOfMovement {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let midpoint = (input.high() + input.low()) / 2.0;
+        let distance = match self.prev_midpoint {
+            Some(prev) => midpoint - prev,
+            None => 0.0,
+        };
+        self.prev_midpoint = Some(midpoint);
+
+        let range = input.high() - input.low();
+        let raw = if input.volume() == 0.0 || range == 0.0 {
+            0.0
+        } else {
+            distance * range / input.volume()
+        };
+
+        self.sma.nexta(raw)
+    }
+}
+
+impl Reset for EaseOfMovement {
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.prev_midpoint = None;
+    }
+}
+
+impl Default for EaseOfMovement {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for EaseOfMovement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EMV({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(EaseOfMovement::new(0).is_err());
+        assert!(EaseOfMovement::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut emv = EaseOfMovement::new(2).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).volume(100.0);
+        let bar2 = Bar::new().high(12).low(10).volume(200.0);
+        let bar3 = Bar::new().high(12).low(11).volume(50.0);
+        let bar4 = Bar::new().high(11).low(11).volume(0.0);
+
+        assert_eq!(emv.nexta(&bar1), 0.0);
+        // raw = 2 / (200 / 2) = 0.02
+        assert_eq!(round(emv.nexta(&bar2)), 0.01);
+        // raw = 0.5 / (50 / 1) = 0.01
+        assert_eq!(round(emv.nexta(&bar3)), 0.015);
+        // raw = 0
+        assert_eq!(round(emv.nexta(&bar4)), 0.005);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut emv = EaseOfMovement::new(1).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).volume(100.0);
+        let bar2 = Bar::new().high(12).low(10).volume(200.0);
+
+        emv.nexta(&bar1);
+        emv.reset();
+        assert_eq!(emv.nexta(&bar2), 0.0);
+        assert_eq!(emv.nexta(&bar1), -0.04);
+    }
+
+    #[test]
+    fn test_default() {
+        EaseOfMovement::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let emv = EaseOfMovement::new(10).unwrap();
+        assert_eq!(format!("{}", emv), "EMV(10)");
+    }
+}
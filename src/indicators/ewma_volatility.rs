@@ -0,0 +1,223 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Exponentially weighted moving average volatility (RiskMetrics).
+///
+/// Estimates the volatility of the logarithmic returns of the input with the RiskMetrics
+/// recursion: the squared return of every period is blended into the variance with the weight
+/// 1 - _λ_. Compared to windowed estimators the volatility reacts to shocks immediately and
+/// decays smoothly afterwards.
+///
+/// # Formula
+///
+/// σ<sup>2</sup><sub>t</sub> = _λ_ × σ<sup>2</sup><sub>t-1</sub> + (1 - _λ_) × r<sup>2</sup><sub>t</sub>
+///
+/// Where:
+///
+/// * _r<sub>t</sub>_ = ln(Price<sub>t</sub> / Price<sub>t-1</sub>)
+///
+/// The variance is initialized with the first squared return, the volatility of the first input
+/// is 0. Returns are not computed for prices less than or equal to 0. With annualization the
+/// volatility is multiplied by √_periods per year_.
+///
+/// # Parameters
+///
+/// * _lambda_ - decay factor (number between 0 and 1, exclusive). Default is 0.94, the
+///   RiskMetrics value for daily data.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::EwmaVolatility;
+/// use tars::Nexta;
+///
+/// let mut vol = EwmaVolatility::new(0.94).unwrap().with_annualization(252).unwrap();
+///
+/// assert_eq!(vol.nexta(100.0), 0.0);
+/// assert!(vol.nexta(101.0) > 0.15);
+/// ```
+///
+/// # Links
+///
+/// * [RiskMetrics, Wikipedia](https://en.wikipedia.org/wiki/RiskMetrics)
+///
+#[doc(alias = "EWMA")]
+#[doc(alias = "RiskMetrics")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct EwmaVolatility {
+    lambda: f64,
+    periods_per_year: Option<usize>,
+    variance: Option<f64>,
+    prev: Option<f64>,
+}
+
+impl EwmaVolatility {
+    pub fn new(lambda: f64) -> Result<Self> {
+        if !(lambda > 0.0 && lambda < 1.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            lambda,
+            periods_per_year: None,
+            variance: None,
+            prev: None,
+        })
+    }
+
+    /// Annualizes the volatility of returns sampled _periods_per_year_ times a year, e.g. 252 for
+    /// daily bars.
+    pub fn with_annualization(mut self, periods_per_year: usize) -> Result<Self> {
+        match periods_per_year {
+            0 => Err(TaError::InvalidParameter),
+            _ => {
+                self.periods_per_year = Some(periods_per_year);
+                Ok(self)
+            }
+        }
+    }
+
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl Nexta<f64> for EwmaVolatility {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        if let Some(prev) = self.prev {
+            if prev > 0.0 && input > 0.0 {
+                let r2 = (input / prev).ln().powi(2);
+                self.variance = Some(match self.variance {
+                    Some(variance) => self.lambda * variance + (1.0 - self.lambda) * r2,
+                    None => r2,
+                });
+            }
+        }
+        self.prev = Some(input);
+
+        let sigma = self.variance.unwrap_or(0.0).sqrt();
+        match self.periods_per_year {
+            Some(periods) => sigma * (periods as f64).sqrt(),
+            None => sigma,
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for EwmaVolatility {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for EwmaVolatility {
+    fn reset(&mut self) {
+        self.variance = None;
+        self.prev = None;
+    }
+}
+
+impl Default for EwmaVolatility {
+    fn default() -> Self {
+        Self::new(0.94).unwrap()
+    }
+}
+
+impl fmt::Display for EwmaVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.periods_per_year {
+            Some(periods) => write!(f, "EWMAVOL({}, {})", self.lambda, periods),
+            None => write!(f, "EWMAVOL({})", self.lambda),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(EwmaVolatility);
+
+    #[test]
+    fn test_new() {
+        assert!(EwmaVolatility::new(0.0).is_err());
+        assert!(EwmaVolatility::new(1.0).is_err());
+        assert!(EwmaVolatility::new(0.94).is_ok());
+        assert!(EwmaVolatility::new(0.94)
+            .unwrap()
+            .with_annualization(0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vol = EwmaVolatility::new(0.5).unwrap();
+
+        assert_eq!(vol.nexta(100.0), 0.0);
+        // ln(1.1)
+        assert_eq!(round(vol.nexta(110.0)), 0.095);
+        assert_eq!(round(vol.nexta(99.0)), 0.1);
+        assert_eq!(round(vol.nexta(99.0)), 0.071);
+    }
+
+    #[test]
+    fn test_next_annualized() {
+        let mut vol = EwmaVolatility::new(0.5)
+            .unwrap()
+            .with_annualization(4)
+            .unwrap();
+
+        vol.nexta(100.0);
+        assert_eq!(round(vol.nexta(110.0)), 0.191);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut vol = EwmaVolatility::new(0.5).unwrap();
+
+        assert_eq!(vol.nexta(&Bar::new().close(100)), 0.0);
+        assert_eq!(round(vol.nexta(&Bar::new().close(110))), 0.095);
+    }
+
+    #[test]
+    fn test_non_positive_prices() {
+        let mut vol = EwmaVolatility::new(0.5).unwrap();
+
+        vol.nexta(0.0);
+        assert_eq!(vol.nexta(100.0), 0.0);
+        assert_eq!(round(vol.nexta(110.0)), 0.095);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vol = EwmaVolatility::new(0.5).unwrap();
+
+        vol.nexta(100.0);
+        vol.nexta(110.0);
+
+        vol.reset();
+        assert_eq!(vol.nexta(110.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        EwmaVolatility::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vol = EwmaVolatility::new(0.97).unwrap();
+        assert_eq!(format!("{}", vol), "EWMAVOL(0.97)");
+        let vol = vol.with_annualization(252).unwrap();
+        assert_eq!(format!("{}", vol), "EWMAVOL(0.97, 252)");
+    }
+}
@@ -0,0 +1,378 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{AverageTrueRange as Atr, StandardDeviation as Sd};
+use crate::{Close, High, Low, Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Expected move projection (EM).
+///
+/// Projects a volatility band _horizon_ bars forward from the current close and keeps track of
+/// whether the following prices stay within it. Every call to `nexta` returns the band projected
+/// from the current bar together with the outcome of the projection made _horizon_ bars ago, which
+/// makes it suitable both for volatility-based targets and for evaluating how well the
+/// volatility estimate describes the actual moves.
+///
+/// # Formula
+///
+/// With [Atr](enum.ExpectedMoveSource.html#variant.Atr) volatility:
+///
+/// * _Upper_ = Close + _k_ * ATR * √_horizon_
+/// * _Lower_ = Close - _k_ * ATR * √_horizon_
+///
+/// With [Realized](enum.ExpectedMoveSource.html#variant.Realized) volatility:
+///
+/// * _Upper_ = Close * e<sup>_k_ * σ * √_horizon_</sup>
+/// * _Lower_ = Close * e<sup>-_k_ * σ * √_horizon_</sup>
+///
+/// Where:
+///
+/// * _σ_ - standard deviation of the logarithmic returns of the last _period_ bars
+///
+/// A projection is held when every price of the _horizon_ bars after it is within the band,
+/// bounds included.
+///
+/// # Parameters
+///
+/// * _source_ - volatility used for the projection. Default is ATR(14).
+/// * _horizon_ - number of bars projected forward (integer greater than 0). Default is 5.
+/// * _multiplier_ - number of volatility units, _k_ (number greater than 0). Default is 1.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{ExpectedMove, ExpectedMoveSource};
+/// use tars::Nexta;
+///
+/// let mut em = ExpectedMove::new(ExpectedMoveSource::Atr(1), 1, 2.0).unwrap();
+///
+/// em.nexta(10.0);
+/// let out = em.nexta(11.0);
+/// assert_eq!((out.lower, out.upper), (9.0, 13.0));
+/// assert_eq!(out.held, Some(false));
+///
+/// let out = em.nexta(12.0);
+/// assert_eq!(out.held, Some(true));
+/// assert_eq!(em.hit_rate(), Some(0.5));
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ExpectedMove {
+    source: ExpectedMoveSource,
+    volatility: Volatility,
+    multiplier: f64,
+    horizon: usize,
+    index: usize,
+    count: usize,
+    held: usize,
+    expired: usize,
+    projections: Box<[Projection]>,
+}
+
+/// Volatility used by [ExpectedMove](struct.ExpectedMove.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpectedMoveSource {
+    /// ATR of the given period.
+    Atr(usize),
+    /// Realized volatility of the logarithmic returns of the given period.
+    Realized(usize),
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+enum Volatility {
+    Atr(Atr),
+    Realized { sd: Sd, prev_close: Option<f64> },
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+struct Projection {
+    lower: f64,
+    upper: f64,
+    held: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpectedMoveOutput {
+    pub lower: f64,
+    pub upper: f64,
+    /// Whether the prices stayed within the projection made _horizon_ bars ago, `None` until the
+    /// first projection expires.
+    pub held: Option<bool>,
+}
+
+impl ExpectedMove {
+    pub fn new(source: ExpectedMoveSource, horizon: usize, multiplier: f64) -> Result<Self> {
+        if horizon == 0 || !(multiplier.is_finite() && multiplier > 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let volatility = match source {
+            ExpectedMoveSource::Atr(period) => Volatility::Atr(Atr::new(period)?),
+            ExpectedMoveSource::Realized(period) => Volatility::Realized {
+                sd: Sd::new(period)?,
+                prev_close: None,
+            },
+        };
+
+        Ok(Self {
+            source,
+            volatility,
+            multiplier,
+            horizon,
+            index: 0,
+            count: 0,
+            held: 0,
+            expired: 0,
+            projections: vec![
+                Projection {
+                    lower: 0.0,
+                    upper: 0.0,
+                    held: true,
+                };
+                horizon
+            ]
+            .into_boxed_slice(),
+        })
+    }
+
+    pub fn source(&self) -> ExpectedMoveSource {
+        self.source
+    }
+
+    pub fn horizon(&self) -> usize {
+        self.horizon
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    /// Returns the share of the expired projections that held, `None` until the first projection
+    /// expires.
+    pub fn hit_rate(&self) -> Option<f64> {
+        match self.expired {
+            0 => None,
+            expired => Some(self.held as f64 / expired as f64),
+        }
+    }
+
+    fn realized(sd: &mut Sd, prev_close: &mut Option<f64>, close: f64) -> f64 {
+        let sigma = match *prev_close {
+            Some(prev) if prev > 0.0 && close > 0.0 => sd.nexta((close / prev).ln()),
+            _ => 0.0,
+        };
+        *prev_close = Some(close);
+        sigma
+    }
+
+    fn calculate(&mut self, close: f64, atr: Option<f64>) -> ExpectedMoveOutput {
+        for projection in self.projections.iter_mut().take(self.count) {
+            projection.held &= close >= projection.lower && close <= projection.upper;
+        }
+
+        let held = if self.count == self.horizon {
+            let held = self.projections[self.index].held;
+            self.expired += 1;
+            if held {
+                self.held += 1;
+            }
+            Some(held)
+        } else {
+            self.count += 1;
+            None
+        };
+
+        let scale = self.multiplier * (self.horizon as f64).sqrt();
+        let (lower, upper) = match &mut self.volatility {
+            Volatility::Atr(_) => {
+                let distance = scale * atr.unwrap_or(0.0);
+                (close - distance, close + distance)
+            }
+            Volatility::Realized { sd, prev_close } => {
+                let factor = (scale * Self::realized(sd, prev_close, close)).exp();
+                (close / factor, close * factor)
+            }
+        };
+
+        self.projections[self.index] = Projection {
+            lower,
+            upper,
+            held: true,
+        };
+        self.index = if self.index + 1 < self.horizon {
+            self.index + 1
+        } else {
+            0
+        };
+
+        ExpectedMoveOutput { lower, upper, held }
+    }
+}
+
+impl Nexta<f64> for ExpectedMove {
+    type Output = ExpectedMoveOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let atr = match &mut self.volatility {
+            Volatility::Atr(atr) => Some(atr.nexta(input)),
+            Volatility::Realized { .. } => None,
+        };
+        self.calculate(input, atr)
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for ExpectedMove {
+    type Output = ExpectedMoveOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let atr = match &mut self.volatility {
+            Volatility::Atr(atr) => Some(atr.nexta(input)),
+            Volatility::Realized { .. } => None,
+        };
+        self.calculate(input.close(), atr)
+    }
+}
+
+impl Reset for ExpectedMove {
+    fn reset(&mut self) {
+        match &mut self.volatility {
+            Volatility::Atr(atr) => atr.reset(),
+            Volatility::Realized { sd, prev_close } => {
+                sd.reset();
+                *prev_close = None;
+            }
+        }
+        self.index = 0;
+        self.count = 0;
+        self.held = 0;
+        self.expired = 0;
+    }
+}
+
+impl Default for ExpectedMove {
+    fn default() -> Self {
+        Self::new(ExpectedMoveSource::Atr(14), 5, 1.0).unwrap()
+    }
+}
+
+impl fmt::Display for ExpectedMove {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.source {
+            ExpectedMoveSource::Atr(period) => write!(f, "EM(ATR({}), ", period)?,
+            ExpectedMoveSource::Realized(period) => write!(f, "EM(RV({}), ", period)?,
+        }
+        write!(f, "{}, {})", self.horizon, self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Em = ExpectedMove;
+
+    test_indicator!(Em);
+
+    #[test]
+    fn test_new() {
+        assert!(Em::new(ExpectedMoveSource::Atr(0), 1, 1.0).is_err());
+        assert!(Em::new(ExpectedMoveSource::Realized(0), 1, 1.0).is_err());
+        assert!(Em::new(ExpectedMoveSource::Atr(1), 0, 1.0).is_err());
+        assert!(Em::new(ExpectedMoveSource::Atr(1), 1, 0.0).is_err());
+        assert!(Em::new(ExpectedMoveSource::Atr(1), 1, f64::NAN).is_err());
+        assert!(Em::new(ExpectedMoveSource::Atr(1), 1, 1.0).is_ok());
+        assert!(Em::new(ExpectedMoveSource::Realized(1), 1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next_atr() {
+        let mut em = Em::new(ExpectedMoveSource::Atr(1), 1, 1.0).unwrap();
+
+        let out = em.nexta(10.0);
+        assert_eq!((out.lower, out.upper, out.held), (10.0, 10.0, None));
+
+        let out = em.nexta(12.0);
+        assert_eq!((out.lower, out.upper, out.held), (10.0, 14.0, Some(false)));
+
+        let out = em.nexta(13.0);
+        assert_eq!((out.lower, out.upper, out.held), (12.0, 14.0, Some(true)));
+
+        let out = em.nexta(11.0);
+        assert_eq!(out.held, Some(false));
+
+        assert_eq!(round(em.hit_rate().unwrap()), 0.333);
+    }
+
+    #[test]
+    fn test_next_horizon() {
+        let mut em = Em::new(ExpectedMoveSource::Atr(1), 4, 1.0).unwrap();
+
+        em.nexta(10.0);
+        // ATR = 1, √4 = 2
+        let out = em.nexta(11.0);
+        assert_eq!((out.lower, out.upper, out.held), (9.0, 13.0, None));
+
+        assert_eq!(em.nexta(12.0).held, None);
+        assert_eq!(em.nexta(10.0).held, None);
+        // the projection of the first bar is [10, 10]
+        assert_eq!(em.nexta(11.5).held, Some(false));
+        // the projection of the second bar is [9, 13], but 8.9 is below
+        assert_eq!(em.nexta(8.9).held, Some(false));
+        assert_eq!(em.hit_rate(), Some(0.0));
+    }
+
+    #[test]
+    fn test_next_realized() {
+        let mut em = Em::new(ExpectedMoveSource::Realized(2), 1, 1.0).unwrap();
+
+        assert_eq!(em.nexta(100.0).upper, 100.0);
+        assert_eq!(em.nexta(110.0).upper, 110.0);
+
+        let out = em.nexta(99.0);
+        assert_eq!(round(out.lower), 89.549);
+        assert_eq!(round(out.upper), 109.449);
+        assert_eq!(out.held, Some(false));
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut em = Em::new(ExpectedMoveSource::Atr(1), 1, 1.0).unwrap();
+
+        em.nexta(&Bar::new().high(11).low(9).close(10));
+        let out = em.nexta(&Bar::new().high(12).low(10).close(11));
+        assert_eq!((out.lower, out.upper, out.held), (9.0, 13.0, Some(true)));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut em = Em::new(ExpectedMoveSource::Atr(1), 1, 1.0).unwrap();
+
+        em.nexta(10.0);
+        em.nexta(12.0);
+
+        em.reset();
+        assert_eq!(em.hit_rate(), None);
+        let out = em.nexta(12.0);
+        assert_eq!((out.lower, out.upper, out.held), (12.0, 12.0, None));
+    }
+
+    #[test]
+    fn test_default() {
+        Em::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let em = Em::new(ExpectedMoveSource::Atr(14), 5, 1.5).unwrap();
+        assert_eq!(format!("{}", em), "EM(ATR(14), 5, 1.5)");
+
+        let em = Em::new(ExpectedMoveSource::Realized(20), 10, 2.0).unwrap();
+        assert_eq!(format!("{}", em), "EM(RV(20), 10, 2)");
+    }
+}
@@ -51,7 +51,6 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [Exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Exponential_moving_average)
 ///
-
 #[doc(alias = "EMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -68,7 +67,7 @@ impl ExponentialMovingAverage {
             0 => Err(TaError::InvalidParameter),
             _ => Ok(Self {
                 period,
-                k: 1.0 / period as f64,
+                k: 2.0 / (period + 1) as f64,
                 current: 0.0,
                 is_new: true,
             }),
@@ -121,4 +120,4 @@ impl fmt::Display for ExponentialMovingAverage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "EMA({})", self.period)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,214 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Exponentially weighted covariance and correlation of two series.
+///
+/// Instead of a hard window, every observation is weighted by a decay factor _λ_ raised to its
+/// age, as in the RiskMetrics methodology. The statistics react quickly to changes of the
+/// relationship between the series without the jumps caused by values leaving a window. Every
+/// input is a pair of values _(X, Y)_, typically the returns of two instruments.
+///
+/// # Formula
+///
+/// * _Mean<sub>t</sub>_ = _λ_ × Mean<sub>t-1</sub> + (1 - _λ_) × x<sub>t</sub>
+/// * _Cov<sub>t</sub>_ = _λ_ × (Cov<sub>t-1</sub> + (1 - _λ_) × ΔX × ΔY)
+/// * _Correlation_ = Cov(X, Y) / √(Var(X) × Var(Y))
+///
+/// Where:
+///
+/// * _ΔX_, _ΔY_ - distance of the input from the mean of the previous step
+/// * _Var_ - covariance of a series with itself
+///
+/// The first input initializes the means and yields covariance 0. When the variance of one of
+/// the series is 0 the correlation is 0. For returns with a mean close to 0 the covariance
+/// matches the zero-mean RiskMetrics estimate.
+///
+/// # Parameters
+///
+/// * _lambda_ - decay factor (number between 0 and 1, exclusive). Default is 0.94.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::ExponentiallyWeightedCorrelation;
+/// use tars::Nexta;
+///
+/// let mut corr = ExponentiallyWeightedCorrelation::new(0.5).unwrap();
+///
+/// corr.nexta((1.0, 2.0));
+/// let out = corr.nexta((3.0, 6.0));
+/// assert_eq!(out.covariance, 2.0);
+/// assert_eq!(out.correlation, 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Exponentially weighted moving variance, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Exponentially_weighted_moving_variance_and_standard_deviation)
+///
+#[doc(alias = "EWCORR")]
+#[doc(alias = "EWMA correlation")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ExponentiallyWeightedCorrelation {
+    lambda: f64,
+    is_new: bool,
+    mean_x: f64,
+    mean_y: f64,
+    var_x: f64,
+    var_y: f64,
+    cov: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExponentiallyWeightedCorrelationOutput {
+    pub covariance: f64,
+    pub correlation: f64,
+}
+
+impl ExponentiallyWeightedCorrelation {
+    pub fn new(lambda: f64) -> Result<Self> {
+        if !(lambda > 0.0 && lambda < 1.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            lambda,
+            is_new: true,
+            mean_x: 0.0,
+            mean_y: 0.0,
+            var_x: 0.0,
+            var_y: 0.0,
+            cov: 0.0,
+        })
+    }
+
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl Nexta<(f64, f64)> for ExponentiallyWeightedCorrelation {
+    type Output = ExponentiallyWeightedCorrelationOutput;
+
+    fn nexta(&mut self, (x, y): (f64, f64)) -> Self::Output {
+        if self.is_new {
+            self.is_new = false;
+            self.mean_x = x;
+            self.mean_y = y;
+        } else {
+            let alpha = 1.0 - self.lambda;
+            let dx = x - self.mean_x;
+            let dy = y - self.mean_y;
+
+            self.mean_x += alpha * dx;
+            self.mean_y += alpha * dy;
+            self.var_x = self.lambda * (self.var_x + alpha * dx * dx);
+            self.var_y = self.lambda * (self.var_y + alpha * dy * dy);
+            self.cov = self.lambda * (self.cov + alpha * dx * dy);
+        }
+
+        let denominator = (self.var_x * self.var_y).sqrt();
+        let correlation = if denominator == 0.0 {
+            0.0
+        } else {
+            (self.cov / denominator).clamp(-1.0, 1.0)
+        };
+
+        ExponentiallyWeightedCorrelationOutput {
+            covariance: self.cov,
+            correlation,
+        }
+    }
+}
+
+impl Reset for ExponentiallyWeightedCorrelation {
+    fn reset(&mut self) {
+        self.is_new = true;
+        self.mean_x = 0.0;
+        self.mean_y = 0.0;
+        self.var_x = 0.0;
+        self.var_y = 0.0;
+        self.cov = 0.0;
+    }
+}
+
+impl Default for ExponentiallyWeightedCorrelation {
+    fn default() -> Self {
+        Self::new(0.94).unwrap()
+    }
+}
+
+impl fmt::Display for ExponentiallyWeightedCorrelation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EWCORR({})", self.lambda)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ExponentiallyWeightedCorrelation::new(0.0).is_err());
+        assert!(ExponentiallyWeightedCorrelation::new(1.0).is_err());
+        assert!(ExponentiallyWeightedCorrelation::new(f64::NAN).is_err());
+        assert!(ExponentiallyWeightedCorrelation::new(0.94).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut corr = ExponentiallyWeightedCorrelation::new(0.5).unwrap();
+
+        let out = corr.nexta((1.0, 2.0));
+        assert_eq!((out.covariance, out.correlation), (0.0, 0.0));
+
+        let out = corr.nexta((3.0, 6.0));
+        assert_eq!((out.covariance, out.correlation), (2.0, 1.0));
+
+        let out = corr.nexta((2.0, 1.0));
+        assert_eq!((out.covariance, round(out.correlation)), (1.0, 0.686));
+
+        let out = corr.nexta((0.0, 5.0));
+        assert_eq!((out.covariance, round(out.correlation)), (-0.75, -0.349));
+    }
+
+    #[test]
+    fn test_next_anticorrelated() {
+        let mut corr = ExponentiallyWeightedCorrelation::new(0.9).unwrap();
+
+        for i in 0..20 {
+            let x = if i % 2 == 0 { 1.0 } else { -1.0 };
+            corr.nexta((x, -2.0 * x));
+        }
+        assert_eq!(round(corr.nexta((1.0, -2.0)).correlation), -1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut corr = ExponentiallyWeightedCorrelation::new(0.5).unwrap();
+
+        corr.nexta((1.0, 2.0));
+        corr.nexta((3.0, 6.0));
+
+        corr.reset();
+        assert_eq!(corr.nexta((3.0, 6.0)).covariance, 0.0);
+        assert_eq!(corr.nexta((1.0, 2.0)).covariance, 2.0);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(ExponentiallyWeightedCorrelation::default().lambda(), 0.94);
+    }
+
+    #[test]
+    fn test_display() {
+        let corr = ExponentiallyWeightedCorrelation::new(0.97).unwrap();
+        assert_eq!(format!("{}", corr), "EWCORR(0.97)");
+    }
+}
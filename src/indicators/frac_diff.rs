@@ -0,0 +1,250 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Longest window of [FracDiff](struct.FracDiff.html).
+const MAX_WIDTH: usize = 10_000;
+
+/// Fractional differentiation (FracDiff).
+///
+/// Differentiates a series to a fractional order _d_: with _d_ = 1 it is the ordinary first
+/// difference, with _d_ between 0 and 1 the result becomes stationary while keeping much more
+/// memory of the original series, which makes it a useful feature for machine learning models.
+///
+/// This is the fixed-width window variant described by Marcos López de Prado in _Advances in
+/// Financial Machine Learning_: the weights of the binomial series are cut off once they drop
+/// below _threshold_ in absolute value, so every output is a weighted sum over the same number
+/// of inputs.
+///
+/// # Formula
+///
+/// FracDiff<sub>t</sub> = Σ w<sub>k</sub> × x<sub>t-k</sub>, for k = 0 .. _width_ - 1
+///
+/// Where:
+///
+/// * w<sub>0</sub> = 1
+/// * w<sub>k</sub> = -w<sub>k-1</sub> × (_d_ - k + 1) / k
+/// * _width_ - number of weights before the first one with |w<sub>k</sub>| < _threshold_
+///
+/// Until _width_ values are available the sum runs over the values seen so far.
+///
+/// # Parameters
+///
+/// * _d_ - order of differentiation (number greater than 0). Default is 0.5.
+/// * _threshold_ - weight cut-off (number between 0 and 1, exclusive). Default is 0.0001. The
+///   window may hold at most 10000 weights, otherwise an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::FracDiff;
+/// use tars::Nexta;
+///
+/// // weights 1, -0.5, -0.125
+/// let mut fd = FracDiff::new(0.5, 0.1).unwrap();
+///
+/// fd.nexta(10.0);
+/// fd.nexta(12.0);
+/// assert_eq!(fd.nexta(16.0), 16.0 - 0.5 * 12.0 - 0.125 * 10.0);
+/// ```
+///
+/// # Links
+///
+/// * [Fractional calculus, Wikipedia](https://en.wikipedia.org/wiki/Fractional_calculus)
+///
+#[doc(alias = "FFD")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct FracDiff {
+    d: f64,
+    threshold: f64,
+    weights: Box<[f64]>,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+impl FracDiff {
+    pub fn new(d: f64, threshold: f64) -> Result<Self> {
+        if !(d.is_finite() && d > 0.0 && threshold > 0.0 && threshold < 1.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let mut weights = vec![1.0];
+        let mut k = 1;
+        loop {
+            let weight = -weights[k - 1] * (d - k as f64 + 1.0) / k as f64;
+            if weight.abs() < threshold {
+                break;
+            }
+            if weights.len() == MAX_WIDTH {
+                return Err(TaError::InvalidParameter);
+            }
+            weights.push(weight);
+            k += 1;
+        }
+
+        let width = weights.len();
+        Ok(Self {
+            d,
+            threshold,
+            weights: weights.into_boxed_slice(),
+            index: 0,
+            count: 0,
+            deque: vec![0.0; width].into_boxed_slice(),
+        })
+    }
+
+    pub fn d(&self) -> f64 {
+        self.d
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Returns the weights, starting with the weight of the current value.
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+}
+
+impl Period for FracDiff {
+    /// Returns the width of the window.
+    fn period(&self) -> usize {
+        self.weights.len()
+    }
+}
+
+impl Nexta<f64> for FracDiff {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let width = self.weights.len();
+
+        self.deque[self.index] = input;
+        if self.count < width {
+            self.count += 1;
+        }
+
+        let mut sum = 0.0;
+        let mut i = self.index;
+        for weight in self.weights.iter().take(self.count) {
+            sum += weight * self.deque[i];
+            i = if i == 0 { width - 1 } else { i - 1 };
+        }
+
+        self.index = if self.index + 1 < width {
+            self.index + 1
+        } else {
+            0
+        };
+
+        sum
+    }
+}
+
+impl<T: Close> Nexta<&T> for FracDiff {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for FracDiff {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.deque.len() {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for FracDiff {
+    fn default() -> Self {
+        Self::new(0.5, 0.0001).unwrap()
+    }
+}
+
+impl fmt::Display for FracDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FRACDIFF({}, {})", self.d, self.threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(FracDiff);
+
+    #[test]
+    fn test_new() {
+        assert!(FracDiff::new(0.0, 0.01).is_err());
+        assert!(FracDiff::new(-0.5, 0.01).is_err());
+        assert!(FracDiff::new(0.5, 0.0).is_err());
+        assert!(FracDiff::new(0.5, 1.0).is_err());
+        // more than 10000 weights
+        assert!(FracDiff::new(0.01, 1e-12).is_err());
+        assert!(FracDiff::new(0.5, 0.01).is_ok());
+    }
+
+    #[test]
+    fn test_weights() {
+        let fd = FracDiff::new(0.5, 0.1).unwrap();
+        assert_eq!(fd.weights(), &[1.0, -0.5, -0.125]);
+        assert_eq!(fd.period(), 3);
+
+        let fd = FracDiff::new(1.0, 0.0001).unwrap();
+        assert_eq!(fd.weights(), &[1.0, -1.0]);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut fd = FracDiff::new(0.5, 0.1).unwrap();
+
+        assert_eq!(fd.nexta(10.0), 10.0);
+        assert_eq!(fd.nexta(12.0), 7.0);
+        assert_eq!(fd.nexta(16.0), 8.75);
+        // 10.0 leaves the window
+        assert_eq!(fd.nexta(14.0), 4.5);
+        assert_eq!(fd.nexta(14.0), 5.0);
+    }
+
+    #[test]
+    fn test_first_difference() {
+        let mut fd = FracDiff::new(1.0, 0.0001).unwrap();
+
+        fd.nexta(&Bar::new().close(10));
+        assert_eq!(fd.nexta(&Bar::new().close(12)), 2.0);
+        assert_eq!(fd.nexta(&Bar::new().close(9)), -3.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fd = FracDiff::new(0.5, 0.1).unwrap();
+
+        fd.nexta(10.0);
+        fd.nexta(12.0);
+        fd.reset();
+        assert_eq!(fd.nexta(16.0), 16.0);
+        assert_eq!(fd.nexta(14.0), 6.0);
+    }
+
+    #[test]
+    fn test_default() {
+        FracDiff::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let fd = FracDiff::new(0.4, 0.001).unwrap();
+        assert_eq!(format!("{}", fd), "FRACDIFF(0.4, 0.001)");
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling Gain to Pain ratio (GPR).
+///
+/// Popularized by Jack Schwager, the ratio relates the net return to the sum of all losses over
+/// a rolling window of returns. A ratio of 1 means that the window earned as much as it lost on
+/// the losing periods. The inputs are returns, not prices.
+///
+/// # Formula
+///
+/// GPR = Σ r<sub>i</sub> / Σ |r<sub>i</sub>| for r<sub>i</sub> < 0
+///
+/// over the last _period_ returns. When there is no loss in the window the ratio is infinite, or
+/// 0 if the net return is 0 as well.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::GainPainRatio;
+/// use tars::Nexta;
+///
+/// let mut gpr = GainPainRatio::new(3).unwrap();
+///
+/// gpr.nexta(0.5);
+/// gpr.nexta(-0.25);
+/// assert_eq!(gpr.nexta(0.25), 2.0);
+/// ```
+///
+#[doc(alias = "GPR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct GainPainRatio {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum: f64,
+    pain: f64,
+    // number of losses in the window, the pain is zeroed at 0 to drop float residue
+    losses: usize,
+    deque: Box<[f64]>,
+}
+
+impl GainPainRatio {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum: 0.0,
+                pain: 0.0,
+                losses: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for GainPainRatio {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for GainPainRatio {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old_val = self.deque[self.index];
+            self.sum -= old_val;
+            if old_val < 0.0 {
+                self.losses -= 1;
+                self.pain = if self.losses > 0 {
+                    (self.pain + old_val).max(0.0)
+                } else {
+                    0.0
+                };
+            }
+        }
+
+        self.sum += input;
+        if input < 0.0 {
+            self.losses += 1;
+            self.pain -= input;
+        }
+
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.pain > 0.0 {
+            self.sum / self.pain
+        } else if self.sum > 0.0 {
+            f64::INFINITY
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Reset for GainPainRatio {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        self.pain = 0.0;
+        self.losses = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for GainPainRatio {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for GainPainRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GPR({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(GainPainRatio::new(0).is_err());
+        assert!(GainPainRatio::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut gpr = GainPainRatio::new(3).unwrap();
+
+        assert_eq!(gpr.nexta(0.0), 0.0);
+        assert_eq!(gpr.nexta(0.5), f64::INFINITY);
+        assert_eq!(gpr.nexta(-0.25), 1.0);
+        // 0.0 leaves the window
+        assert_eq!(gpr.nexta(0.25), 2.0);
+        // 0.5 leaves the window
+        assert_eq!(round(gpr.nexta(-0.5)), -0.667);
+        // -0.25 leaves the window
+        assert_eq!(gpr.nexta(0.75), 1.0);
+    }
+
+    #[test]
+    fn test_next_losses_leave_window() {
+        let mut gpr = GainPainRatio::new(2).unwrap();
+
+        gpr.nexta(-0.013);
+        gpr.nexta(-0.029);
+        gpr.nexta(0.01);
+        assert_eq!(gpr.nexta(0.01), f64::INFINITY);
+        assert_eq!(gpr.nexta(0.01), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut gpr = GainPainRatio::new(3).unwrap();
+
+        gpr.nexta(-0.25);
+        gpr.reset();
+        assert_eq!(gpr.nexta(0.25), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_default() {
+        GainPainRatio::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let gpr = GainPainRatio::new(12).unwrap();
+        assert_eq!(format!("{}", gpr), "GPR(12)");
+    }
+}
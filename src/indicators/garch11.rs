@@ -0,0 +1,421 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Online GARCH(1,1) volatility estimator.
+///
+/// Models the variance of the logarithmic returns of the input as a mean-reverting process: the
+/// variance of the next return is a blend of the long-run variance, the last squared return and
+/// the last variance. The indicator emits the conditional volatility forecast for the next
+/// return.
+///
+/// The parameters are either supplied by the user ([new](#method.new)) or calibrated on a
+/// rolling window of returns ([calibrated](#method.calibrated)).
+///
+/// # Formula
+///
+/// σ<sup>2</sup><sub>t+1</sub> = _ω_ + _α_ × r<sup>2</sup><sub>t</sub> + _β_ × σ<sup>2</sup><sub>t</sub>
+///
+/// Where:
+///
+/// * _r<sub>t</sub>_ = ln(Price<sub>t</sub> / Price<sub>t-1</sub>)
+///
+/// With user supplied parameters the variance starts at the long-run variance
+/// _ω_ / (1 - _α_ - _β_). Returns are not computed for prices less than or equal to 0.
+///
+/// # Rolling calibration
+///
+/// The parameters are fitted by [GarchParameters::fit](struct.GarchParameters.html#method.fit)
+/// on the last _window_ returns, as soon as _window_ returns are available and again after every
+/// further _window_ returns. Until the first calibration the RiskMetrics recursion
+/// (_ω_ = 0, _α_ = 0.06, _β_ = 0.94) starting at the first squared return is used, and the
+/// volatility of the first input is 0.
+///
+/// # Parameters
+///
+/// * _omega_ - constant (number greater than 0).
+/// * _alpha_ - weight of the last squared return (number greater than or equal to 0).
+/// * _beta_ - weight of the last variance (number greater than or equal to 0). The sum of
+///   _alpha_ and _beta_ must be less than 1.
+/// * _window_ - calibration window (integer greater than or equal to 10). Default is 250.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::Garch11;
+/// use tars::Nexta;
+///
+/// let mut garch = Garch11::new(0.5, 0.25, 0.25).unwrap();
+///
+/// // long-run volatility
+/// assert_eq!(garch.nexta(100.0), 1.0);
+/// // r = 0: 0.5 + 0.25 × 1
+/// assert_eq!(garch.nexta(100.0), 0.75f64.sqrt());
+/// ```
+///
+/// # Links
+///
+/// * [GARCH, Wikipedia](https://en.wikipedia.org/wiki/Autoregressive_conditional_heteroskedasticity#GARCH)
+///
+#[doc(alias = "GARCH")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Garch11 {
+    parameters: GarchParameters,
+    initial: GarchParameters,
+    calibration: Option<Calibration>,
+    variance: Option<f64>,
+    prev: Option<f64>,
+}
+
+/// Parameters of a GARCH(1,1) model.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GarchParameters {
+    pub omega: f64,
+    pub alpha: f64,
+    pub beta: f64,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct Calibration {
+    index: usize,
+    count: usize,
+    since_fit: usize,
+    returns: Box<[f64]>,
+}
+
+const RISK_METRICS: GarchParameters = GarchParameters {
+    omega: 0.0,
+    alpha: 0.06,
+    beta: 0.94,
+};
+
+impl GarchParameters {
+    /// Returns the long-run variance _ω_ / (1 - _α_ - _β_).
+    pub fn long_run_variance(&self) -> f64 {
+        self.omega / (1.0 - self.alpha - self.beta)
+    }
+
+    /// Fits the parameters to the given returns by maximizing the Gaussian likelihood.
+    ///
+    /// The returns are assumed to have zero mean. The long-run variance is fixed to the sample
+    /// variance (variance targeting) and _α_ and _β_ are searched on a grid with step 0.01,
+    /// _α_ up to 0.3 and _β_ from 0.5, with _α_ + _β_ below 1. Returns `None` if there are less
+    /// than 2 returns or their variance is 0.
+    pub fn fit(returns: &[f64]) -> Option<Self> {
+        if returns.len() < 2 {
+            return None;
+        }
+        let sample_variance = returns.iter().map(|r| r * r).sum::<f64>() / returns.len() as f64;
+        if sample_variance == 0.0 || !sample_variance.is_finite() {
+            return None;
+        }
+
+        let mut best = None;
+        let mut best_likelihood = f64::NEG_INFINITY;
+        for a in 1..=30 {
+            for b in 50..(100 - a) {
+                let alpha = a as f64 / 100.0;
+                let beta = b as f64 / 100.0;
+                let parameters = GarchParameters {
+                    omega: sample_variance * (1.0 - alpha - beta),
+                    alpha,
+                    beta,
+                };
+                let likelihood = parameters.log_likelihood(returns, sample_variance);
+                if likelihood > best_likelihood {
+                    best_likelihood = likelihood;
+                    best = Some(parameters);
+                }
+            }
+        }
+        best
+    }
+
+    fn is_valid(&self) -> bool {
+        self.omega.is_finite()
+            && self.omega > 0.0
+            && self.alpha >= 0.0
+            && self.beta >= 0.0
+            && self.alpha + self.beta < 1.0
+    }
+
+    fn next_variance(&self, variance: f64, r: f64) -> f64 {
+        self.omega + self.alpha * r * r + self.beta * variance
+    }
+
+    // Log-likelihood without the constant terms.
+    fn log_likelihood(&self, returns: &[f64], initial_variance: f64) -> f64 {
+        let mut variance = initial_variance;
+        let mut likelihood = 0.0;
+        for &r in returns {
+            likelihood -= variance.ln() + r * r / variance;
+            variance = self.next_variance(variance, r);
+        }
+        likelihood
+    }
+}
+
+impl Garch11 {
+    pub fn new(omega: f64, alpha: f64, beta: f64) -> Result<Self> {
+        let parameters = GarchParameters { omega, alpha, beta };
+        if !parameters.is_valid() {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            parameters,
+            initial: parameters,
+            calibration: None,
+            variance: Some(parameters.long_run_variance()),
+            prev: None,
+        })
+    }
+
+    /// Creates the estimator with rolling calibration of the parameters over _window_ returns.
+    pub fn calibrated(window: usize) -> Result<Self> {
+        if window < 10 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            parameters: RISK_METRICS,
+            initial: RISK_METRICS,
+            calibration: Some(Calibration {
+                index: 0,
+                count: 0,
+                since_fit: 0,
+                returns: vec![0.0; window].into_boxed_slice(),
+            }),
+            variance: None,
+            prev: None,
+        })
+    }
+
+    /// Returns the parameters currently in use.
+    pub fn parameters(&self) -> GarchParameters {
+        self.parameters
+    }
+
+    fn calibrate(&mut self, r: f64) {
+        let calibration = match self.calibration.as_mut() {
+            Some(calibration) => calibration,
+            None => return,
+        };
+
+        let window = calibration.returns.len();
+        calibration.returns[calibration.index] = r;
+        calibration.index = (calibration.index + 1) % window;
+        calibration.count = (calibration.count + 1).min(window);
+        calibration.since_fit += 1;
+
+        if calibration.count == window && calibration.since_fit >= window {
+            calibration.since_fit = 0;
+            // oldest return first
+            let returns: Vec<f64> = (0..window)
+                .map(|i| calibration.returns[(calibration.index + i) % window])
+                .collect();
+            if let Some(parameters) = GarchParameters::fit(&returns) {
+                self.parameters = parameters;
+            }
+        }
+    }
+}
+
+impl Nexta<f64> for Garch11 {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        if let Some(prev) = self.prev {
+            if prev > 0.0 && input > 0.0 {
+                let r = (input / prev).ln();
+                self.variance = Some(match self.variance {
+                    Some(variance) => self.parameters.next_variance(variance, r),
+                    None => r * r,
+                });
+                self.calibrate(r);
+            }
+        }
+        self.prev = Some(input);
+
+        self.variance.unwrap_or(0.0).sqrt()
+    }
+}
+
+impl<T: Close> Nexta<&T> for Garch11 {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for Garch11 {
+    fn reset(&mut self) {
+        self.parameters = self.initial;
+        self.prev = None;
+        match self.calibration.as_mut() {
+            Some(calibration) => {
+                calibration.index = 0;
+                calibration.count = 0;
+                calibration.since_fit = 0;
+                for i in 0..calibration.returns.len() {
+                    calibration.returns[i] = 0.0;
+                }
+                self.variance = None;
+            }
+            None => self.variance = Some(self.parameters.long_run_variance()),
+        }
+    }
+}
+
+impl Default for Garch11 {
+    fn default() -> Self {
+        Self::calibrated(250).unwrap()
+    }
+}
+
+impl fmt::Display for Garch11 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.calibration {
+            Some(calibration) => write!(f, "GARCH(ROLLING({}))", calibration.returns.len()),
+            None => write!(
+                f,
+                "GARCH({}, {}, {})",
+                self.parameters.omega, self.parameters.alpha, self.parameters.beta
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synthetic::{Process, Regime, SyntheticBars};
+    use crate::test_helper::*;
+    use std::f64::consts::E;
+
+    test_indicator!(Garch11);
+
+    #[test]
+    fn test_new() {
+        assert!(Garch11::new(0.0, 0.1, 0.8).is_err());
+        assert!(Garch11::new(0.1, -0.1, 0.8).is_err());
+        assert!(Garch11::new(0.1, 0.1, -0.8).is_err());
+        assert!(Garch11::new(0.1, 0.2, 0.8).is_err());
+        assert!(Garch11::new(0.1, 0.1, 0.8).is_ok());
+
+        assert!(Garch11::calibrated(9).is_err());
+        assert!(Garch11::calibrated(10).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut garch = Garch11::new(0.1, 0.2, 0.7).unwrap();
+
+        assert_eq!(round(garch.nexta(1.0)), 1.0);
+        // r = 1: 0.1 + 0.2 + 0.7
+        assert_eq!(round(garch.nexta(E)), 1.0);
+        // r = 0: 0.1 + 0.7
+        assert_eq!(round(garch.nexta(E)), 0.894);
+        // r = 2: 0.1 + 0.8 + 0.56
+        assert_eq!(round(garch.nexta(E.powi(3))), 1.208);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut garch = Garch11::new(0.1, 0.2, 0.7).unwrap();
+
+        garch.nexta(&Bar::new().close(100));
+        assert_eq!(round(garch.nexta(&Bar::new().close(100))), 0.894);
+    }
+
+    #[test]
+    fn test_fit() {
+        assert_eq!(GarchParameters::fit(&[0.01]), None);
+        assert_eq!(GarchParameters::fit(&[0.0, 0.0]), None);
+
+        // volatility clusters in long calm and turbulent regimes
+        let process = Process::RegimeSwitching {
+            regimes: vec![
+                Regime {
+                    drift: 0.0,
+                    volatility: 0.005,
+                },
+                Regime {
+                    drift: 0.0,
+                    volatility: 0.03,
+                },
+            ],
+            switch_probability: 0.02,
+        };
+        let closes: Vec<f64> = SyntheticBars::new(process, 100.0, 11)
+            .unwrap()
+            .take(2000)
+            .map(|bar| bar.close())
+            .collect();
+        let returns: Vec<f64> = closes.windows(2).map(|w| (w[1] / w[0]).ln()).collect();
+
+        let parameters = GarchParameters::fit(&returns).unwrap();
+        assert!(parameters.is_valid());
+        assert!(parameters.alpha + parameters.beta > 0.9);
+    }
+
+    #[test]
+    fn test_calibrated() {
+        let mut garch = Garch11::calibrated(100).unwrap();
+        assert_eq!(garch.parameters(), RISK_METRICS);
+
+        let process = Process::Gbm {
+            drift: 0.0,
+            volatility: 0.01,
+        };
+        let bars: Vec<_> = SyntheticBars::new(process, 100.0, 5)
+            .unwrap()
+            .take(300)
+            .collect();
+
+        assert_eq!(garch.nexta(&bars[0]), 0.0);
+        let mut sigma = 0.0;
+        for bar in &bars[1..] {
+            sigma = garch.nexta(bar);
+        }
+
+        assert_ne!(garch.parameters(), RISK_METRICS);
+        assert!(garch.parameters().is_valid());
+        assert!(sigma > 0.005 && sigma < 0.02);
+
+        garch.reset();
+        assert_eq!(garch.parameters(), RISK_METRICS);
+        assert_eq!(garch.nexta(&bars[0]), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut garch = Garch11::new(0.1, 0.2, 0.7).unwrap();
+
+        garch.nexta(1.0);
+        garch.nexta(E.powi(3));
+
+        garch.reset();
+        assert_eq!(round(garch.nexta(E)), 1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Garch11::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let garch = Garch11::new(0.1, 0.2, 0.7).unwrap();
+        assert_eq!(format!("{}", garch), "GARCH(0.1, 0.2, 0.7)");
+        let garch = Garch11::calibrated(250).unwrap();
+        assert_eq!(format!("{}", garch), "GARCH(ROLLING(250))");
+    }
+}
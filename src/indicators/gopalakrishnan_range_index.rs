@@ -0,0 +1,179 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Maximum, Minimum};
+use crate::{High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Gopalakrishnan Range Index (GAPO).
+///
+/// Developed by Jayanthi Gopalakrishnan, the index quantifies the variability of a market by
+/// relating the logarithm of the price range over a window to the logarithm of the window length.
+/// Higher values indicate more erratic price movement.
+///
+/// # Formula
+///
+/// GAPO = ln(Max(_period_) of High - Min(_period_) of Low) / ln(_period_)
+///
+/// When the range is 0 (e.g. on the first input passed as `f64`) the index returns 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 5.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::GopalakrishnanRangeIndex;
+/// use tars::Nexta;
+///
+/// let mut gapo = GopalakrishnanRangeIndex::new(2).unwrap();
+/// assert_eq!(gapo.nexta(10.0), 0.0);
+/// assert_eq!(gapo.nexta(12.0), 1.0); // ln(12 - 10) / ln(2)
+/// assert_eq!(gapo.nexta(16.0), 2.0); // ln(16 - 12) / ln(2)
+/// ```
+///
+/// # Links
+///
+/// * [Gopalakrishnan Range Index, Investopedia](https://www.investopedia.com/terms/g/gapo.asp)
+///
+#[doc(alias = "GAPO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct GopalakrishnanRangeIndex {
+    period: usize,
+    ln_period: f64,
+    max: Maximum,
+    min: Minimum,
+}
+
+impl GopalakrishnanRangeIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 | 1 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                ln_period: (period as f64).ln(),
+                max: Maximum::new(period)?,
+                min: Minimum::new(period)?,
+            }),
+        }
+    }
+
+    fn calculate(&self, highest: f64, lowest: f64) -> f64 {
+        let range = highest - lowest;
+        if range <= 0.0 {
+            0.0
+        } else {
+            range.ln() / self.ln_period
+        }
+    }
+}
+
+impl Period for GopalakrishnanRangeIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for GopalakrishnanRangeIndex {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let highest = self.max.nexta(input);
+        let lowest = self.min.nexta(input);
+        self.calculate(highest, lowest)
+    }
+}
+
+impl<T: High + Low> Nexta<&T> for GopalakrishnanRangeIndex {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let highest = self.max.nexta(input.high());
+        let lowest = self.min.nexta(input.low());
+        self.calculate(highest, lowest)
+    }
+}
+
+impl Reset for GopalakrishnanRangeIndex {
+    fn reset(&mut self) {
+        self.max.reset();
+        self.min.reset();
+    }
+}
+
+impl Default for GopalakrishnanRangeIndex {
+    fn default() -> Self {
+        Self::new(5).unwrap()
+    }
+}
+
+impl fmt::Display for GopalakrishnanRangeIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GAPO({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(GopalakrishnanRangeIndex);
+
+    #[test]
+    fn test_new() {
+        assert!(GopalakrishnanRangeIndex::new(0).is_err());
+        assert!(GopalakrishnanRangeIndex::new(1).is_err());
+        assert!(GopalakrishnanRangeIndex::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut gapo = GopalakrishnanRangeIndex::new(3).unwrap();
+
+        assert_eq!(gapo.nexta(10.0), 0.0);
+        assert_eq!(round(gapo.nexta(13.0)), 1.0);
+        assert_eq!(round(gapo.nexta(11.0)), 1.0);
+        assert_eq!(round(gapo.nexta(12.0)), 0.631);
+        assert_eq!(gapo.nexta(12.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut gapo = GopalakrishnanRangeIndex::new(2).unwrap();
+
+        let bar1 = Bar::new().high(11).low(9);
+        let bar2 = Bar::new().high(14).low(10);
+        let bar3 = Bar::new().high(13).low(12);
+
+        assert_eq!(gapo.nexta(&bar1), 1.0);
+        assert_eq!(round(gapo.nexta(&bar2)), 2.322);
+        assert_eq!(round(gapo.nexta(&bar3)), 2.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut gapo = GopalakrishnanRangeIndex::new(2).unwrap();
+
+        assert_eq!(gapo.nexta(10.0), 0.0);
+        assert_eq!(gapo.nexta(12.0), 1.0);
+
+        gapo.reset();
+        assert_eq!(gapo.nexta(20.0), 0.0);
+        assert_eq!(gapo.nexta(24.0), 2.0);
+    }
+
+    #[test]
+    fn test_default() {
+        GopalakrishnanRangeIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = GopalakrishnanRangeIndex::new(10).unwrap();
+        assert_eq!(format!("{}", indicator), "GAPO(10)");
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+
+use crate::{Close, High, Low, Nexta, Open, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Heikin-Ashi bar transformer.
+///
+/// Converts regular OHLC bars into smoothed Heikin-Ashi bars. The produced
+/// [HeikinAshiBar](struct.HeikinAshiBar.html) implements `Open`, `High`, `Low`, `Close` and
+/// `Volume`, so it can be passed to any other indicator in place of the original bar.
+///
+/// # Formula
+///
+/// * _HA Close_ = (Open + High + Low + Close) / 4
+/// * _HA Open_ = (previous HA Open + previous HA Close) / 2
+/// * _HA High_ = max(High, HA Open, HA Close)
+/// * _HA Low_ = min(Low, HA Open, HA Close)
+///
+/// For the first bar _HA Open_ = (Open + Close) / 2. Volume is passed through unchanged.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{HeikinAshi, SimpleMovingAverage};
+/// use tars::{Close, Nexta, Open, DataItema};
+///
+/// let mut ha = HeikinAshi::new();
+/// let mut sma = SimpleMovingAverage::new(3).unwrap();
+///
+/// let di = DataItema::builder()
+///             .high(12.0)
+///             .low(8.0)
+///             .close(11.0)
+///             .open(9.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// let bar = ha.nexta(&di);
+/// assert_eq!(bar.open(), 10.0);
+/// assert_eq!(bar.close(), 10.0);
+/// assert_eq!(sma.nexta(&bar), 10.0);
+/// ```
+///
+/// # Links
+///
+/// * [Heikin-Ashi, Investopedia](https://www.investopedia.com/trading/heikin-ashi-better-candlestick/)
+///
+#[doc(alias = "HA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HeikinAshi {
+    prev: Option<(f64, f64)>,
+}
+
+/// Smoothed bar produced by [HeikinAshi](struct.HeikinAshi.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeikinAshiBar {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+impl HeikinAshi {
+    pub fn new() -> Self {
+        Self { prev: None }
+    }
+}
+
+impl<T: Open + High + Low + Close + Volume> Nexta<&T> for HeikinAshi {
+    type Output = HeikinAshiBar;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let close = (input.open() + input.high() + input.low() + input.close()) / 4.0;
+        let open = match self.prev {
+            Some((prev_open, prev_close)) => (prev_open + prev_close) / 2.0,
+            None => (input.open() + input.close()) / 2.0,
+        };
+        self.prev = Some((open, close));
+
+        HeikinAshiBar {
+            open,
+            high: input.high().max(open).max(close),
+            low: input.low().min(open).min(close),
+            close,
+            volume: input.volume(),
+        }
+    }
+}
+
+impl Reset for HeikinAshi {
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+impl Default for HeikinAshi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for HeikinAshi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HA")
+    }
+}
+
+impl Open for HeikinAshiBar {
+    fn open(&self) -> f64 {
+        self.open
+    }
+}
+
+impl High for HeikinAshiBar {
+    fn high(&self) -> f64 {
+        self.high
+    }
+}
+
+impl Low for HeikinAshiBar {
+    fn low(&self) -> f64 {
+        self.low
+    }
+}
+
+impl Close for HeikinAshiBar {
+    fn close(&self) -> f64 {
+        self.close
+    }
+}
+
+impl Volume for HeikinAshiBar {
+    fn volume(&self) -> f64 {
+        self.volume
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::OnBalanceVolume;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next_bar() {
+        let mut ha = HeikinAshi::new();
+
+        let bar1 = Bar::new().open(9).high(12).low(8).close(11).volume(100.0);
+        let bar2 = Bar::new().open(11).high(15).low(10).close(14).volume(200.0);
+        let bar3 = Bar::new()
+            .open(14)
+            .high(14.5)
+            .low(9)
+            .close(9.5)
+            .volume(300.0);
+
+        assert_eq!(
+            ha.nexta(&bar1),
+            HeikinAshiBar {
+                open: 10.0,
+                high: 12.0,
+                low: 8.0,
+                close: 10.0,
+                volume: 100.0
+            }
+        );
+        assert_eq!(
+            ha.nexta(&bar2),
+            HeikinAshiBar {
+                open: 10.0,
+                high: 15.0,
+                low: 10.0,
+                close: 12.5,
+                volume: 200.0
+            }
+        );
+        assert_eq!(
+            ha.nexta(&bar3),
+            HeikinAshiBar {
+                open: 11.25,
+                high: 14.5,
+                low: 9.0,
+                close: 11.75,
+                volume: 300.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_as_indicator_input() {
+        let mut ha = HeikinAshi::new();
+        let mut obv = OnBalanceVolume::new();
+
+        let bar1 = Bar::new().open(9).high(12).low(8).close(11).volume(100.0);
+        let bar2 = Bar::new().open(11).high(15).low(10).close(14).volume(200.0);
+
+        assert_eq!(obv.nexta(&ha.nexta(&bar1)), 100.0);
+        assert_eq!(obv.nexta(&ha.nexta(&bar2)), 300.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ha = HeikinAshi::new();
+
+        let bar1 = Bar::new().open(9).high(12).low(8).close(11).volume(100.0);
+        let bar2 = Bar::new().open(11).high(15).low(10).close(14).volume(200.0);
+
+        ha.nexta(&bar1);
+        ha.reset();
+        assert_eq!(ha.nexta(&bar2).open, 12.5);
+    }
+
+    #[test]
+    fn test_default() {
+        HeikinAshi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ha = HeikinAshi::new();
+        assert_eq!(format!("{}", ha), "HA");
+    }
+}
@@ -0,0 +1,196 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Variance, VarianceMode};
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Historical volatility (HV).
+///
+/// The annualized sample standard deviation of the logarithmic returns of the last _period_
+/// bars, also known as close-to-close volatility. The inputs are prices; the returns are
+/// computed internally.
+///
+/// # Formula
+///
+/// HV = √(Σ (r<sub>i</sub> - r̄)<sup>2</sup> / (n - 1)) × √_bars per year_
+///
+/// Where:
+///
+/// * _r<sub>t</sub>_ = ln(Price<sub>t</sub> / Price<sub>t-1</sub>)
+/// * _n_ - number of returns in the window, at most _period_
+///
+/// The volatility is 0 until there are two returns. Returns are not computed for prices less
+/// than or equal to 0; the volatility stays unchanged on such bars.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 1). Default is 20.
+/// * _bars_per_year_ - number of bars in a year, e.g. 252 for daily bars (integer greater than
+///   0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::HistoricalVolatility;
+/// use tars::Nexta;
+///
+/// let mut hv = HistoricalVolatility::new(20, 252).unwrap();
+///
+/// assert_eq!(hv.nexta(100.0), 0.0);
+/// assert_eq!(hv.nexta(101.0), 0.0);
+/// assert!(hv.nexta(100.0) > 0.2);
+/// ```
+///
+/// # Links
+///
+/// * [Volatility (finance), Wikipedia](https://en.wikipedia.org/wiki/Volatility_(finance))
+///
+#[doc(alias = "HV")]
+#[doc(alias = "Close-to-close volatility")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HistoricalVolatility {
+    bars_per_year: usize,
+    variance: Variance,
+    prev: Option<f64>,
+    volatility: f64,
+}
+
+impl HistoricalVolatility {
+    pub fn new(period: usize, bars_per_year: usize) -> Result<Self> {
+        if period < 2 || bars_per_year == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            bars_per_year,
+            variance: Variance::new(period, VarianceMode::Sample)?,
+            prev: None,
+            volatility: 0.0,
+        })
+    }
+
+    pub fn bars_per_year(&self) -> usize {
+        self.bars_per_year
+    }
+}
+
+impl Period for HistoricalVolatility {
+    fn period(&self) -> usize {
+        self.variance.period()
+    }
+}
+
+impl Nexta<f64> for HistoricalVolatility {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        if let Some(prev) = self.prev {
+            if prev > 0.0 && input > 0.0 {
+                let variance = self.variance.nexta((input / prev).ln());
+                self.volatility = (variance * self.bars_per_year as f64).sqrt();
+            }
+        }
+        self.prev = Some(input);
+
+        self.volatility
+    }
+}
+
+impl<T: Close> Nexta<&T> for HistoricalVolatility {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for HistoricalVolatility {
+    fn reset(&mut self) {
+        self.variance.reset();
+        self.prev = None;
+        self.volatility = 0.0;
+    }
+}
+
+impl Default for HistoricalVolatility {
+    fn default() -> Self {
+        Self::new(20, 252).unwrap()
+    }
+}
+
+impl fmt::Display for HistoricalVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HV({}, {})", self.variance.period(), self.bars_per_year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(HistoricalVolatility);
+
+    #[test]
+    fn test_new() {
+        assert!(HistoricalVolatility::new(1, 252).is_err());
+        assert!(HistoricalVolatility::new(20, 0).is_err());
+        assert!(HistoricalVolatility::new(2, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut hv = HistoricalVolatility::new(3, 1).unwrap();
+
+        assert_eq!(hv.nexta(100.0), 0.0);
+        assert_eq!(hv.nexta(110.0), 0.0);
+        assert_eq!(round(hv.nexta(99.0)), 0.142);
+        assert_eq!(round(hv.nexta(108.9)), 0.116);
+    }
+
+    #[test]
+    fn test_next_annualized() {
+        let mut hv = HistoricalVolatility::new(3, 4).unwrap();
+
+        hv.nexta(100.0);
+        hv.nexta(110.0);
+        assert_eq!(round(hv.nexta(99.0)), 0.284);
+    }
+
+    #[test]
+    fn test_next_non_positive_price() {
+        let mut hv = HistoricalVolatility::new(3, 1).unwrap();
+
+        hv.nexta(100.0);
+        hv.nexta(110.0);
+        let out = hv.nexta(99.0);
+        assert_eq!(hv.nexta(0.0), out);
+        assert_eq!(hv.nexta(&Bar::new().close(99)), out);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hv = HistoricalVolatility::new(3, 1).unwrap();
+
+        hv.nexta(100.0);
+        hv.nexta(110.0);
+        hv.nexta(99.0);
+        hv.reset();
+        assert_eq!(hv.nexta(99.0), 0.0);
+        assert_eq!(hv.nexta(100.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        HistoricalVolatility::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let hv = HistoricalVolatility::new(30, 365).unwrap();
+        assert_eq!(format!("{}", hv), "HV(30, 365)");
+    }
+}
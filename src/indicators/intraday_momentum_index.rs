@@ -0,0 +1,240 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Intraday Momentum Index (IMI).
+///
+/// Developed by Tushar Chande, the IMI applies the math of the
+/// [RSI](struct.RelativeStrengthIndex.html) to the body of every bar, the move from the open to
+/// the close, instead of the change between closes. It accounts for intraday buying and selling
+/// pressure and is read like the RSI: above 70 overbought, below 30 oversold.
+///
+/// # Formula
+///
+/// IMI = 100 × Σ Gains / (Σ Gains + Σ Losses)
+///
+/// Where over the last _period_ bars:
+///
+/// * _Gain_ = Close - Open if the close is above the open, otherwise 0
+/// * _Loss_ = Open - Close if the close is below the open, otherwise 0
+///
+/// When all the bars of the window have close equal to open the IMI is 50.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::IntradayMomentumIndex as Imi;
+/// use tars::{DataItema, Nexta};
+///
+/// let mut imi = Imi::new(3).unwrap();
+///
+/// let bar = |open: f64, close: f64| {
+///     DataItema::builder()
+///         .open(open)
+///         .high(open.max(close))
+///         .low(open.min(close))
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// assert_eq!(imi.nexta(&bar(10.0, 11.0)), 100.0);
+/// assert_eq!(imi.nexta(&bar(11.0, 10.0)), 50.0);
+/// ```
+///
+/// # Links
+///
+/// * [Intraday Momentum Index (IMI), Investopedia](https://www.investopedia.com/terms/i/intraday-momentum-index-imi.asp)
+///
+#[doc(alias = "IMI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct IntradayMomentumIndex {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum_up: f64,
+    sum_down: f64,
+    // number of up and down bars in the window, a sum is zeroed at 0 to drop float residue
+    ups: usize,
+    downs: usize,
+    deque: Box<[f64]>,
+}
+
+impl IntradayMomentumIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum_up: 0.0,
+                sum_down: 0.0,
+                ups: 0,
+                downs: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for IntradayMomentumIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: Open + Close> Nexta<&T> for IntradayMomentumIndex {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let change = input.close() - input.open();
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old_val = self.deque[self.index];
+            if old_val > 0.0 {
+                self.ups -= 1;
+                self.sum_up = if self.ups > 0 {
+                    (self.sum_up - old_val).max(0.0)
+                } else {
+                    0.0
+                };
+            } else if old_val < 0.0 {
+                self.downs -= 1;
+                self.sum_down = if self.downs > 0 {
+                    (self.sum_down + old_val).max(0.0)
+                } else {
+                    0.0
+                };
+            }
+        }
+
+        if change > 0.0 {
+            self.ups += 1;
+            self.sum_up += change;
+        } else if change < 0.0 {
+            self.downs += 1;
+            self.sum_down -= change;
+        }
+
+        self.deque[self.index] = change;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let total = self.sum_up + self.sum_down;
+        if total == 0.0 {
+            50.0
+        } else {
+            100.0 * self.sum_up / total
+        }
+    }
+}
+
+impl Reset for IntradayMomentumIndex {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum_up = 0.0;
+        self.sum_down = 0.0;
+        self.ups = 0;
+        self.downs = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for IntradayMomentumIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for IntradayMomentumIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "IMI({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Imi = IntradayMomentumIndex;
+
+    fn bar(open: f64, close: f64) -> Bar {
+        Bar::new().open(open).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Imi::new(0).is_err());
+        assert!(Imi::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut imi = Imi::new(3).unwrap();
+
+        assert_eq!(imi.nexta(&bar(10.0, 11.0)), 100.0);
+        assert_eq!(round(imi.nexta(&bar(11.0, 10.5))), 66.667);
+        assert_eq!(round(imi.nexta(&bar(10.0, 10.0))), 66.667);
+        // the +1 gain leaves the window
+        assert_eq!(imi.nexta(&bar(10.0, 12.0)), 80.0);
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut imi = Imi::new(2).unwrap();
+
+        assert_eq!(imi.nexta(&bar(10.0, 10.0)), 50.0);
+        assert_eq!(imi.nexta(&bar(10.0, 9.0)), 0.0);
+    }
+
+    #[test]
+    fn test_flat_after_bodies() {
+        let mut imi = Imi::new(3).unwrap();
+
+        imi.nexta(&bar(0.1, 0.129));
+        imi.nexta(&bar(0.1, 0.4));
+        imi.nexta(&bar(0.1, 0.113));
+        imi.nexta(&bar(1.0, 1.0));
+        imi.nexta(&bar(1.0, 1.0));
+        assert_eq!(imi.nexta(&bar(1.0, 1.0)), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut imi = Imi::new(3).unwrap();
+
+        imi.nexta(&bar(10.0, 11.0));
+        imi.reset();
+        assert_eq!(imi.nexta(&bar(11.0, 10.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Imi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let imi = Imi::new(10).unwrap();
+        assert_eq!(format!("{}", imi), "IMI(10)");
+    }
+}
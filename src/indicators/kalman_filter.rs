@@ -0,0 +1,225 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kalman filter price smoother.
+///
+/// Estimates the "true" price level with a one-dimensional Kalman filter, modelling the level as
+/// a random walk observed with noise. The ratio of the process noise to the measurement noise
+/// controls the smoothing: the smaller the ratio, the smoother and more lagging the estimate.
+/// Besides the estimate, the filter emits the innovation, the surprise of the input relative to
+/// the prior estimate.
+///
+/// # Formula
+///
+/// * _Prior variance_ P<sub>t|t-1</sub> = P<sub>t-1</sub> + Q
+/// * _Gain_ K<sub>t</sub> = P<sub>t|t-1</sub> / (P<sub>t|t-1</sub> + R)
+/// * _Innovation_ = z<sub>t</sub> - x<sub>t-1</sub>
+/// * _Estimate_ x<sub>t</sub> = x<sub>t-1</sub> + K<sub>t</sub> × Innovation
+/// * _Variance_ P<sub>t</sub> = (1 - K<sub>t</sub>) × P<sub>t|t-1</sub>
+///
+/// Where:
+///
+/// * _z<sub>t</sub>_ - input
+/// * _Q_ - process noise variance
+/// * _R_ - measurement noise variance
+///
+/// The first input initializes the estimate with variance R and innovation 0.
+///
+/// # Parameters
+///
+/// * _process_noise_ - variance Q of the change of the level (number greater than 0). Default
+///   is 0.01.
+/// * _measurement_noise_ - variance R of the noise of the inputs (number greater than 0).
+///   Default is 1.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::KalmanFilter;
+/// use tars::Nexta;
+///
+/// let mut kf = KalmanFilter::new(1.0, 1.0).unwrap();
+///
+/// assert_eq!(kf.nexta(10.0).estimate, 10.0);
+///
+/// let out = kf.nexta(13.0);
+/// assert_eq!((out.estimate, out.innovation), (12.0, 3.0));
+/// ```
+///
+/// # Links
+///
+/// * [Kalman filter, Wikipedia](https://en.wikipedia.org/wiki/Kalman_filter)
+///
+#[doc(alias = "KF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KalmanFilter {
+    process_noise: f64,
+    measurement_noise: f64,
+    // estimate and its variance
+    state: Option<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KalmanFilterOutput {
+    pub estimate: f64,
+    pub innovation: f64,
+}
+
+impl KalmanFilter {
+    pub fn new(process_noise: f64, measurement_noise: f64) -> Result<Self> {
+        let valid = |noise: f64| noise.is_finite() && noise > 0.0;
+        if !(valid(process_noise) && valid(measurement_noise)) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            process_noise,
+            measurement_noise,
+            state: None,
+        })
+    }
+
+    /// Returns the variance of the current estimate, `None` before the first input.
+    pub fn variance(&self) -> Option<f64> {
+        self.state.map(|(_, variance)| variance)
+    }
+}
+
+impl Nexta<f64> for KalmanFilter {
+    type Output = KalmanFilterOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let (estimate, innovation, variance) = match self.state {
+            None => (input, 0.0, self.measurement_noise),
+            Some((estimate, variance)) => {
+                let prior = variance + self.process_noise;
+                let gain = prior / (prior + self.measurement_noise);
+                let innovation = input - estimate;
+                (
+                    estimate + gain * innovation,
+                    innovation,
+                    (1.0 - gain) * prior,
+                )
+            }
+        };
+        self.state = Some((estimate, variance));
+
+        KalmanFilterOutput {
+            estimate,
+            innovation,
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for KalmanFilter {
+    type Output = KalmanFilterOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for KalmanFilter {
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+impl Default for KalmanFilter {
+    fn default() -> Self {
+        Self::new(0.01, 1.0).unwrap()
+    }
+}
+
+impl fmt::Display for KalmanFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "KF({}, {})", self.process_noise, self.measurement_noise)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(KalmanFilter);
+
+    #[test]
+    fn test_new() {
+        assert!(KalmanFilter::new(0.0, 1.0).is_err());
+        assert!(KalmanFilter::new(1.0, 0.0).is_err());
+        assert!(KalmanFilter::new(f64::INFINITY, 1.0).is_err());
+        assert!(KalmanFilter::new(0.01, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kf = KalmanFilter::new(1.0, 1.0).unwrap();
+        assert_eq!(kf.variance(), None);
+
+        let out = kf.nexta(10.0);
+        assert_eq!((out.estimate, out.innovation), (10.0, 0.0));
+        assert_eq!(kf.variance(), Some(1.0));
+
+        // gain 2 / 3
+        let out = kf.nexta(12.0);
+        assert_eq!((round(out.estimate), out.innovation), (11.333, 2.0));
+
+        // gain 0.625
+        let out = kf.nexta(11.0);
+        assert_eq!(
+            (round(out.estimate), round(out.innovation)),
+            (11.125, -0.333)
+        );
+        assert_eq!(round(kf.variance().unwrap()), 0.625);
+    }
+
+    #[test]
+    fn test_smoothing() {
+        let mut smooth = KalmanFilter::new(0.001, 1.0).unwrap();
+        let mut fast = KalmanFilter::new(1.0, 0.001).unwrap();
+
+        for _ in 0..50 {
+            smooth.nexta(10.0);
+            fast.nexta(10.0);
+        }
+        assert!(smooth.nexta(20.0).estimate < 11.0);
+        assert!(fast.nexta(20.0).estimate > 19.9);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut kf = KalmanFilter::new(1.0, 1.0).unwrap();
+
+        kf.nexta(&Bar::new().close(10));
+        assert_eq!(kf.nexta(&Bar::new().close(13)).estimate, 12.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kf = KalmanFilter::new(1.0, 1.0).unwrap();
+
+        kf.nexta(10.0);
+        kf.nexta(12.0);
+
+        kf.reset();
+        assert_eq!(kf.variance(), None);
+        assert_eq!(kf.nexta(12.0).estimate, 12.0);
+    }
+
+    #[test]
+    fn test_default() {
+        KalmanFilter::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let kf = KalmanFilter::new(0.5, 2.0).unwrap();
+        assert_eq!(format!("{}", kf), "KF(0.5, 2)");
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::errors::Result;
+use crate::errors::{Result, TaError};
 use crate::indicators::{AverageTrueRange, ExponentialMovingAverage};
 use crate::{Close, High, Low, Nexta, Period, Reset};
 #[cfg(feature = "serde")]
@@ -22,6 +22,12 @@ use serde::{Deserialize, Serialize};
 ///  * _KC<sub>Upper Band</sub>_ = EMA + ATR of observation * multipler (usually 2.0)
 ///  * _KC<sub>Lower Band</sub>_ = EMA - ATR of observation * multipler (usually 2.0)
 ///
+/// # Parameters
+///
+/// * _period_ - period of the EMA and the ATR (integer greater than 0).
+/// * _multiplier_ - ATR multiplier (non-negative number). A multiplier of 0 collapses both bands
+///   onto the middle band.
+///
 /// # Example
 ///
 ///```
@@ -65,6 +71,10 @@ pub struct KeltnerChannelOutput {
 
 impl KeltnerChannel {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        if !(multiplier.is_finite() && multiplier >= 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
         Ok(Self {
             period,
             multiplier,
@@ -147,6 +157,9 @@ mod tests {
         assert!(KeltnerChannel::new(0, 2_f64).is_err());
         assert!(KeltnerChannel::new(1, 2_f64).is_ok());
         assert!(KeltnerChannel::new(2, 2_f64).is_ok());
+        assert!(KeltnerChannel::new(2, 0.0).is_ok());
+        assert!(KeltnerChannel::new(2, -1.0).is_err());
+        assert!(KeltnerChannel::new(2, f64::NAN).is_err());
     }
 
     #[test]
@@ -197,6 +210,14 @@ mod tests {
         assert_eq!(round(o3.upper), 2.525);
     }
 
+    #[test]
+    fn test_zero_multiplier() {
+        let mut kc = KeltnerChannel::new(3, 0.0).unwrap();
+        kc.nexta(2.0);
+        let out = kc.nexta(5.0);
+        assert_eq!((out.average, out.upper, out.lower), (3.5, 3.5, 3.5));
+    }
+
     #[test]
     fn test_reset() {
         let mut kc = KeltnerChannel::new(5, 2.0_f64).unwrap();
@@ -0,0 +1,271 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, High, Low, Nexta, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Klinger Volume Oscillator (KVO).
+///
+/// Developed by Stephen Klinger, the indicator compares the volume flowing in and out of a
+/// security to detect long-term trends of money flow while staying sensitive to short-term
+/// fluctuations. Crosses of the oscillator and its signal line, and divergences from the price,
+/// are used as signals.
+///
+/// # Formula
+///
+/// * _KVO_ = EMA(_fast period_) of VF - EMA(_slow period_) of VF
+/// * _Signal_ = EMA(_signal period_) of KVO
+///
+/// Where:
+///
+/// * _VF_ (volume force) = Volume × |2 × (DM / CM - 1)| × Trend × 100
+/// * _Trend_ = +1 if High + Low + Close is greater than on the previous bar, otherwise -1
+/// * _DM_ = High - Low
+/// * _CM_ = Prev CM + DM if the trend has not changed, otherwise Prev DM + DM
+///
+/// The volume force of the first bar, and of any bar with CM equal to 0, is 0.
+///
+/// # Parameters
+///
+/// * _fast_period_ - period of the fast EMA (integer greater than 0). Default is 34.
+/// * _slow_period_ - period of the slow EMA (integer greater than 0). Default is 55.
+/// * _signal_period_ - period of the signal EMA (integer greater than 0). Default is 13.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::KlingerVolumeOscillator;
+/// use tars::{Nexta, DataItema};
+///
+/// let mut kvo = KlingerVolumeOscillator::new(2, 3, 2).unwrap();
+///
+/// let di1 = DataItema::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// let di2 = DataItema::builder()
+///             .high(11.0)
+///             .low(9.0)
+///             .close(10.5)
+///             .open(10.0)
+///             .volume(300.0)
+///             .build().unwrap();
+///
+/// assert_eq!(kvo.nexta(&di1).kvo, 0.0);
+/// assert_eq!(kvo.nexta(&di2).kvo, 5000.0);
+/// ```
+///
+#[doc(alias = "KVO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KlingerVolumeOscillator {
+    fast_ema: Ema,
+    slow_ema: Ema,
+    signal_ema: Ema,
+    prev: Option<VolumeForceState>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+struct VolumeForceState {
+    hlc: f64,
+    trend: Option<f64>,
+    dm: f64,
+    cm: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KlingerVolumeOscillatorOutput {
+    pub kvo: f64,
+    pub signal: f64,
+}
+
+impl From<KlingerVolumeOscillatorOutput> for (f64, f64) {
+    fn from(ko: KlingerVolumeOscillatorOutput) -> Self {
+        (ko.kvo, ko.signal)
+    }
+}
+
+impl KlingerVolumeOscillator {
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        Ok(Self {
+            fast_ema: Ema::new(fast_period)?,
+            slow_ema: Ema::new(slow_period)?,
+            signal_ema: Ema::new(signal_period)?,
+            prev: None,
+        })
+    }
+
+    fn volume_force<T: High + Low + Close + Volume>(&mut self, input: &T) -> f64 {
+        let hlc = input.high() + input.low() + input.close();
+        let dm = input.high() - input.low();
+
+        let (state, vf) = match self.prev {
+            None => (
+                VolumeForceState {
+                    hlc,
+                    trend: None,
+                    dm,
+                    cm: dm,
+                },
+                0.0,
+            ),
+            Some(prev) => {
+                let trend = if hlc > prev.hlc { 1.0 } else { -1.0 };
+                let cm = if prev.trend == Some(trend) {
+                    prev.cm + dm
+                } else {
+                    prev.dm + dm
+                };
+                let vf = if cm == 0.0 {
+                    0.0
+                } else {
+                    input.volume() * (2.0 * (dm / cm - 1.0)).abs() * trend * 100.0
+                };
+                (
+                    VolumeForceState {
+                        hlc,
+                        trend: Some(trend),
+                        dm,
+                        cm,
+                    },
+                    vf,
+                )
+            }
+        };
+
+        self.prev = Some(state);
+        vf
+    }
+}
+
+impl<T: High + Low + Close + Volume> Nexta<&T> for KlingerVolumeOscillator {
+    type Output = KlingerVolumeOscillatorOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let vf = self.volume_force(input);
+        let kvo = self.fast_ema.nexta(vf) - self.slow_ema.nexta(vf);
+        let signal = self.signal_ema.nexta(kvo);
+
+        KlingerVolumeOscillatorOutput { kvo, signal }
+    }
+}
+
+impl Reset for KlingerVolumeOscillator {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.signal_ema.reset();
+        self.prev = None;
+    }
+}
+
+impl Default for KlingerVolumeOscillator {
+    fn default() -> Self {
+        Self::new(34, 55, 13).unwrap()
+    }
+}
+
+impl fmt::Display for KlingerVolumeOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "KVO({}, {}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.signal_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, close: f64, volume: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close).volume(volume)
+    }
+
+    fn round(nums: (f64, f64)) -> (f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        (n0, n1)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(KlingerVolumeOscillator::new(0, 55, 13).is_err());
+        assert!(KlingerVolumeOscillator::new(34, 0, 13).is_err());
+        assert!(KlingerVolumeOscillator::new(34, 55, 0).is_err());
+        assert!(KlingerVolumeOscillator::new(34, 55, 13).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kvo = KlingerVolumeOscillator::new(2, 3, 2).unwrap();
+
+        assert_eq!(
+            round(kvo.nexta(&bar(10.0, 8.0, 9.0, 100.0)).into()),
+            (0.0, 0.0)
+        );
+        // trend changes: VF = 200 * |2 * (2 / 4 - 1)| * 100 = 20000
+        assert_eq!(
+            round(kvo.nexta(&bar(11.0, 9.0, 10.5, 200.0)).into()),
+            (3333.333, 2222.222)
+        );
+        // trend continues: VF = 150 * |2 * (2 / 6 - 1)| * 100 = 20000
+        assert_eq!(
+            round(kvo.nexta(&bar(12.0, 10.0, 11.0, 150.0)).into()),
+            (2777.778, 2592.593)
+        );
+        assert_eq!(
+            round(kvo.nexta(&bar(11.0, 8.0, 9.0, 300.0)).into()),
+            (-5574.074, -2851.852)
+        );
+        assert_eq!(
+            round(kvo.nexta(&bar(10.0, 7.0, 8.0, 250.0)).into()),
+            (-6316.358, -5161.523)
+        );
+        assert_eq!(
+            round(kvo.nexta(&bar(12.0, 9.0, 11.5, 400.0)).into()),
+            (7540.381, 3306.413)
+        );
+    }
+
+    #[test]
+    fn test_flat_bars() {
+        let mut kvo = KlingerVolumeOscillator::new(2, 3, 2).unwrap();
+
+        kvo.nexta(&bar(10.0, 10.0, 10.0, 100.0));
+        assert_eq!(kvo.nexta(&bar(10.0, 10.0, 10.0, 100.0)).kvo, 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kvo = KlingerVolumeOscillator::new(2, 3, 2).unwrap();
+
+        kvo.nexta(&bar(10.0, 8.0, 9.0, 100.0));
+        kvo.nexta(&bar(11.0, 9.0, 10.5, 200.0));
+
+        kvo.reset();
+        assert_eq!(kvo.nexta(&bar(11.0, 9.0, 10.5, 200.0)).kvo, 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        KlingerVolumeOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let kvo = KlingerVolumeOscillator::default();
+        assert_eq!(format!("{}", kvo), "KVO(34, 55, 13)");
+    }
+}
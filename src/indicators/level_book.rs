@@ -0,0 +1,453 @@
+use std::fmt;
+
+use crate::indicators::{Breakout, OpeningRangeOutput, PivotLevels};
+use crate::{Close, High, Low, Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Book of active price levels.
+///
+/// Stores drawn levels such as stops, pivot points or the bounds of an opening range, checks
+/// every new bar against all of them and reports how the price interacted with each level, so
+/// a strategy reacts to levels from any source the same way.
+///
+/// Levels are added with [add](struct.LevelBook.html#method.add), or in bulk from
+/// [PivotLevels](struct.PivotLevels.html) and an [OpeningRangeOutput](struct.OpeningRangeOutput.html).
+/// Every level is checked from the next bar passed to `nexta` on, which returns the events of
+/// that bar:
+///
+/// * [Break](enum.LevelEventKind.html#variant.Break) - the close crossed the level: it was below
+///   the level on the previous bar and is above it now (up), or the other way round (down). A
+///   close equal to the level does not change the side of the price.
+/// * [Touch](enum.LevelEventKind.html#variant.Touch) - the bar reached the level without breaking
+///   it, i.e. low ≤ level ≤ high.
+/// * [Expire](enum.LevelEventKind.html#variant.Expire) - the level was removed by its
+///   [Expiry](enum.Expiry.html) policy. It follows the touch or break that ended the level, if
+///   any.
+///
+/// The side of a level added before any bar is taken from the close of its first bar, which
+/// therefore cannot break it.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{Breakout, Expiry, LevelBook, LevelEventKind, LevelSource};
+/// use tars::{DataItema, Nexta};
+///
+/// let bar = |high: f64, low: f64, close: f64| {
+///     DataItema::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut book = LevelBook::new();
+/// book.nexta(&bar(101.0, 99.0, 100.0));
+/// let id = book.add(102.0, LevelSource::Manual, Expiry::Break);
+///
+/// let events = book.nexta(&bar(102.5, 100.0, 101.0));
+/// assert_eq!((events[0].id, events[0].kind), (id, LevelEventKind::Touch));
+///
+/// let events = book.nexta(&bar(104.0, 101.0, 103.0));
+/// assert_eq!(events[0].kind, LevelEventKind::Break(Breakout::Up));
+/// assert_eq!(events[1].kind, LevelEventKind::Expire);
+/// assert!(book.is_empty());
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LevelBook {
+    count: usize,
+    next_id: usize,
+    close: Option<f64>,
+    levels: Vec<Level>,
+}
+
+/// Origin of a level in a [LevelBook](struct.LevelBook.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelSource {
+    /// A level of [PivotPoints](struct.PivotPoints.html).
+    Pivot,
+    /// A bound of an [OpeningRange](struct.OpeningRange.html).
+    OpeningRange,
+    /// A swing high or low.
+    Swing,
+    /// A stop, a target or any other level placed by the user.
+    Manual,
+}
+
+/// When a level is removed from a [LevelBook](struct.LevelBook.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expiry {
+    /// Never, until removed by the user.
+    Never,
+    /// After the given number of bars (integer greater than 0) were checked against it.
+    Bars(usize),
+    /// On the given touch (integer greater than 0). A break counts as a touch.
+    Touches(usize),
+    /// On the first break.
+    Break,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Above,
+    Below,
+}
+
+/// Active level of a [LevelBook](struct.LevelBook.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Level {
+    /// Identifier returned when the level was added; unique within the book.
+    pub id: usize,
+    pub price: f64,
+    pub source: LevelSource,
+    pub expiry: Expiry,
+    /// Index of the first bar checked against the level, counting from 0.
+    pub created: usize,
+    /// Number of touches so far, breaks included.
+    pub touches: usize,
+    side: Option<Side>,
+}
+
+/// Kind of a [LevelEvent](struct.LevelEvent.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelEventKind {
+    Touch,
+    Break(Breakout),
+    Expire,
+}
+
+/// Interaction between a bar and a level of a [LevelBook](struct.LevelBook.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelEvent {
+    pub id: usize,
+    pub price: f64,
+    pub source: LevelSource,
+    pub kind: LevelEventKind,
+    /// Index of the bar, counting from 0.
+    pub bar: usize,
+}
+
+impl LevelBook {
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            next_id: 0,
+            close: None,
+            levels: Vec::new(),
+        }
+    }
+
+    /// Adds a level and returns its identifier.
+    ///
+    /// `Expiry::Bars(0)` and `Expiry::Touches(0)` are treated as 1.
+    pub fn add(&mut self, price: f64, source: LevelSource, expiry: Expiry) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let side = self.close.and_then(|close| side(close, price));
+        self.levels.push(Level {
+            id,
+            price,
+            source,
+            expiry,
+            created: self.count,
+            touches: 0,
+            side,
+        });
+        id
+    }
+
+    /// Adds the pivot point and the support and resistance levels.
+    pub fn add_pivot_levels(&mut self, levels: &PivotLevels, expiry: Expiry) {
+        let prices = [
+            levels.s3, levels.s2, levels.s1, levels.pp, levels.r1, levels.r2, levels.r3,
+        ];
+        for &price in &prices {
+            self.add(price, LevelSource::Pivot, expiry);
+        }
+    }
+
+    /// Adds the high and the low of an opening range. Does nothing while the range is not
+    /// complete.
+    pub fn add_opening_range(&mut self, range: &OpeningRangeOutput, expiry: Expiry) {
+        if range.complete {
+            self.add(range.high, LevelSource::OpeningRange, expiry);
+            self.add(range.low, LevelSource::OpeningRange, expiry);
+        }
+    }
+
+    /// Removes a level. Returns whether it was active.
+    pub fn remove(&mut self, id: usize) -> bool {
+        let len = self.levels.len();
+        self.levels.retain(|level| level.id != id);
+        self.levels.len() != len
+    }
+
+    /// Returns the active levels, in the order they were added.
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+}
+
+fn side(close: f64, price: f64) -> Option<Side> {
+    if close > price {
+        Some(Side::Above)
+    } else if close < price {
+        Some(Side::Below)
+    } else {
+        None
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for LevelBook {
+    type Output = Vec<LevelEvent>;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let bar = self.count;
+        self.count += 1;
+        self.close = Some(input.close());
+
+        let mut events = Vec::new();
+        self.levels.retain_mut(|level| {
+            let new_side = side(input.close(), level.price).or(level.side);
+            let kind = match (level.side, new_side) {
+                (Some(Side::Below), Some(Side::Above)) => Some(LevelEventKind::Break(Breakout::Up)),
+                (Some(Side::Above), Some(Side::Below)) => {
+                    Some(LevelEventKind::Break(Breakout::Down))
+                }
+                _ if input.low() <= level.price && level.price <= input.high() => {
+                    Some(LevelEventKind::Touch)
+                }
+                _ => None,
+            };
+            level.side = new_side;
+
+            let mut expired = false;
+            if let Some(kind) = kind {
+                level.touches += 1;
+                expired = match level.expiry {
+                    Expiry::Touches(n) => level.touches >= n,
+                    Expiry::Break => kind != LevelEventKind::Touch,
+                    _ => false,
+                };
+                events.push(LevelEvent {
+                    id: level.id,
+                    price: level.price,
+                    source: level.source,
+                    kind,
+                    bar,
+                });
+            }
+            if let Expiry::Bars(n) = level.expiry {
+                expired = expired || bar + 1 - level.created >= n;
+            }
+
+            if expired {
+                events.push(LevelEvent {
+                    id: level.id,
+                    price: level.price,
+                    source: level.source,
+                    kind: LevelEventKind::Expire,
+                    bar,
+                });
+            }
+            !expired
+        });
+
+        events
+    }
+}
+
+impl Reset for LevelBook {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.next_id = 0;
+        self.close = None;
+        self.levels.clear();
+    }
+}
+
+impl Default for LevelBook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for LevelBook {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LEVELBOOK")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::PivotKind;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    fn kinds(events: &[LevelEvent]) -> Vec<(usize, LevelEventKind)> {
+        events.iter().map(|e| (e.id, e.kind)).collect()
+    }
+
+    #[test]
+    fn test_touch_and_break() {
+        let mut book = LevelBook::new();
+        book.nexta(&bar(11.0, 9.0, 10.0));
+        let resistance = book.add(12.0, LevelSource::Manual, Expiry::Never);
+        let support = book.add(8.0, LevelSource::Manual, Expiry::Never);
+
+        assert!(book.nexta(&bar(11.5, 9.5, 11.0)).is_empty());
+        assert_eq!(
+            kinds(&book.nexta(&bar(12.0, 10.0, 11.0))),
+            vec![(resistance, LevelEventKind::Touch)]
+        );
+        assert_eq!(
+            kinds(&book.nexta(&bar(13.0, 11.0, 12.5))),
+            vec![(resistance, LevelEventKind::Break(Breakout::Up))]
+        );
+        // closing on the level keeps the price above it
+        assert_eq!(
+            kinds(&book.nexta(&bar(12.5, 11.5, 12.0))),
+            vec![(resistance, LevelEventKind::Touch)]
+        );
+        let events = book.nexta(&bar(12.0, 7.0, 7.5));
+        assert_eq!(
+            kinds(&events),
+            vec![
+                (resistance, LevelEventKind::Break(Breakout::Down)),
+                (support, LevelEventKind::Break(Breakout::Down)),
+            ]
+        );
+        assert_eq!(events[1].bar, 5);
+        assert_eq!(book.levels()[0].touches, 4);
+        assert_eq!(book.len(), 2);
+    }
+
+    #[test]
+    fn test_added_before_first_bar() {
+        let mut book = LevelBook::new();
+        let id = book.add(10.0, LevelSource::Manual, Expiry::Never);
+
+        assert_eq!(book.levels()[0].created, 0);
+        // the first bar sets the side, so it can only be a touch
+        assert_eq!(
+            kinds(&book.nexta(&bar(11.0, 9.0, 10.5))),
+            vec![(id, LevelEventKind::Touch)]
+        );
+        assert_eq!(
+            kinds(&book.nexta(&bar(10.5, 9.0, 9.5))),
+            vec![(id, LevelEventKind::Break(Breakout::Down))]
+        );
+    }
+
+    #[test]
+    fn test_expiry() {
+        let mut book = LevelBook::new();
+        book.nexta(&bar(11.0, 9.0, 10.0));
+        let bars = book.add(20.0, LevelSource::Manual, Expiry::Bars(2));
+        let touches = book.add(11.0, LevelSource::Manual, Expiry::Touches(2));
+        let broken = book.add(9.0, LevelSource::Manual, Expiry::Break);
+
+        assert_eq!(
+            kinds(&book.nexta(&bar(11.0, 9.0, 10.0))),
+            vec![
+                (touches, LevelEventKind::Touch),
+                (broken, LevelEventKind::Touch)
+            ]
+        );
+        assert_eq!(
+            kinds(&book.nexta(&bar(11.5, 8.0, 8.5))),
+            vec![
+                (bars, LevelEventKind::Expire),
+                (touches, LevelEventKind::Touch),
+                (touches, LevelEventKind::Expire),
+                (broken, LevelEventKind::Break(Breakout::Down)),
+                (broken, LevelEventKind::Expire),
+            ]
+        );
+        assert!(book.is_empty());
+    }
+
+    #[test]
+    fn test_feeders() {
+        let mut book = LevelBook::new();
+
+        let levels = PivotLevels::calculate(PivotKind::Classic, 110.0, 90.0, 103.0);
+        book.add_pivot_levels(&levels, Expiry::Never);
+        assert_eq!(book.len(), 7);
+        assert!(book
+            .levels()
+            .iter()
+            .all(|level| level.source == LevelSource::Pivot));
+        assert_eq!(book.levels()[3].price, 101.0);
+
+        let mut range = OpeningRangeOutput {
+            high: 105.0,
+            low: 95.0,
+            complete: false,
+            breakout: None,
+        };
+        book.add_opening_range(&range, Expiry::Never);
+        assert_eq!(book.len(), 7);
+        range.complete = true;
+        book.add_opening_range(&range, Expiry::Never);
+        assert_eq!(book.len(), 9);
+        assert_eq!(book.levels()[8].source, LevelSource::OpeningRange);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut book = LevelBook::new();
+        let a = book.add(10.0, LevelSource::Manual, Expiry::Never);
+        let b = book.add(20.0, LevelSource::Manual, Expiry::Never);
+
+        assert!(book.remove(a));
+        assert!(!book.remove(a));
+        assert_eq!(book.levels()[0].id, b);
+        assert_ne!(book.add(30.0, LevelSource::Manual, Expiry::Never), a);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut book = LevelBook::new();
+        book.nexta(&bar(11.0, 9.0, 10.0));
+        book.add(10.0, LevelSource::Manual, Expiry::Never);
+
+        book.reset();
+        assert!(book.is_empty());
+        assert_eq!(book.add(10.0, LevelSource::Manual, Expiry::Never), 0);
+        assert_eq!(book.levels()[0].created, 0);
+    }
+
+    #[test]
+    fn test_default() {
+        LevelBook::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", LevelBook::new()), "LEVELBOOK");
+    }
+}
@@ -0,0 +1,267 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling linear regression.
+///
+/// Fits a least squares line through the last _period_ values, where _x_ is the offset of a value
+/// within the window (0 for the oldest value, _period_ - 1 for the most recent one).
+/// The fitted value at the most recent bar is also known as the Least Squares Moving Average (LSMA).
+///
+/// The sums of _y_, _xy_ and _y²_ are maintained incrementally, while the sums of _x_ and _x²_
+/// only depend on the number of values in the window, so every call is O(1).
+///
+/// # Formula
+///
+/// slope = (n * Σxy - Σx * Σy) / (n * Σx² - (Σx)²)
+///
+/// intercept = (Σy - slope * Σx) / n
+///
+/// value = intercept + slope * (n - 1)
+///
+/// R² = (n * Σxy - Σx * Σy)² / ((n * Σx² - (Σx)²) * (n * Σy² - (Σy)²))
+///
+/// Where:
+///
+/// * _n_ - number of values in the window
+/// * _intercept_ - fitted value at the oldest bar of the window
+///
+/// Until there are at least 2 values the slope is 0. R² is 0 when it is undefined, that is when
+/// there are fewer than 2 values or all the values in the window are equal.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::LinearRegression;
+/// use tars::Nexta;
+///
+/// let mut lr = LinearRegression::new(3).unwrap();
+/// lr.nexta(2.0);
+/// lr.nexta(4.0);
+/// let out = lr.nexta(3.0);
+///
+/// assert_eq!(out.value, 3.5);
+/// assert_eq!(out.slope, 0.5);
+/// assert_eq!(out.intercept, 2.5);
+/// assert_eq!(out.r_squared, 0.25);
+/// ```
+///
+/// # Links
+///
+/// * [Linear regression, Wikipedia](https://en.wikipedia.org/wiki/Simple_linear_regression)
+/// * [Least Squares Moving Average, TradingView](https://www.tradingview.com/support/solutions/43000594683-least-squares-moving-average/)
+///
+#[doc(alias = "LSMA")]
+#[doc(alias = "LINREG")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LinearRegression {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum_y: f64,
+    sum_xy: f64,
+    sum_y2: f64,
+    deque: Box<[f64]>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegressionOutput {
+    pub value: f64,
+    pub slope: f64,
+    pub intercept: f64,
+    pub r_squared: f64,
+}
+
+impl LinearRegression {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum_y: 0.0,
+                sum_xy: 0.0,
+                sum_y2: 0.0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for LinearRegression {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for LinearRegression {
+    type Output = LinearRegressionOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        if self.count < self.period {
+            self.sum_xy += self.count as f64 * input;
+            self.count += 1;
+        } else {
+            // Drop the oldest value (its x is 0) and shift the remaining ones by one position
+            // towards the start of the window.
+            let old_val = self.deque[self.index];
+            self.sum_y -= old_val;
+            self.sum_y2 -= old_val * old_val;
+            self.sum_xy -= self.sum_y;
+            self.sum_xy += (self.period - 1) as f64 * input;
+        }
+        self.sum_y += input;
+        self.sum_y2 += input * input;
+
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let n = self.count as f64;
+        let sum_x = n * (n - 1.0) / 2.0;
+        let sum_x2 = (n - 1.0) * n * (2.0 * n - 1.0) / 6.0;
+
+        let sxx = n * sum_x2 - sum_x * sum_x;
+        let sxy = n * self.sum_xy - sum_x * self.sum_y;
+        let syy = (n * self.sum_y2 - self.sum_y * self.sum_y).max(0.0);
+
+        let slope = if sxx == 0.0 { 0.0 } else { sxy / sxx };
+        let intercept = (self.sum_y - slope * sum_x) / n;
+        let r_squared = if sxx == 0.0 || syy == 0.0 {
+            0.0
+        } else {
+            (sxy * sxy / (sxx * syy)).min(1.0)
+        };
+
+        LinearRegressionOutput {
+            value: intercept + slope * (n - 1.0),
+            slope,
+            intercept,
+            r_squared,
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for LinearRegression {
+    type Output = LinearRegressionOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for LinearRegression {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum_y = 0.0;
+        self.sum_xy = 0.0;
+        self.sum_y2 = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for LinearRegression {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for LinearRegression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LINREG({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(LinearRegression);
+
+    fn round_output(out: LinearRegressionOutput) -> (f64, f64, f64, f64) {
+        (
+            round(out.value),
+            round(out.slope),
+            round(out.intercept),
+            round(out.r_squared),
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(LinearRegression::new(0).is_err());
+        assert!(LinearRegression::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut lr = LinearRegression::new(3).unwrap();
+
+        assert_eq!(round_output(lr.nexta(1.0)), (1.0, 0.0, 1.0, 0.0));
+        assert_eq!(round_output(lr.nexta(3.0)), (3.0, 2.0, 1.0, 1.0));
+        assert_eq!(round_output(lr.nexta(5.0)), (5.0, 2.0, 1.0, 1.0));
+        assert_eq!(round_output(lr.nexta(7.0)), (7.0, 2.0, 3.0, 1.0));
+        assert_eq!(round_output(lr.nexta(6.0)), (6.5, 0.5, 5.5, 0.25));
+        assert_eq!(round_output(lr.nexta(6.0)), (5.833, -0.5, 6.833, 0.75));
+        assert_eq!(round_output(lr.nexta(6.0)), (6.0, 0.0, 6.0, 0.0));
+    }
+
+    #[test]
+    fn test_next_period_1() {
+        let mut lr = LinearRegression::new(1).unwrap();
+
+        assert_eq!(round_output(lr.nexta(4.0)), (4.0, 0.0, 4.0, 0.0));
+        assert_eq!(round_output(lr.nexta(2.0)), (2.0, 0.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut lr = LinearRegression::new(3).unwrap();
+        lr.nexta(&bar(2.0));
+        lr.nexta(&bar(4.0));
+        assert_eq!(round_output(lr.nexta(&bar(3.0))), (3.5, 0.5, 2.5, 0.25));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut lr = LinearRegression::new(3).unwrap();
+
+        lr.nexta(1.0);
+        lr.nexta(3.0);
+
+        lr.reset();
+        assert_eq!(round_output(lr.nexta(2.0)), (2.0, 0.0, 2.0, 0.0));
+        assert_eq!(round_output(lr.nexta(4.0)), (4.0, 2.0, 2.0, 1.0));
+    }
+
+    #[test]
+    fn test_default() {
+        LinearRegression::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let lr = LinearRegression::new(9).unwrap();
+        assert_eq!(format!("{}", lr), "LINREG(9)");
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{AverageTrueRange, ExponentialMovingAverage as Ema};
+use crate::{Close, High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volatility-normalized MACD (MACD-V).
+///
+/// Developed by Alex Spiroglou, MACD-V divides the MACD line by the Average True Range (ATR) of
+/// the slow period. The raw MACD is in price units and grows with the volatility of the
+/// instrument; the normalized line is comparable across instruments and time, with readings
+/// above 150 or below -150 usually considered extreme. The signal line is an EMA of MACD-V.
+///
+/// # Formula
+///
+/// * _MACD-V_ = (EMA(_fast_period_) - EMA(_slow_period_)) / ATR(_slow_period_) × 100
+/// * _Signal_ = EMA(_signal_period_) of MACD-V
+/// * _Histogram_ = MACD-V - Signal
+///
+/// MACD-V is 0 while the ATR is 0. For `f64` input the ATR is calculated from the absolute
+/// changes of the close.
+///
+/// # Parameters
+///
+/// * _fast_period_ - period for the fast EMA. Default is 12.
+/// * _slow_period_ - period for the slow EMA and the ATR. Default is 26.
+/// * _signal_period_ - period for the signal EMA. Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::MacdV;
+/// use tars::{DataItema, Nexta};
+///
+/// let mut macd_v = MacdV::new(3, 6, 4).unwrap();
+///
+/// let bar = |high: f64, low: f64, close: f64| {
+///     DataItema::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// assert_eq!(macd_v.nexta(&bar(11.0, 9.0, 10.0)).macd_v, 0.0);
+/// assert!(macd_v.nexta(&bar(12.0, 10.0, 11.5)).macd_v > 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [MACD, Investopedia](https://www.investopedia.com/terms/m/macd.asp)
+///
+#[doc(alias = "MACD-V")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MacdV {
+    fast_ema: Ema,
+    slow_ema: Ema,
+    signal_ema: Ema,
+    atr: AverageTrueRange,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MacdVOutput {
+    pub macd_v: f64,
+    pub signal: f64,
+    pub histogram: f64,
+}
+
+impl From<MacdVOutput> for (f64, f64, f64) {
+    fn from(mo: MacdVOutput) -> Self {
+        (mo.macd_v, mo.signal, mo.histogram)
+    }
+}
+
+impl MacdV {
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        Ok(Self {
+            fast_ema: Ema::new(fast_period)?,
+            slow_ema: Ema::new(slow_period)?,
+            signal_ema: Ema::new(signal_period)?,
+            atr: AverageTrueRange::new(slow_period)?,
+        })
+    }
+
+    fn calculate(&mut self, close: f64, atr: f64) -> MacdVOutput {
+        let macd = self.fast_ema.nexta(close) - self.slow_ema.nexta(close);
+        let macd_v = if atr > 0.0 { macd / atr * 100.0 } else { 0.0 };
+        let signal = self.signal_ema.nexta(macd_v);
+
+        MacdVOutput {
+            macd_v,
+            signal,
+            histogram: macd_v - signal,
+        }
+    }
+}
+
+impl Period for MacdV {
+    fn period(&self) -> usize {
+        self.slow_ema.period()
+    }
+}
+
+impl Nexta<f64> for MacdV {
+    type Output = MacdVOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let atr = self.atr.nexta(input);
+        self.calculate(input, atr)
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for MacdV {
+    type Output = MacdVOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let atr = self.atr.nexta(input);
+        self.calculate(input.close(), atr)
+    }
+}
+
+impl Reset for MacdV {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.signal_ema.reset();
+        self.atr.reset();
+    }
+}
+
+impl Default for MacdV {
+    fn default() -> Self {
+        Self::new(12, 26, 9).unwrap()
+    }
+}
+
+impl fmt::Display for MacdV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "MACDV({}, {}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.signal_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(MacdV);
+
+    fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        let n0 = (nums.0 * 100.0).round() / 100.0;
+        let n1 = (nums.1 * 100.0).round() / 100.0;
+        let n2 = (nums.2 * 100.0).round() / 100.0;
+        (n0, n1, n2)
+    }
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(MacdV::new(0, 1, 1).is_err());
+        assert!(MacdV::new(1, 0, 1).is_err());
+        assert!(MacdV::new(1, 1, 0).is_err());
+        assert!(MacdV::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut macd_v = MacdV::new(3, 6, 4).unwrap();
+
+        assert_eq!(
+            round(macd_v.nexta(&bar(11.0, 9.0, 10.0)).into()),
+            (0.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            round(macd_v.nexta(&bar(12.0, 10.0, 11.5)).into()),
+            (16.07, 6.43, 9.64)
+        );
+        assert_eq!(
+            round(macd_v.nexta(&bar(13.0, 11.0, 12.5)).into()),
+            (30.23, 15.95, 14.28)
+        );
+        assert_eq!(
+            round(macd_v.nexta(&bar(12.5, 10.5, 11.0)).into()),
+            (14.9, 15.53, -0.63)
+        );
+    }
+
+    #[test]
+    fn test_zero_atr() {
+        let mut macd_v = MacdV::new(3, 6, 4).unwrap();
+
+        // the ATR of the first f64 input is 0
+        assert_eq!(round(macd_v.nexta(10.0).into()), (0.0, 0.0, 0.0));
+        assert!(macd_v.nexta(11.0).macd_v > 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut macd_v = MacdV::new(3, 6, 4).unwrap();
+
+        macd_v.nexta(&bar(11.0, 9.0, 10.0));
+        let out = macd_v.nexta(&bar(12.0, 10.0, 11.5));
+        macd_v.reset();
+        macd_v.nexta(&bar(11.0, 9.0, 10.0));
+        assert_eq!(macd_v.nexta(&bar(12.0, 10.0, 11.5)), out);
+    }
+
+    #[test]
+    fn test_default() {
+        MacdV::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let macd_v = MacdV::new(13, 30, 10).unwrap();
+        assert_eq!(format!("{}", macd_v), "MACDV(13, 30, 10)");
+    }
+}
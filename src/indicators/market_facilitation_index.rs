@@ -0,0 +1,213 @@
+use std::fmt;
+
+use crate::{High, Low, Nexta, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bill Williams' Market Facilitation Index (BW MFI).
+///
+/// The index measures how much the price moves per unit of volume. Together with the change of
+/// volume it is used to classify every bar into one of four states, see
+/// [MarketFacilitationColor](enum.MarketFacilitationColor.html).
+///
+/// # Formula
+///
+/// MFI = (High - Low) / Volume
+///
+/// When volume is 0 the index returns 0.
+///
+/// The color is determined by comparing MFI and volume with the values of the previous bar:
+///
+/// | MFI  | Volume | Color |
+/// |------|--------|-------|
+/// | up   | up     | Green |
+/// | down | down   | Fade  |
+/// | up   | down   | Fake  |
+/// | down | up     | Squat |
+///
+/// A value that did not increase is treated as "down". The first bar has no color.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{MarketFacilitationIndex, MarketFacilitationColor};
+/// use tars::{Nexta, DataItema};
+///
+/// let mut mfi = MarketFacilitationIndex::new();
+///
+/// let di1 = DataItema::builder()
+///             .high(3.0)
+///             .low(1.0)
+///             .close(2.0)
+///             .open(1.5)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// let di2 = DataItema::builder()
+///             .high(5.0)
+///             .low(1.0)
+///             .close(4.0)
+///             .open(2.0)
+///             .volume(160.0)
+///             .build().unwrap();
+///
+/// let out1 = mfi.nexta(&di1);
+/// assert_eq!(out1.mfi, 0.02);
+/// assert_eq!(out1.color, None);
+///
+/// let out2 = mfi.nexta(&di2);
+/// assert_eq!(out2.mfi, 0.025);
+/// assert_eq!(out2.color, Some(MarketFacilitationColor::Green));
+/// ```
+///
+/// # Links
+///
+/// * [Market Facilitation Index, Wikipedia](https://en.wikipedia.org/wiki/Market_facilitation_index)
+///
+#[doc(alias = "BWMFI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MarketFacilitationIndex {
+    prev: Option<(f64, f64)>,
+}
+
+/// Bill Williams' classification of a bar by the change of MFI and volume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketFacilitationColor {
+    /// MFI and volume are both up: the market is moving and new participants are entering.
+    Green,
+    /// MFI and volume are both down: the market is losing interest.
+    Fade,
+    /// MFI is up but volume is down: the move is not supported by volume.
+    Fake,
+    /// MFI is down but volume is up: a battle between buyers and sellers, often before a reversal.
+    Squat,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketFacilitationIndexOutput {
+    pub mfi: f64,
+    pub color: Option<MarketFacilitationColor>,
+}
+
+impl MarketFacilitationIndex {
+    pub fn new() -> Self {
+        Self { prev: None }
+    }
+}
+
+impl<T: High + Low + Volume> Nexta<&T> for MarketFacilitationIndex {
+    type Output = MarketFacilitationIndexOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let volume = input.volume();
+        let mfi = if volume == 0.0 {
+            0.0
+        } else {
+            (input.high() - input.low()) / volume
+        };
+
+        let color = self
+            .prev
+            .map(|(prev_mfi, prev_volume)| classify(mfi > prev_mfi, volume > prev_volume));
+        self.prev = Some((mfi, volume));
+
+        MarketFacilitationIndexOutput { mfi, color }
+    }
+}
+
+fn classify(mfi_up: bool, volume_up: bool) -> MarketFacilitationColor {
+    match (mfi_up, volume_up) {
+        (true, true) => MarketFacilitationColor::Green,
+        (false, false) => MarketFacilitationColor::Fade,
+        (true, false) => MarketFacilitationColor::Fake,
+        (false, true) => MarketFacilitationColor::Squat,
+    }
+}
+
+impl Default for MarketFacilitationIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for MarketFacilitationIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BW_MFI")
+    }
+}
+
+impl Reset for MarketFacilitationIndex {
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use MarketFacilitationColor::*;
+
+    #[test]
+    fn test_next_bar() {
+        let mut mfi = MarketFacilitationIndex::new();
+
+        let bar1 = Bar::new().high(3).low(1).volume(100.0);
+        let bar2 = Bar::new().high(5).low(1).volume(160.0);
+        let bar3 = Bar::new().high(4).low(2).volume(80.0);
+        let bar4 = Bar::new().high(6).low(2).volume(50.0);
+        let bar5 = Bar::new().high(4).low(3).volume(200.0);
+        let bar6 = Bar::new().high(4).low(3).volume(0.0);
+
+        let out = mfi.nexta(&bar1);
+        assert_eq!(out.mfi, 0.02);
+        assert_eq!(out.color, None);
+
+        let out = mfi.nexta(&bar2);
+        assert_eq!(out.mfi, 0.025);
+        assert_eq!(out.color, Some(Green));
+
+        let out = mfi.nexta(&bar3);
+        assert_eq!(out.mfi, 0.025);
+        assert_eq!(out.color, Some(Fade));
+
+        let out = mfi.nexta(&bar4);
+        assert_eq!(out.mfi, 0.08);
+        assert_eq!(out.color, Some(Fake));
+
+        let out = mfi.nexta(&bar5);
+        assert_eq!(out.mfi, 0.005);
+        assert_eq!(out.color, Some(Squat));
+
+        let out = mfi.nexta(&bar6);
+        assert_eq!(out.mfi, 0.0);
+        assert_eq!(out.color, Some(Fade));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mfi = MarketFacilitationIndex::new();
+
+        let bar1 = Bar::new().high(3).low(1).volume(100.0);
+        let bar2 = Bar::new().high(5).low(1).volume(160.0);
+
+        mfi.nexta(&bar1);
+        mfi.nexta(&bar2);
+
+        mfi.reset();
+        assert_eq!(mfi.nexta(&bar2).color, None);
+        assert_eq!(mfi.nexta(&bar1).color, Some(Fade));
+    }
+
+    #[test]
+    fn test_default() {
+        MarketFacilitationIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let mfi = MarketFacilitationIndex::new();
+        assert_eq!(format!("{}", mfi), "BW_MFI");
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt;
+
+use crate::{Close, Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Maximum drawdown (MDD).
+///
+/// Tracks the running peak of a series, typically an equity curve, and returns the current
+/// drawdown from it together with the maximum drawdown since the first input, both as an
+/// absolute value and in percent of the peak. All the drawdowns are reported as non-negative
+/// numbers.
+///
+/// # Formula
+///
+/// Peak<sub>t</sub> = max(X<sub>1</sub>, ..., X<sub>t</sub>)
+///
+/// DD<sub>t</sub> = Peak<sub>t</sub> - X<sub>t</sub>
+///
+/// DD%<sub>t</sub> = DD<sub>t</sub> / Peak<sub>t</sub> * 100
+///
+/// MDD<sub>t</sub> = max(DD<sub>1</sub>, ..., DD<sub>t</sub>), and likewise for the percent
+/// drawdowns. The two maxima can come from different drawdowns. The percent drawdown is 0 while
+/// the peak is not above 0.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::MaxDrawdown;
+/// use tars::Nexta;
+///
+/// let mut mdd = MaxDrawdown::new();
+///
+/// mdd.nexta(100.0);
+/// mdd.nexta(120.0);
+/// let out = mdd.nexta(90.0);
+/// assert_eq!((out.drawdown, out.drawdown_percent), (30.0, 25.0));
+/// let out = mdd.nexta(110.0);
+/// assert_eq!((out.max_drawdown, out.max_drawdown_percent), (30.0, 25.0));
+/// ```
+///
+/// # Links
+///
+/// * [Drawdown, Wikipedia](https://en.wikipedia.org/wiki/Drawdown_(economics))
+///
+#[doc(alias = "MDD")]
+#[doc(alias = "Drawdown")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MaxDrawdown {
+    peak: Option<f64>,
+    max_drawdown: f64,
+    max_drawdown_percent: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaxDrawdownOutput {
+    pub drawdown: f64,
+    pub drawdown_percent: f64,
+    pub max_drawdown: f64,
+    pub max_drawdown_percent: f64,
+}
+
+impl MaxDrawdown {
+    pub fn new() -> Self {
+        Self {
+            peak: None,
+            max_drawdown: 0.0,
+            max_drawdown_percent: 0.0,
+        }
+    }
+
+    /// Highest value so far, `None` before the first input.
+    pub fn peak(&self) -> Option<f64> {
+        self.peak
+    }
+}
+
+impl Nexta<f64> for MaxDrawdown {
+    type Output = MaxDrawdownOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let peak = match self.peak {
+            Some(peak) if peak >= input => peak,
+            _ => input,
+        };
+        self.peak = Some(peak);
+
+        let drawdown = peak - input;
+        let drawdown_percent = if peak > 0.0 {
+            drawdown / peak * 100.0
+        } else {
+            0.0
+        };
+        self.max_drawdown = self.max_drawdown.max(drawdown);
+        self.max_drawdown_percent = self.max_drawdown_percent.max(drawdown_percent);
+
+        MaxDrawdownOutput {
+            drawdown,
+            drawdown_percent,
+            max_drawdown: self.max_drawdown,
+            max_drawdown_percent: self.max_drawdown_percent,
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for MaxDrawdown {
+    type Output = MaxDrawdownOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for MaxDrawdown {
+    fn reset(&mut self) {
+        self.peak = None;
+        self.max_drawdown = 0.0;
+        self.max_drawdown_percent = 0.0;
+    }
+}
+
+impl Default for MaxDrawdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for MaxDrawdown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MDD")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(MaxDrawdown);
+
+    fn drawdowns(out: MaxDrawdownOutput) -> (f64, f64, f64, f64) {
+        (
+            round(out.drawdown),
+            round(out.drawdown_percent),
+            round(out.max_drawdown),
+            round(out.max_drawdown_percent),
+        )
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mdd = MaxDrawdown::new();
+
+        assert_eq!(drawdowns(mdd.nexta(100.0)), (0.0, 0.0, 0.0, 0.0));
+        assert_eq!(drawdowns(mdd.nexta(120.0)), (0.0, 0.0, 0.0, 0.0));
+        assert_eq!(drawdowns(mdd.nexta(90.0)), (30.0, 25.0, 30.0, 25.0));
+        assert_eq!(drawdowns(mdd.nexta(110.0)), (10.0, 8.333, 30.0, 25.0));
+        assert_eq!(drawdowns(mdd.nexta(130.0)), (0.0, 0.0, 30.0, 25.0));
+        assert_eq!(drawdowns(mdd.nexta(104.0)), (26.0, 20.0, 30.0, 25.0));
+        assert_eq!(mdd.peak(), Some(130.0));
+    }
+
+    #[test]
+    fn test_next_separate_maxima() {
+        let mut mdd = MaxDrawdown::new();
+
+        mdd.nexta(10.0);
+        assert_eq!(drawdowns(mdd.nexta(5.0)), (5.0, 50.0, 5.0, 50.0));
+        mdd.nexta(100.0);
+        assert_eq!(drawdowns(mdd.nexta(80.0)), (20.0, 20.0, 20.0, 50.0));
+    }
+
+    #[test]
+    fn test_next_non_positive_peak() {
+        let mut mdd = MaxDrawdown::new();
+
+        mdd.nexta(-5.0);
+        assert_eq!(drawdowns(mdd.nexta(-8.0)), (3.0, 0.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mdd = MaxDrawdown::new();
+
+        mdd.nexta(100.0);
+        mdd.nexta(50.0);
+        mdd.reset();
+        assert_eq!(mdd.peak(), None);
+        assert_eq!(drawdowns(mdd.nexta(40.0)), (0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        MaxDrawdown::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let mdd = MaxDrawdown::new();
+        assert_eq!(format!("{}", mdd), "MDD");
+    }
+}
@@ -0,0 +1,280 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::StandardDeviation as Sd;
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Spread z-score mean reversion block.
+///
+/// Combines the spread of two series, its rolling z-score and entry/exit thresholds into a
+/// single component. Every input is a pair of values _(A, B)_, e.g. the closes of two correlated
+/// instruments or the outputs of two indicators.
+///
+/// The block enters a [Short](enum.MeanReversionPosition.html#variant.Short) position on the
+/// spread when the z-score rises to _entry_ and a [Long](enum.MeanReversionPosition.html#variant.Long)
+/// position when it falls to -_entry_. An open position is closed when the z-score returns to
+/// _exit_ (for short) or -_exit_ (for long). A position can be closed and the opposite one opened
+/// on the same input.
+///
+/// # Formula
+///
+/// * _Spread_ = A - B ([Difference](enum.SpreadKind.html#variant.Difference)) or
+///   A / B ([Ratio](enum.SpreadKind.html#variant.Ratio))
+/// * _Z-Score_ = (Spread - SMA(_period_) of Spread) / SD(_period_) of Spread
+///
+/// When the standard deviation is 0 the z-score is 0. For ratio spread B must not be 0.
+///
+/// # Parameters
+///
+/// * _kind_ - how the spread is computed. Default is difference.
+/// * _period_ - z-score window (integer greater than 0). Default is 20.
+/// * _entry_ - entry threshold (number greater than _exit_). Default is 2.
+/// * _exit_ - exit threshold (number greater than or equal to 0). Default is 0.5.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{MeanReversionBlock, MeanReversionPosition, SpreadKind};
+/// use tars::Nexta;
+///
+/// let mut block = MeanReversionBlock::new(SpreadKind::Difference, 3, 1.0, 0.5).unwrap();
+///
+/// assert_eq!(block.nexta((10.0, 10.0)).position, MeanReversionPosition::Flat);
+///
+/// let out = block.nexta((11.0, 10.0));
+/// assert_eq!(out.spread, 1.0);
+/// assert_eq!(out.zscore, 1.0);
+/// assert_eq!(out.position, MeanReversionPosition::Short);
+/// ```
+///
+/// # Links
+///
+/// * [Pairs trade, Wikipedia](https://en.wikipedia.org/wiki/Pairs_trade)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MeanReversionBlock {
+    kind: SpreadKind,
+    entry: f64,
+    exit: f64,
+    sd: Sd,
+    position: MeanReversionPosition,
+}
+
+/// How the spread of two series is computed.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpreadKind {
+    /// A - B
+    Difference,
+    /// A / B
+    Ratio,
+}
+
+impl SpreadKind {
+    /// Computes the spread of the given values.
+    pub fn spread(self, a: f64, b: f64) -> f64 {
+        match self {
+            SpreadKind::Difference => a - b,
+            SpreadKind::Ratio => a / b,
+        }
+    }
+}
+
+/// Position on the spread held by [MeanReversionBlock](struct.MeanReversionBlock.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeanReversionPosition {
+    /// Long A, short B.
+    Long,
+    Flat,
+    /// Short A, long B.
+    Short,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeanReversionBlockOutput {
+    pub spread: f64,
+    pub zscore: f64,
+    pub position: MeanReversionPosition,
+}
+
+impl MeanReversionBlock {
+    pub fn new(kind: SpreadKind, period: usize, entry: f64, exit: f64) -> Result<Self> {
+        if !(exit >= 0.0 && entry > exit && entry.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            kind,
+            entry,
+            exit,
+            sd: Sd::new(period)?,
+            position: MeanReversionPosition::Flat,
+        })
+    }
+
+    pub fn kind(&self) -> SpreadKind {
+        self.kind
+    }
+
+    pub fn entry(&self) -> f64 {
+        self.entry
+    }
+
+    pub fn exit(&self) -> f64 {
+        self.exit
+    }
+
+    /// Returns the current position on the spread.
+    pub fn position(&self) -> MeanReversionPosition {
+        self.position
+    }
+}
+
+impl Period for MeanReversionBlock {
+    fn period(&self) -> usize {
+        self.sd.period()
+    }
+}
+
+impl Nexta<(f64, f64)> for MeanReversionBlock {
+    type Output = MeanReversionBlockOutput;
+
+    fn nexta(&mut self, (a, b): (f64, f64)) -> Self::Output {
+        let spread = self.kind.spread(a, b);
+        let sd = self.sd.nexta(spread);
+        let zscore = if sd == 0.0 {
+            0.0
+        } else {
+            (spread - self.sd.mean()) / sd
+        };
+
+        self.position = match self.position {
+            MeanReversionPosition::Long if zscore >= -self.exit => MeanReversionPosition::Flat,
+            MeanReversionPosition::Short if zscore <= self.exit => MeanReversionPosition::Flat,
+            position => position,
+        };
+        if self.position == MeanReversionPosition::Flat {
+            if zscore >= self.entry {
+                self.position = MeanReversionPosition::Short;
+            } else if zscore <= -self.entry {
+                self.position = MeanReversionPosition::Long;
+            }
+        }
+
+        MeanReversionBlockOutput {
+            spread,
+            zscore,
+            position: self.position,
+        }
+    }
+}
+
+impl Reset for MeanReversionBlock {
+    fn reset(&mut self) {
+        self.sd.reset();
+        self.position = MeanReversionPosition::Flat;
+    }
+}
+
+impl Default for MeanReversionBlock {
+    fn default() -> Self {
+        Self::new(SpreadKind::Difference, 20, 2.0, 0.5).unwrap()
+    }
+}
+
+impl fmt::Display for MeanReversionBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self.kind {
+            SpreadKind::Difference => "DIFF",
+            SpreadKind::Ratio => "RATIO",
+        };
+        write!(
+            f,
+            "MR({}, {}, {}, {})",
+            kind,
+            self.sd.period(),
+            self.entry,
+            self.exit
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use MeanReversionPosition::*;
+
+    #[test]
+    fn test_new() {
+        assert!(MeanReversionBlock::new(SpreadKind::Difference, 0, 2.0, 0.5).is_err());
+        assert!(MeanReversionBlock::new(SpreadKind::Difference, 3, 0.5, 0.5).is_err());
+        assert!(MeanReversionBlock::new(SpreadKind::Difference, 3, 2.0, -0.1).is_err());
+        assert!(MeanReversionBlock::new(SpreadKind::Difference, 3, f64::NAN, 0.5).is_err());
+        assert!(MeanReversionBlock::new(SpreadKind::Difference, 3, 2.0, 0.0).is_ok());
+        assert!(MeanReversionBlock::new(SpreadKind::Ratio, 3, 2.0, 0.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut block = MeanReversionBlock::new(SpreadKind::Difference, 3, 1.0, 0.5).unwrap();
+
+        let out = block.nexta((10.0, 10.0));
+        assert_eq!((out.spread, out.zscore, out.position), (0.0, 0.0, Flat));
+
+        let out = block.nexta((11.0, 10.0));
+        assert_eq!((out.spread, out.zscore, out.position), (1.0, 1.0, Short));
+
+        // z-score 0.707 is still above the exit threshold
+        let out = block.nexta((12.0, 11.0));
+        assert_eq!(round(out.zscore), 0.707);
+        assert_eq!(out.position, Short);
+
+        // the short position is closed and a long one opened on the same input
+        let out = block.nexta((10.0, 10.0));
+        assert_eq!(round(out.zscore), -1.414);
+        assert_eq!(out.position, Long);
+
+        let out = block.nexta((10.5, 10.0));
+        assert_eq!(round(out.zscore), 0.0);
+        assert_eq!(out.position, Flat);
+    }
+
+    #[test]
+    fn test_next_ratio() {
+        let mut block = MeanReversionBlock::new(SpreadKind::Ratio, 2, 1.0, 0.5).unwrap();
+
+        assert_eq!(block.nexta((20.0, 10.0)).spread, 2.0);
+
+        let out = block.nexta((15.0, 10.0));
+        assert_eq!((out.spread, out.zscore, out.position), (1.5, -1.0, Long));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut block = MeanReversionBlock::new(SpreadKind::Difference, 3, 1.0, 0.5).unwrap();
+
+        block.nexta((10.0, 10.0));
+        block.nexta((11.0, 10.0));
+        assert_eq!(block.position(), Short);
+
+        block.reset();
+        assert_eq!(block.position(), Flat);
+        assert_eq!(block.nexta((11.0, 10.0)).zscore, 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        MeanReversionBlock::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let block = MeanReversionBlock::new(SpreadKind::Ratio, 30, 2.5, 0.0).unwrap();
+        assert_eq!(format!("{}", block), "MR(RATIO, 30, 2.5, 0)");
+    }
+}
@@ -0,0 +1,154 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::RollingOrderStatistics;
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling median.
+///
+/// Returns the middle value of the last _period_ values, or the mean of the two middle values
+/// if the window holds an even number of values. Unlike the simple moving average it is not
+/// pulled away by single outliers.
+///
+/// The window is kept in an order-statistics structure, so every update takes O(log _period_)
+/// amortized time instead of sorting the window.
+///
+/// # Parameters
+///
+/// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::Median;
+/// use tars::Nexta;
+///
+/// let mut median = Median::new(3).unwrap();
+/// assert_eq!(median.nexta(7.0), 7.0);
+/// assert_eq!(median.nexta(5.0), 6.0);
+/// assert_eq!(median.nexta(100.0), 7.0);
+/// assert_eq!(median.nexta(4.0), 5.0);
+/// ```
+///
+/// # Links
+///
+/// * [Median filter, Wikipedia](https://en.wikipedia.org/wiki/Median_filter)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Median {
+    window: RollingOrderStatistics,
+}
+
+impl Median {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            window: RollingOrderStatistics::new(period)?,
+        })
+    }
+}
+
+impl Period for Median {
+    fn period(&self) -> usize {
+        self.window.period()
+    }
+}
+
+impl Nexta<f64> for Median {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        self.window.push(input);
+        self.window.quantile(0.5)
+    }
+}
+
+impl<T: Close> Nexta<&T> for Median {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for Median {
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+impl Default for Median {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for Median {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MEDIAN({})", self.window.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Median);
+
+    #[test]
+    fn test_new() {
+        assert!(Median::new(0).is_err());
+        assert!(Median::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut median = Median::new(4).unwrap();
+
+        assert_eq!(median.nexta(4.0), 4.0);
+        assert_eq!(median.nexta(2.0), 3.0);
+        assert_eq!(median.nexta(9.0), 4.0);
+        assert_eq!(median.nexta(2.0), 3.0);
+        // 4.0 leaves the window
+        assert_eq!(median.nexta(7.0), 4.5);
+        // 2.0 leaves the window
+        assert_eq!(median.nexta(-1.0), 4.5);
+        assert_eq!(median.nexta(2.0), 2.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut median = Median::new(3).unwrap();
+        assert_eq!(median.nexta(&bar(4.0)), 4.0);
+        assert_eq!(median.nexta(&bar(8.0)), 6.0);
+        assert_eq!(median.nexta(&bar(5.0)), 5.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut median = Median::new(3).unwrap();
+
+        median.nexta(4.0);
+        median.nexta(8.0);
+        median.reset();
+        assert_eq!(median.nexta(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Median::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let median = Median::new(7).unwrap();
+        assert_eq!(format!("{}", median), "MEDIAN(7)");
+    }
+}
@@ -67,3 +67,254 @@ pub use self::money_flow_index::MoneyFlowIndex;
 
 mod on_balance_volume;
 pub use self::on_balance_volume::OnBalanceVolume;
+
+mod pretty_good_oscillator;
+pub use self::pretty_good_oscillator::PrettyGoodOscillator;
+
+mod gopalakrishnan_range_index;
+pub use self::gopalakrishnan_range_index::GopalakrishnanRangeIndex;
+
+mod tillson_t3;
+pub use self::tillson_t3::TillsonT3;
+
+mod market_facilitation_index;
+pub use self::market_facilitation_index::{
+    MarketFacilitationColor, MarketFacilitationIndex, MarketFacilitationIndexOutput,
+};
+
+mod adaptive_zones;
+pub use self::adaptive_zones::{AdaptiveZoneState, AdaptiveZones, AdaptiveZonesOutput};
+
+mod linear_regression;
+pub use self::linear_regression::{LinearRegression, LinearRegressionOutput};
+
+mod score_aggregator;
+pub use self::score_aggregator::{ScoreAggregator, ScoreAggregatorOutput, ScoreSignal};
+
+mod pivot_points;
+pub use self::pivot_points::{PivotKind, PivotLevels, PivotPoints};
+
+mod heikin_ashi;
+pub use self::heikin_ashi::{HeikinAshi, HeikinAshiBar};
+
+mod zig_zag;
+pub use self::zig_zag::{SwingKind, SwingPoint, ZigZag, ZigZagThreshold};
+
+mod opening_range;
+pub use self::opening_range::{Breakout, OpeningRange, OpeningRangeOutput};
+
+mod session_stats;
+pub use self::session_stats::{SessionStats, SessionStatsOutput};
+
+mod expected_move;
+pub use self::expected_move::{ExpectedMove, ExpectedMoveOutput, ExpectedMoveSource};
+
+mod balance_of_power;
+pub use self::balance_of_power::BalanceOfPower;
+
+mod mean_reversion_block;
+pub use self::mean_reversion_block::{
+    MeanReversionBlock, MeanReversionBlockOutput, MeanReversionPosition, SpreadKind,
+};
+
+mod ease_of_movement;
+pub use self::ease_of_movement::EaseOfMovement;
+
+mod up_down_volume_ratio;
+pub use self::up_down_volume_ratio::UpDownVolumeRatio;
+
+mod alligator;
+pub use self::alligator::{Alligator, AlligatorOutput};
+
+mod schaff_trend_cycle;
+pub use self::schaff_trend_cycle::SchaffTrendCycle;
+
+mod moving_average;
+pub use self::moving_average::MovingAverageKind;
+pub(crate) use self::moving_average::{wilder, MovingAverage};
+
+mod qstick;
+pub use self::qstick::Qstick;
+
+mod klinger_volume_oscillator;
+pub use self::klinger_volume_oscillator::{KlingerVolumeOscillator, KlingerVolumeOscillatorOutput};
+
+mod relative_vigor_index;
+pub use self::relative_vigor_index::{RelativeVigorIndex, RelativeVigorIndexOutput};
+
+mod chande_momentum_oscillator;
+pub use self::chande_momentum_oscillator::ChandeMomentumOscillator;
+
+mod stochastic_momentum_index;
+pub use self::stochastic_momentum_index::{StochasticMomentumIndex, StochasticMomentumIndexOutput};
+
+mod exponentially_weighted_correlation;
+pub use self::exponentially_weighted_correlation::{
+    ExponentiallyWeightedCorrelation, ExponentiallyWeightedCorrelationOutput,
+};
+
+mod ewma_volatility;
+pub use self::ewma_volatility::EwmaVolatility;
+
+mod center_of_gravity;
+pub use self::center_of_gravity::{CenterOfGravity, CenterOfGravityOutput};
+
+mod garch11;
+pub use self::garch11::{Garch11, GarchParameters};
+
+mod super_smoother;
+pub use self::super_smoother::SuperSmoother;
+
+mod downside_deviation;
+pub use self::downside_deviation::DownsideDeviation;
+
+mod realized_semivariance;
+pub use self::realized_semivariance::{RealizedSemivariance, RealizedSemivarianceOutput};
+
+mod kalman_filter;
+pub use self::kalman_filter::{KalmanFilter, KalmanFilterOutput};
+
+mod omega_ratio;
+pub use self::omega_ratio::OmegaRatio;
+
+mod gain_pain_ratio;
+pub use self::gain_pain_ratio::GainPainRatio;
+
+mod order_statistics;
+pub(crate) use self::order_statistics::RollingOrderStatistics;
+
+mod median;
+pub use self::median::Median;
+
+mod quantile;
+pub use self::quantile::Quantile;
+
+mod starc_bands;
+pub use self::starc_bands::{StarcBands, StarcBandsOutput};
+
+mod bollinger_bands_extended;
+pub use self::bollinger_bands_extended::{BollingerBandsExtended, BollingerBandsExtendedOutput};
+
+mod momentum;
+pub use self::momentum::Momentum;
+
+mod williams_fractals;
+pub use self::williams_fractals::{Fractal, WilliamsFractals};
+
+mod regime_detector;
+pub use self::regime_detector::{RegimeDetector, RegimeLabel, RegimeModel, RegimeOutput};
+
+mod volume_profile;
+pub use self::volume_profile::{VolumeProfile, VolumeProfileOutput};
+
+mod cusum_filter;
+pub use self::cusum_filter::{CusumEvent, CusumFilter};
+
+mod variance;
+pub use self::variance::{Variance, VarianceMode};
+
+mod frac_diff;
+pub use self::frac_diff::FracDiff;
+
+mod rolling_mutual_information;
+pub use self::rolling_mutual_information::RollingMutualInformation;
+
+mod z_score;
+pub use self::z_score::ZScore;
+
+mod cross_correlation;
+pub use self::cross_correlation::{CrossCorrelation, CrossCorrelationOutput};
+
+mod seasonal_decomposition;
+pub use self::seasonal_decomposition::{SeasonalDecomposition, SeasonalDecompositionOutput};
+
+mod rolling_sharpe;
+pub use self::rolling_sharpe::RollingSharpe;
+
+mod rolling_sortino;
+pub use self::rolling_sortino::RollingSortino;
+
+mod rolling_mae;
+pub use self::rolling_mae::RollingMae;
+
+mod rolling_rmse;
+pub use self::rolling_rmse::RollingRmse;
+
+mod rolling_mape;
+pub use self::rolling_mape::RollingMape;
+
+mod max_drawdown;
+pub use self::max_drawdown::{MaxDrawdown, MaxDrawdownOutput};
+
+mod ulcer_index;
+pub use self::ulcer_index::UlcerIndex;
+
+mod co_moments;
+pub(crate) use self::co_moments::RollingCoMoments;
+
+mod rolling_correlation;
+pub use self::rolling_correlation::RollingCorrelation;
+
+mod rolling_beta;
+pub use self::rolling_beta::RollingBeta;
+
+mod ring_buffer;
+pub(crate) use self::ring_buffer::RingBuffer;
+
+mod rolling_covariance;
+pub use self::rolling_covariance::RollingCovariance;
+
+mod pair_spread;
+pub use self::pair_spread::{PairSpread, PairSpreadOutput};
+
+mod historical_volatility;
+pub use self::historical_volatility::HistoricalVolatility;
+
+mod range_volatility;
+pub use self::range_volatility::{RangeEstimator, RangeVolatility};
+
+mod weighted_percentile;
+pub use self::weighted_percentile::WeightedPercentile;
+
+mod level_book;
+pub use self::level_book::{Expiry, Level, LevelBook, LevelEvent, LevelEventKind, LevelSource};
+
+mod pivot_detector;
+pub use self::pivot_detector::{Pivot, PivotDetector};
+
+mod divergence_detector;
+pub use self::divergence_detector::{
+    Divergence, DivergenceDetector, DivergenceKind, ExternalOscillator,
+};
+
+mod macd_v;
+pub use self::macd_v::{MacdV, MacdVOutput};
+
+mod percentage_volume_oscillator;
+pub use self::percentage_volume_oscillator::{
+    PercentageVolumeOscillator, PercentageVolumeOscillatorOutput,
+};
+
+mod vertical_horizontal_filter;
+pub use self::vertical_horizontal_filter::VerticalHorizontalFilter;
+
+mod trend_intensity_index;
+pub use self::trend_intensity_index::TrendIntensityIndex;
+
+mod average_directional_index;
+pub use self::average_directional_index::{AverageDirectionalIndex, AverageDirectionalIndexOutput};
+
+mod adxr;
+pub use self::adxr::Adxr;
+
+mod intraday_momentum_index;
+pub use self::intraday_momentum_index::IntradayMomentumIndex;
+
+mod twiggs_money_flow;
+pub use self::twiggs_money_flow::TwiggsMoneyFlow;
+
+mod ttm_squeeze;
+pub use self::ttm_squeeze::{TtmSqueeze, TtmSqueezeOutput};
+
+mod prior_period_levels;
+pub use self::prior_period_levels::{PriorPeriodLevels, PriorPeriodLevelsOutput};
@@ -0,0 +1,195 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::traits::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Momentum (MOM)
+///
+/// The difference between the current price and the price _n_ periods ago. Unlike
+/// [RateOfChange](crate::indicators::RateOfChange) it is expressed in price units rather than
+/// as a percentage, and is a building block of composite indicators such as KST, Coppock and TSI.
+///
+/// # Formula
+///
+/// MOM = Price<sub>t</sub> - Price<sub>t-n</sub>
+///
+/// Where:
+///
+/// * MOM - current value of Momentum indicator
+/// * P<sub>t</sub> - price at the moment
+/// * P<sub>t-n</sub> - price _n_ periods ago
+///
+/// Until _n_ prices have been processed, the first price is used as P<sub>t-n</sub>.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods integer greater than 0. Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::Momentum;
+/// use tars::Nexta;
+///
+/// let mut mom = Momentum::new(2).unwrap();
+/// assert_eq!(mom.nexta(10.0), 0.0);   //  0
+/// assert_eq!(mom.nexta(9.5), -0.5);   //  9.5 - 10 = -0.5
+/// assert_eq!(mom.nexta(12.0), 2.0);   //  12 - 10  = 2
+/// assert_eq!(mom.nexta(13.0), 3.5);   //  13 - 9.5 = 3.5
+/// ```
+///
+/// # Links
+///
+/// * [Momentum, Wikipedia](https://en.wikipedia.org/wiki/Momentum_(technical_analysis))
+///
+#[doc(alias = "MOM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Momentum {
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+impl Momentum {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for Momentum {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for Momentum {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> f64 {
+        let previous = if self.count > self.period {
+            self.deque[self.index]
+        } else {
+            self.count += 1;
+            if self.count == 1 {
+                input
+            } else {
+                self.deque[0]
+            }
+        };
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        input - previous
+    }
+}
+
+impl<T: Close> Nexta<&T> for Momentum {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> f64 {
+        self.nexta(input.close())
+    }
+}
+
+impl Default for Momentum {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for Momentum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MOM({})", self.period)
+    }
+}
+
+impl Reset for Momentum {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Momentum);
+
+    #[test]
+    fn test_new() {
+        assert!(Momentum::new(0).is_err());
+        assert!(Momentum::new(1).is_ok());
+        assert!(Momentum::new(100_000).is_ok());
+    }
+
+    #[test]
+    fn test_next_f64() {
+        let mut mom = Momentum::new(3).unwrap();
+
+        assert_eq!(round(mom.nexta(10.0)), 0.0);
+        assert_eq!(round(mom.nexta(10.4)), 0.4);
+        assert_eq!(round(mom.nexta(10.57)), 0.57);
+        assert_eq!(round(mom.nexta(10.8)), 0.8);
+        assert_eq!(round(mom.nexta(10.9)), 0.5);
+        assert_eq!(round(mom.nexta(10.0)), -0.57);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut mom = Momentum::new(1).unwrap();
+
+        assert_eq!(round(mom.nexta(&bar(10.0))), 0.0);
+        assert_eq!(round(mom.nexta(&bar(10.4))), 0.4);
+        assert_eq!(round(mom.nexta(&bar(10.1))), -0.3);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mom = Momentum::new(3).unwrap();
+
+        mom.nexta(12.3);
+        mom.nexta(15.0);
+
+        mom.reset();
+
+        assert_eq!(round(mom.nexta(10.0)), 0.0);
+        assert_eq!(round(mom.nexta(10.4)), 0.4);
+    }
+
+    #[test]
+    fn test_default() {
+        Momentum::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let mom = Momentum::new(12).unwrap();
+        assert_eq!(format!("{}", mom), "MOM(12)");
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kind of moving average used by indicators with a selectable smoothing.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovingAverageKind {
+    /// [SimpleMovingAverage](struct.SimpleMovingAverage.html)
+    Simple,
+    /// [ExponentialMovingAverage](struct.ExponentialMovingAverage.html)
+    Exponential,
+}
+
+/// Wilder's smoothed moving average (SMMA, RMA).
+///
+/// Wilder's smoothing with α = 1 / _period_ is an EMA of 2 * _period_ - 1.
+pub(crate) fn wilder(period: usize) -> Result<Ema> {
+    if period == 0 {
+        return Err(TaError::InvalidParameter);
+    }
+    Ema::new(2 * period - 1)
+}
+
+/// Moving average of a selectable kind.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) enum MovingAverage {
+    Simple(Sma),
+    Exponential(Ema),
+}
+
+impl MovingAverage {
+    pub(crate) fn new(kind: MovingAverageKind, period: usize) -> Result<Self> {
+        Ok(match kind {
+            MovingAverageKind::Simple => MovingAverage::Simple(Sma::new(period)?),
+            MovingAverageKind::Exponential => MovingAverage::Exponential(Ema::new(period)?),
+        })
+    }
+
+    pub(crate) fn kind(&self) -> MovingAverageKind {
+        match self {
+            MovingAverage::Simple(_) => MovingAverageKind::Simple,
+            MovingAverage::Exponential(_) => MovingAverageKind::Exponential,
+        }
+    }
+}
+
+impl Period for MovingAverage {
+    fn period(&self) -> usize {
+        match self {
+            MovingAverage::Simple(ma) => ma.period(),
+            MovingAverage::Exponential(ma) => ma.period(),
+        }
+    }
+}
+
+impl Nexta<f64> for MovingAverage {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        match self {
+            MovingAverage::Simple(ma) => ma.nexta(input),
+            MovingAverage::Exponential(ma) => ma.nexta(input),
+        }
+    }
+}
+
+impl Reset for MovingAverage {
+    fn reset(&mut self) {
+        match self {
+            MovingAverage::Simple(ma) => ma.reset(),
+            MovingAverage::Exponential(ma) => ma.reset(),
+        }
+    }
+}
+
+impl fmt::Display for MovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MovingAverage::Simple(ma) => ma.fmt(f),
+            MovingAverage::Exponential(ma) => ma.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling Omega ratio.
+///
+/// Relates the gains above a threshold return to the losses below it over a rolling window of
+/// returns. Unlike Sharpe or Sortino it takes the whole return distribution into account. Values
+/// above 1 mean that the gains above the threshold outweigh the shortfalls. The inputs are
+/// returns, not prices.
+///
+/// # Formula
+///
+/// Ω = Σ max(r<sub>i</sub> - _threshold_, 0) / Σ max(_threshold_ - r<sub>i</sub>, 0)
+///
+/// over the last _period_ returns. When there is no shortfall in the window the ratio is
+/// infinite, or 1 if there are no gains either.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 0). Default is 20.
+/// * _threshold_ - threshold return (number). Default is 0.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::OmegaRatio;
+/// use tars::Nexta;
+///
+/// let mut omega = OmegaRatio::new(3, 0.0).unwrap();
+///
+/// omega.nexta(0.02);
+/// omega.nexta(-0.01);
+/// assert_eq!(omega.nexta(0.04), 6.0);
+/// ```
+///
+/// # Links
+///
+/// * [Omega ratio, Wikipedia](https://en.wikipedia.org/wiki/Omega_ratio)
+///
+#[doc(alias = "Omega")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct OmegaRatio {
+    period: usize,
+    threshold: f64,
+    index: usize,
+    count: usize,
+    gains: f64,
+    losses: f64,
+    // number of gains and losses in the window, the sum is zeroed at 0 to drop float residue
+    gain_count: usize,
+    loss_count: usize,
+    deque: Box<[f64]>,
+}
+
+impl OmegaRatio {
+    pub fn new(period: usize, threshold: f64) -> Result<Self> {
+        if period == 0 || !threshold.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            period,
+            threshold,
+            index: 0,
+            count: 0,
+            gains: 0.0,
+            losses: 0.0,
+            gain_count: 0,
+            loss_count: 0,
+            deque: vec![0.0; period].into_boxed_slice(),
+        })
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+}
+
+impl Period for OmegaRatio {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for OmegaRatio {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let excess = input - self.threshold;
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old_val = self.deque[self.index];
+            if old_val > 0.0 {
+                self.gain_count -= 1;
+                self.gains = if self.gain_count > 0 {
+                    (self.gains - old_val).max(0.0)
+                } else {
+                    0.0
+                };
+            } else if old_val < 0.0 {
+                self.loss_count -= 1;
+                self.losses = if self.loss_count > 0 {
+                    (self.losses + old_val).max(0.0)
+                } else {
+                    0.0
+                };
+            }
+        }
+
+        if excess > 0.0 {
+            self.gain_count += 1;
+            self.gains += excess;
+        } else if excess < 0.0 {
+            self.loss_count += 1;
+            self.losses -= excess;
+        }
+
+        self.deque[self.index] = excess;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.losses > 0.0 {
+            self.gains / self.losses
+        } else if self.gains > 0.0 {
+            f64::INFINITY
+        } else {
+            1.0
+        }
+    }
+}
+
+impl Reset for OmegaRatio {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.gains = 0.0;
+        self.losses = 0.0;
+        self.gain_count = 0;
+        self.loss_count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for OmegaRatio {
+    fn default() -> Self {
+        Self::new(20, 0.0).unwrap()
+    }
+}
+
+impl fmt::Display for OmegaRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "OMEGA({}, {})", self.period, self.threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(OmegaRatio::new(0, 0.0).is_err());
+        assert!(OmegaRatio::new(1, f64::NAN).is_err());
+        assert!(OmegaRatio::new(1, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut omega = OmegaRatio::new(3, 0.0).unwrap();
+
+        assert_eq!(omega.nexta(0.0), 1.0);
+        assert_eq!(omega.nexta(0.02), f64::INFINITY);
+        assert_eq!(omega.nexta(-0.01), 2.0);
+        // 0.0 leaves the window
+        assert_eq!(omega.nexta(0.04), 6.0);
+        // 0.02 leaves the window
+        assert_eq!(round(omega.nexta(-0.03)), 1.0);
+        assert_eq!(round(omega.nexta(-0.01)), 1.0);
+    }
+
+    #[test]
+    fn test_next_with_threshold() {
+        let mut omega = OmegaRatio::new(2, 0.01).unwrap();
+
+        assert_eq!(omega.nexta(0.0), 0.0);
+        assert_eq!(round(omega.nexta(0.04)), 3.0);
+    }
+
+    #[test]
+    fn test_next_losses_leave_window() {
+        let mut omega = OmegaRatio::new(2, 0.0).unwrap();
+
+        omega.nexta(-0.013);
+        omega.nexta(-0.029);
+        omega.nexta(0.01);
+        assert_eq!(omega.nexta(0.01), f64::INFINITY);
+        assert_eq!(omega.nexta(0.01), f64::INFINITY);
+
+        let mut omega = OmegaRatio::new(2, 0.0).unwrap();
+
+        omega.nexta(0.013);
+        omega.nexta(0.029);
+        omega.nexta(-0.01);
+        assert_eq!(omega.nexta(-0.01), 0.0);
+        assert_eq!(omega.nexta(-0.01), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut omega = OmegaRatio::new(3, 0.0).unwrap();
+
+        omega.nexta(-0.01);
+        omega.reset();
+        assert_eq!(omega.nexta(0.01), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_default() {
+        OmegaRatio::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let omega = OmegaRatio::new(20, 0.001).unwrap();
+        assert_eq!(format!("{}", omega), "OMEGA(20, 0.001)");
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Opening range breakout (ORB).
+///
+/// Captures the high and low of the first _period_ bars of every session and then reports
+/// whether the close of the following bars of the same session is above or below that range.
+///
+/// Bars carry no timestamps, so the duration of the opening range is given as a number of bars
+/// (e.g. 6 bars of 5 minutes for a 30 minutes opening range) and the start of a session is
+/// signaled by calling [new_session](struct.OpeningRange.html#method.new_session) before the
+/// first bar of the session. The bars received before the first call belong to the first session.
+///
+/// # Formula
+///
+/// * _High_ = highest high of the first _period_ bars of the session
+/// * _Low_ = lowest low of the first _period_ bars of the session
+///
+/// Once the range is complete, the breakout is [Up](enum.Breakout.html#variant.Up) when close is
+/// above the high, [Down](enum.Breakout.html#variant.Down) when close is below the low and `None`
+/// otherwise. While the range is being formed the breakout is always `None`.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars of the opening range (integer greater than 0). Default is 30.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{Breakout, OpeningRange};
+/// use tars::{Nexta, DataItema};
+///
+/// fn bar(high: f64, low: f64, close: f64) -> DataItema {
+///     DataItema::builder()
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .open(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// }
+///
+/// let mut orb = OpeningRange::new(2).unwrap();
+///
+/// orb.nexta(&bar(10.0, 9.0, 9.5));
+/// let out = orb.nexta(&bar(10.5, 9.5, 10.0));
+/// assert!(out.complete);
+/// assert_eq!((out.high, out.low), (10.5, 9.0));
+///
+/// assert_eq!(orb.nexta(&bar(11.0, 10.0, 10.8)).breakout, Some(Breakout::Up));
+///
+/// orb.new_session();
+/// assert_eq!(orb.nexta(&bar(9.0, 8.0, 8.5)).breakout, None);
+/// ```
+///
+/// # Links
+///
+/// * [Opening Range Breakout, Investopedia](https://www.investopedia.com/terms/o/openingrange.asp)
+///
+#[doc(alias = "ORB")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct OpeningRange {
+    period: usize,
+    count: usize,
+    high: f64,
+    low: f64,
+}
+
+/// Direction of a breakout from a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakout {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpeningRangeOutput {
+    pub high: f64,
+    pub low: f64,
+    /// Whether all the bars of the opening range were received.
+    pub complete: bool,
+    pub breakout: Option<Breakout>,
+}
+
+impl OpeningRange {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                count: 0,
+                high: f64::NEG_INFINITY,
+                low: f64::INFINITY,
+            }),
+        }
+    }
+
+    /// Starts a new session, the next bar is the first bar of its opening range.
+    pub fn new_session(&mut self) {
+        self.count = 0;
+        self.high = f64::NEG_INFINITY;
+        self.low = f64::INFINITY;
+    }
+
+    /// Whether the opening range of the current session is complete.
+    pub fn is_complete(&self) -> bool {
+        self.count >= self.period
+    }
+}
+
+impl Period for OpeningRange {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for OpeningRange {
+    type Output = OpeningRangeOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let breakout = if self.is_complete() {
+            if input.close() > self.high {
+                Some(Breakout::Up)
+            } else if input.close() < self.low {
+                Some(Breakout::Down)
+            } else {
+                None
+            }
+        } else {
+            self.count += 1;
+            self.high = self.high.max(input.high());
+            self.low = self.low.min(input.low());
+            None
+        };
+
+        OpeningRangeOutput {
+            high: self.high,
+            low: self.low,
+            complete: self.is_complete(),
+            breakout,
+        }
+    }
+}
+
+impl Reset for OpeningRange {
+    fn reset(&mut self) {
+        self.new_session();
+    }
+}
+
+impl Default for OpeningRange {
+    fn default() -> Self {
+        Self::new(30).unwrap()
+    }
+}
+
+impl fmt::Display for OpeningRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ORB({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(OpeningRange::new(0).is_err());
+        assert!(OpeningRange::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut orb = OpeningRange::new(3).unwrap();
+
+        let out = orb.nexta(&Bar::new().high(10).low(9).close(9.5));
+        assert_eq!((out.high, out.low, out.complete), (10.0, 9.0, false));
+
+        // the range is still being formed, so a close outside of it is not a breakout
+        let out = orb.nexta(&Bar::new().high(12).low(9.5).close(11));
+        assert_eq!((out.high, out.low, out.complete), (12.0, 9.0, false));
+        assert_eq!(out.breakout, None);
+
+        let out = orb.nexta(&Bar::new().high(11).low(8).close(10));
+        assert_eq!((out.high, out.low, out.complete), (12.0, 8.0, true));
+        assert_eq!(out.breakout, None);
+
+        let out = orb.nexta(&Bar::new().high(12.5).low(10).close(12.2));
+        assert_eq!((out.high, out.low), (12.0, 8.0));
+        assert_eq!(out.breakout, Some(Breakout::Up));
+
+        assert_eq!(
+            orb.nexta(&Bar::new().high(12).low(10).close(11)).breakout,
+            None
+        );
+        assert_eq!(
+            orb.nexta(&Bar::new().high(8.5).low(7).close(7.5)).breakout,
+            Some(Breakout::Down)
+        );
+    }
+
+    #[test]
+    fn test_new_session() {
+        let mut orb = OpeningRange::new(1).unwrap();
+
+        orb.nexta(&Bar::new().high(10).low(9).close(9.5));
+        assert!(orb.is_complete());
+
+        orb.new_session();
+        assert!(!orb.is_complete());
+
+        let out = orb.nexta(&Bar::new().high(20).low(19).close(19.5));
+        assert_eq!((out.high, out.low, out.complete), (20.0, 19.0, true));
+        assert_eq!(
+            orb.nexta(&Bar::new().high(19).low(18).close(18.5)).breakout,
+            Some(Breakout::Down)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut orb = OpeningRange::new(2).unwrap();
+
+        orb.nexta(&Bar::new().high(10).low(9).close(9.5));
+        orb.nexta(&Bar::new().high(10).low(9).close(9.5));
+
+        orb.reset();
+        let out = orb.nexta(&Bar::new().high(5).low(4).close(4.5));
+        assert_eq!((out.high, out.low, out.complete), (5.0, 4.0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        OpeningRange::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let orb = OpeningRange::new(6).unwrap();
+        assert_eq!(format!("{}", orb), "ORB(6)");
+    }
+}
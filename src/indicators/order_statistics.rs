@@ -0,0 +1,291 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::errors::{Result, TaError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Order statistics of the last _period_ values.
+///
+/// The window is split between two heaps: `lower` holds the smallest values with the largest
+/// of them on top, `upper` holds the rest with the smallest on top. Values that leave the window
+/// are not searched for but deleted lazily once they reach the top of a heap, so inserting a
+/// value and reading a quantile are both O(log _period_) amortized.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct RollingOrderStatistics {
+    period: usize,
+    index: usize,
+    count: usize,
+    // sequence number of the next value, values with a lower number than
+    // `next_seq - count` have left the window
+    next_seq: u64,
+    deque: Box<[f64]>,
+    lower: BinaryHeap<Entry>,
+    upper: BinaryHeap<ReverseEntry>,
+    // number of values in the heaps that are still in the window
+    lower_len: usize,
+    upper_len: usize,
+}
+
+impl RollingOrderStatistics {
+    pub(crate) fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                next_seq: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+                lower: BinaryHeap::with_capacity(period),
+                upper: BinaryHeap::with_capacity(period),
+                lower_len: 0,
+                upper_len: 0,
+            }),
+        }
+    }
+
+    pub(crate) fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Adds a value to the window, dropping the oldest one once the window is full.
+    pub(crate) fn push(&mut self, value: f64) {
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let oldest = Entry {
+                value: self.deque[self.index],
+                seq: self.next_seq - self.period as u64,
+            };
+            if self.in_lower(&oldest) {
+                self.lower_len -= 1;
+            } else {
+                self.upper_len -= 1;
+            }
+        }
+
+        let entry = Entry {
+            value,
+            seq: self.next_seq,
+        };
+        self.next_seq += 1;
+        if self.in_lower(&entry) {
+            self.lower.push(entry);
+            self.lower_len += 1;
+        } else {
+            self.upper.push(ReverseEntry(entry));
+            self.upper_len += 1;
+        }
+
+        self.deque[self.index] = value;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        // keep the expired values buried in the heaps from piling up
+        if self.lower.len() + self.upper.len() > 2 * self.period {
+            self.compact();
+        }
+    }
+
+    /// Returns the _q_-th quantile of the window, linearly interpolated between the closest
+    /// ranks, or 0 if the window is empty.
+    pub(crate) fn quantile(&mut self, q: f64) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        let rank = q * (self.count - 1) as f64;
+        let below = rank.floor();
+        self.rebalance(below as usize + 1);
+
+        let value = self.lower_top().unwrap_or(0.0);
+        let fraction = rank - below;
+        match self.upper_top() {
+            Some(next) if fraction > 0.0 => value + fraction * (next - value),
+            _ => value,
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.next_seq = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+        self.lower.clear();
+        self.upper.clear();
+        self.lower_len = 0;
+        self.upper_len = 0;
+    }
+
+    // Every entry in `lower`, expired or not, is ordered before every entry in `upper`, so
+    // comparing with the top of `lower` tells which heap an entry belongs to.
+    fn in_lower(&self, entry: &Entry) -> bool {
+        self.lower.peek().is_some_and(|top| entry <= top)
+    }
+
+    fn is_expired(&self, entry: &Entry) -> bool {
+        entry.seq + (self.count as u64) < self.next_seq
+    }
+
+    fn prune(&mut self) {
+        while self.lower.peek().is_some_and(|top| self.is_expired(top)) {
+            self.lower.pop();
+        }
+        while self.upper.peek().is_some_and(|top| self.is_expired(&top.0)) {
+            self.upper.pop();
+        }
+    }
+
+    // Moves values between the heaps until `lower` holds `lower_len` values of the window.
+    fn rebalance(&mut self, lower_len: usize) {
+        self.prune();
+        while self.lower_len > lower_len {
+            if let Some(entry) = self.lower.pop() {
+                self.upper.push(ReverseEntry(entry));
+                self.lower_len -= 1;
+                self.upper_len += 1;
+            }
+            self.prune();
+        }
+        while self.lower_len < lower_len {
+            if let Some(ReverseEntry(entry)) = self.upper.pop() {
+                self.lower.push(entry);
+                self.upper_len -= 1;
+                self.lower_len += 1;
+            }
+            self.prune();
+        }
+    }
+
+    fn lower_top(&self) -> Option<f64> {
+        self.lower.peek().map(|entry| entry.value)
+    }
+
+    fn upper_top(&self) -> Option<f64> {
+        self.upper.peek().map(|entry| entry.0.value)
+    }
+
+    fn compact(&mut self) {
+        let start = self.next_seq - self.count as u64;
+        let lower = std::mem::take(&mut self.lower);
+        self.lower = lower.into_iter().filter(|e| e.seq >= start).collect();
+        let upper = std::mem::take(&mut self.upper);
+        self.upper = upper.into_iter().filter(|e| e.0.seq >= start).collect();
+    }
+}
+
+/// Value ordered first by value, then by position in the input, so that equal values can be
+/// told apart.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    value: f64,
+    seq: u64,
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value
+            .total_cmp(&other.value)
+            .then(self.seq.cmp(&other.seq))
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+/// Entry with the order reversed, turning `BinaryHeap` into a min-heap.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ReverseEntry(Entry);
+
+impl Ord for ReverseEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl PartialOrd for ReverseEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // naive reference: sort the window and interpolate
+    fn sorted_quantile(window: &[f64], q: f64) -> f64 {
+        let mut sorted = window.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let rank = q * (sorted.len() - 1) as f64;
+        let below = rank.floor() as usize;
+        let fraction = rank - below as f64;
+        if below + 1 < sorted.len() {
+            sorted[below] + fraction * (sorted[below + 1] - sorted[below])
+        } else {
+            sorted[below]
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RollingOrderStatistics::new(0).is_err());
+        assert!(RollingOrderStatistics::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut stats = RollingOrderStatistics::new(3).unwrap();
+        assert_eq!(stats.quantile(0.5), 0.0);
+    }
+
+    #[test]
+    fn test_matches_sorted_window() {
+        let inputs: Vec<f64> = (0..500u64)
+            .map(|i| ((i * 7919 + 13) % 101) as f64 / 4.0)
+            .collect();
+
+        for &period in &[1, 2, 5, 16] {
+            for &q in &[0.0, 0.1, 0.5, 0.75, 1.0] {
+                let mut stats = RollingOrderStatistics::new(period).unwrap();
+                for (i, &input) in inputs.iter().enumerate() {
+                    stats.push(input);
+                    let start = (i + 1).saturating_sub(period);
+                    let expected = sorted_quantile(&inputs[start..=i], q);
+                    assert_eq!(stats.quantile(q), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stats = RollingOrderStatistics::new(3).unwrap();
+
+        stats.push(5.0);
+        stats.push(1.0);
+        stats.reset();
+        stats.push(2.0);
+        assert_eq!(stats.quantile(0.5), 2.0);
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{SpreadKind, ZScore};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pair spread and ratio.
+///
+/// Tracks the relationship of the two legs of a pair trade: every input is a pair of prices
+/// _(A, B)_, and the output holds their spread, their ratio and the [z-score](struct.ZScore.html)
+/// of one of the two over the last _period_ bars. Unlike
+/// [MeanReversionBlock](struct.MeanReversionBlock.html) it does not derive a position from the
+/// z-score.
+///
+/// # Formula
+///
+/// * _Spread_ = A - B
+/// * _Ratio_ = A / B
+/// * _Z-Score_ = (S - SMA(S, _period_)) / SD(S, _period_)
+///
+/// Where _S_ is the spread or the ratio, selected by _kind_. When the standard deviation is 0
+/// the z-score is 0. B must not be 0.
+///
+/// # Parameters
+///
+/// * _period_ - z-score window (integer greater than 0). Default is 20.
+/// * _kind_ - series the z-score is computed of. Default is the spread (difference).
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{PairSpread, SpreadKind};
+/// use tars::Nexta;
+///
+/// let mut pair = PairSpread::new(3, SpreadKind::Difference).unwrap();
+///
+/// pair.nexta((10.0, 10.0));
+/// let out = pair.nexta((11.0, 10.0));
+/// assert_eq!(out.spread, 1.0);
+/// assert_eq!(out.ratio, 1.1);
+/// assert_eq!(out.zscore, 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Pairs trade, Wikipedia](https://en.wikipedia.org/wiki/Pairs_trade)
+///
+#[doc(alias = "Spread")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PairSpread {
+    kind: SpreadKind,
+    zscore: ZScore,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PairSpreadOutput {
+    pub spread: f64,
+    pub ratio: f64,
+    pub zscore: f64,
+}
+
+impl PairSpread {
+    pub fn new(period: usize, kind: SpreadKind) -> Result<Self> {
+        Ok(Self {
+            kind,
+            zscore: ZScore::new(period)?,
+        })
+    }
+
+    pub fn kind(&self) -> SpreadKind {
+        self.kind
+    }
+}
+
+impl Period for PairSpread {
+    fn period(&self) -> usize {
+        self.zscore.period()
+    }
+}
+
+impl Nexta<(f64, f64)> for PairSpread {
+    type Output = PairSpreadOutput;
+
+    fn nexta(&mut self, (a, b): (f64, f64)) -> Self::Output {
+        let spread = SpreadKind::Difference.spread(a, b);
+        let ratio = SpreadKind::Ratio.spread(a, b);
+        let zscore = match self.kind {
+            SpreadKind::Difference => self.zscore.nexta(spread),
+            SpreadKind::Ratio => self.zscore.nexta(ratio),
+        };
+
+        PairSpreadOutput {
+            spread,
+            ratio,
+            zscore,
+        }
+    }
+}
+
+impl Reset for PairSpread {
+    fn reset(&mut self) {
+        self.zscore.reset();
+    }
+}
+
+impl Default for PairSpread {
+    fn default() -> Self {
+        Self::new(20, SpreadKind::Difference).unwrap()
+    }
+}
+
+impl fmt::Display for PairSpread {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self.kind {
+            SpreadKind::Difference => "DIFF",
+            SpreadKind::Ratio => "RATIO",
+        };
+        write!(f, "PAIR({}, {})", self.zscore.period(), kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(PairSpread::new(0, SpreadKind::Difference).is_err());
+        assert!(PairSpread::new(1, SpreadKind::Ratio).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pair = PairSpread::new(3, SpreadKind::Difference).unwrap();
+
+        let out = pair.nexta((10.0, 10.0));
+        assert_eq!((out.spread, out.ratio, out.zscore), (0.0, 1.0, 0.0));
+        let out = pair.nexta((11.0, 10.0));
+        assert_eq!((out.spread, out.ratio, out.zscore), (1.0, 1.1, 1.0));
+        let out = pair.nexta((12.0, 11.0));
+        assert_eq!(
+            (out.spread, round(out.ratio), round(out.zscore)),
+            (1.0, 1.091, 0.707)
+        );
+    }
+
+    #[test]
+    fn test_next_ratio() {
+        let mut pair = PairSpread::new(3, SpreadKind::Ratio).unwrap();
+
+        assert_eq!(pair.nexta((10.0, 10.0)).zscore, 0.0);
+        assert_eq!(round(pair.nexta((11.0, 10.0)).zscore), 1.0);
+        let out = pair.nexta((12.0, 10.0));
+        assert_eq!((out.spread, round(out.zscore)), (2.0, 1.225));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pair = PairSpread::new(3, SpreadKind::Difference).unwrap();
+
+        pair.nexta((10.0, 10.0));
+        pair.nexta((11.0, 10.0));
+        pair.reset();
+        assert_eq!(pair.nexta((12.0, 10.0)).zscore, 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        PairSpread::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let pair = PairSpread::new(60, SpreadKind::Ratio).unwrap();
+        assert_eq!(format!("{}", pair), "PAIR(60, RATIO)");
+    }
+}
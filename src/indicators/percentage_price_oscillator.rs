@@ -28,6 +28,9 @@ use serde::{Deserialize, Serialize};
 /// * _slow_period_ - period for the slow EMA. Default is 26.
 /// * _signal_period_ - period for the signal EMA. Default is 9.
 ///
+/// Equal fast and slow periods are accepted: both EMAs are then identical and the PPO,
+/// signal and histogram are 0 on every bar.
+///
 /// # Example
 ///
 /// ```
@@ -170,6 +173,18 @@ mod tests {
         assert_eq!(round(ppo.nexta(6.5).into()), (17.84, 19.08, -1.24));
     }
 
+    #[test]
+    fn test_equal_periods() {
+        let mut ind = Ppo::new(3, 3, 2).unwrap();
+
+        for &x in &[2.0, 3.0, 4.2, 7.0, 6.7] {
+            let out = ind.nexta(x);
+            assert_eq!(out.ppo, 0.0);
+            assert_eq!(out.signal, 0.0);
+            assert_eq!(out.histogram, 0.0);
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut ppo = Ppo::new(3, 6, 4).unwrap();
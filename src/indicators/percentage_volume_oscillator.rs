@@ -0,0 +1,223 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Nexta, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Percentage Volume Oscillator (PVO).
+///
+/// The [PPO](struct.PercentagePriceOscillator.html) applied to volume: the difference between a
+/// fast and a slow EMA of the volume as a percentage of the slow EMA. A positive PVO means the
+/// volume is above its longer-term average. The signal line is an EMA of the PVO and the
+/// histogram the difference between the two.
+///
+/// # Formula
+///
+/// * _PVO_ = (EMA(_fast_period_) - EMA(_slow_period_)) / EMA(_slow_period_) × 100
+/// * _Signal_ = EMA(_signal_period_) of PVO
+/// * _Histogram_ = PVO - Signal
+///
+/// The PVO is 0 while the slow EMA is 0. For `f64` input the value is the volume.
+///
+/// # Parameters
+///
+/// * _fast_period_ - period for the fast EMA. Default is 12.
+/// * _slow_period_ - period for the slow EMA. Default is 26.
+/// * _signal_period_ - period for the signal EMA. Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::PercentageVolumeOscillator as Pvo;
+/// use tars::Nexta;
+///
+/// let mut pvo = Pvo::new(3, 6, 4).unwrap();
+///
+/// assert_eq!(round(pvo.nexta(200.0).into()), (0.0, 0.0, 0.0));
+/// assert_eq!(round(pvo.nexta(300.0).into()), (9.38, 3.75, 5.63));
+/// assert_eq!(round(pvo.nexta(420.0).into()), (18.26, 9.56, 8.71));
+///
+/// fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+///     let n0 = (nums.0 * 100.0).round() / 100.0;
+///     let n1 = (nums.1 * 100.0).round() / 100.0;
+///     let n2 = (nums.2 * 100.0).round() / 100.0;
+///     (n0, n1, n2)
+/// }
+/// ```
+///
+/// # Links
+///
+/// * [Percentage Volume Oscillator (PVO), StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:percentage_volume_oscillator_pvo)
+///
+#[doc(alias = "PVO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PercentageVolumeOscillator {
+    fast_ema: Ema,
+    slow_ema: Ema,
+    signal_ema: Ema,
+}
+
+impl PercentageVolumeOscillator {
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        Ok(Self {
+            fast_ema: Ema::new(fast_period)?,
+            slow_ema: Ema::new(slow_period)?,
+            signal_ema: Ema::new(signal_period)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentageVolumeOscillatorOutput {
+    pub pvo: f64,
+    pub signal: f64,
+    pub histogram: f64,
+}
+
+impl From<PercentageVolumeOscillatorOutput> for (f64, f64, f64) {
+    fn from(po: PercentageVolumeOscillatorOutput) -> Self {
+        (po.pvo, po.signal, po.histogram)
+    }
+}
+
+impl Period for PercentageVolumeOscillator {
+    fn period(&self) -> usize {
+        self.slow_ema.period()
+    }
+}
+
+impl Nexta<f64> for PercentageVolumeOscillator {
+    type Output = PercentageVolumeOscillatorOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let fast_val = self.fast_ema.nexta(input);
+        let slow_val = self.slow_ema.nexta(input);
+
+        let pvo = if slow_val != 0.0 {
+            (fast_val - slow_val) / slow_val * 100.0
+        } else {
+            0.0
+        };
+        let signal = self.signal_ema.nexta(pvo);
+        let histogram = pvo - signal;
+
+        PercentageVolumeOscillatorOutput {
+            pvo,
+            signal,
+            histogram,
+        }
+    }
+}
+
+impl<T: Volume> Nexta<&T> for PercentageVolumeOscillator {
+    type Output = PercentageVolumeOscillatorOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.volume())
+    }
+}
+
+impl Reset for PercentageVolumeOscillator {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.signal_ema.reset();
+    }
+}
+
+impl Default for PercentageVolumeOscillator {
+    fn default() -> Self {
+        Self::new(12, 26, 9).unwrap()
+    }
+}
+
+impl fmt::Display for PercentageVolumeOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PVO({}, {}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.signal_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    type Pvo = PercentageVolumeOscillator;
+
+    test_indicator!(Pvo);
+
+    fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        let n0 = (nums.0 * 100.0).round() / 100.0;
+        let n1 = (nums.1 * 100.0).round() / 100.0;
+        let n2 = (nums.2 * 100.0).round() / 100.0;
+        (n0, n1, n2)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Pvo::new(0, 1, 1).is_err());
+        assert!(Pvo::new(1, 0, 1).is_err());
+        assert!(Pvo::new(1, 1, 0).is_err());
+        assert!(Pvo::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        assert_eq!(round(pvo.nexta(200.0).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(pvo.nexta(300.0).into()), (9.38, 3.75, 5.63));
+        assert_eq!(round(pvo.nexta(420.0).into()), (18.26, 9.56, 8.71));
+        assert_eq!(round(pvo.nexta(700.0).into()), (28.62, 17.18, 11.44));
+        assert_eq!(round(pvo.nexta(670.0).into()), (24.01, 19.91, 4.09));
+        assert_eq!(round(pvo.nexta(650.0).into()), (17.84, 19.08, -1.24));
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        pvo.nexta(&Bar::new().volume(200.0));
+        let out = pvo.nexta(&Bar::new().volume(300.0));
+        assert_eq!(round(out.into()), (9.38, 3.75, 5.63));
+    }
+
+    #[test]
+    fn test_zero_volume() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        assert_eq!(round(pvo.nexta(0.0).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(pvo.nexta(0.0).into()), (0.0, 0.0, 0.0));
+        assert!(pvo.nexta(100.0).pvo > 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        pvo.nexta(200.0);
+        pvo.nexta(300.0);
+        pvo.reset();
+        assert_eq!(round(pvo.nexta(200.0).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(pvo.nexta(300.0).into()), (9.38, 3.75, 5.63));
+    }
+
+    #[test]
+    fn test_default() {
+        Pvo::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let pvo = Pvo::new(13, 30, 10).unwrap();
+        assert_eq!(format!("{}", pvo), "PVO(13, 30, 10)");
+    }
+}
@@ -0,0 +1,275 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pivot high / pivot low (swing point) detector.
+///
+/// A pivot high is a bar whose high is higher than the highs of the _left_ bars before it and
+/// of the _right_ bars after it; a pivot low is a bar whose low is lower than the lows of those
+/// bars. A pivot is confirmed _right_ bars after it happened, so `nexta` returns the
+/// [Pivot](struct.Pivot.html) on the confirming bar, and `None` otherwise. With _left_ and
+/// _right_ equal this is the same pattern as [WilliamsFractals](struct.WilliamsFractals.html).
+///
+/// Pivots are the raw material of structure-based analysis such as breaks of structure or
+/// support and resistance levels, e.g. with a [LevelBook](struct.LevelBook.html).
+///
+/// For `f64` input both the highs and the lows use the given value.
+///
+/// # Parameters
+///
+/// * _left_ - number of bars before the pivot (integer greater than 0). Default is 5.
+/// * _right_ - number of bars after the pivot (integer greater than 0). Default is 5.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::PivotDetector;
+/// use tars::Nexta;
+///
+/// let mut pivots = PivotDetector::new(2, 1).unwrap();
+///
+/// assert_eq!(pivots.nexta(10.0), None);
+/// assert_eq!(pivots.nexta(11.0), None);
+/// assert_eq!(pivots.nexta(13.0), None);
+///
+/// let pivot = pivots.nexta(12.0).unwrap();
+/// assert_eq!(pivot.high, Some(13.0));
+/// assert_eq!(pivot.low, None);
+/// assert_eq!(pivot.offset, 1);
+/// ```
+///
+/// # Links
+///
+/// * [ta.pivothigh, TradingView](https://www.tradingview.com/pine-script-reference/v5/#fun_ta.pivothigh)
+///
+#[doc(alias = "Swing High")]
+#[doc(alias = "Swing Low")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PivotDetector {
+    left: usize,
+    right: usize,
+    index: usize,
+    count: usize,
+    highs: Box<[f64]>,
+    lows: Box<[f64]>,
+}
+
+/// Confirmed pivot. At least one of `high` and `low` is set; a bar with a wide range can be
+/// both.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pivot {
+    /// High of the pivot high bar.
+    pub high: Option<f64>,
+    /// Low of the pivot low bar.
+    pub low: Option<f64>,
+    /// Number of bars between the pivot and the bar that confirmed it, i.e. _right_.
+    pub offset: usize,
+}
+
+impl PivotDetector {
+    pub fn new(left: usize, right: usize) -> Result<Self> {
+        if left == 0 || right == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let len = left + right + 1;
+        Ok(Self {
+            left,
+            right,
+            index: 0,
+            count: 0,
+            highs: vec![0.0; len].into_boxed_slice(),
+            lows: vec![0.0; len].into_boxed_slice(),
+        })
+    }
+
+    pub fn left(&self) -> usize {
+        self.left
+    }
+
+    pub fn right(&self) -> usize {
+        self.right
+    }
+
+    fn calculate(&mut self, high: f64, low: f64) -> Option<Pivot> {
+        let len = self.highs.len();
+
+        self.highs[self.index] = high;
+        self.lows[self.index] = low;
+        self.index = if self.index + 1 < len {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < len {
+            self.count += 1;
+            if self.count < len {
+                return None;
+            }
+        }
+
+        // the next slot holds the oldest bar, the candidate is _left_ bars after it
+        let candidate = (self.index + self.left) % len;
+        let others = (0..len).filter(|&i| i != candidate);
+
+        let mut is_high = true;
+        let mut is_low = true;
+        for i in others {
+            is_high &= self.highs[i] < self.highs[candidate];
+            is_low &= self.lows[i] > self.lows[candidate];
+        }
+
+        if !is_high && !is_low {
+            return None;
+        }
+
+        Some(Pivot {
+            high: if is_high {
+                Some(self.highs[candidate])
+            } else {
+                None
+            },
+            low: if is_low {
+                Some(self.lows[candidate])
+            } else {
+                None
+            },
+            offset: self.right,
+        })
+    }
+}
+
+impl Period for PivotDetector {
+    fn period(&self) -> usize {
+        self.highs.len()
+    }
+}
+
+impl Nexta<f64> for PivotDetector {
+    type Output = Option<Pivot>;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        self.calculate(input, input)
+    }
+}
+
+impl<T: High + Low> Nexta<&T> for PivotDetector {
+    type Output = Option<Pivot>;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.calculate(input.high(), input.low())
+    }
+}
+
+impl Reset for PivotDetector {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.highs.len() {
+            self.highs[i] = 0.0;
+            self.lows[i] = 0.0;
+        }
+    }
+}
+
+impl Default for PivotDetector {
+    fn default() -> Self {
+        Self::new(5, 5).unwrap()
+    }
+}
+
+impl fmt::Display for PivotDetector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PIVOTS({}, {})", self.left, self.right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(PivotDetector);
+
+    fn bar(high: f64, low: f64) -> Bar {
+        Bar::new().high(high).low(low)
+    }
+
+    fn pivot(high: Option<f64>, low: Option<f64>, offset: usize) -> Option<Pivot> {
+        Some(Pivot { high, low, offset })
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(PivotDetector::new(0, 1).is_err());
+        assert!(PivotDetector::new(1, 0).is_err());
+        assert!(PivotDetector::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next_asymmetric() {
+        let mut pivots = PivotDetector::new(1, 3).unwrap();
+
+        assert_eq!(pivots.nexta(&bar(10.0, 9.0)), None);
+        assert_eq!(pivots.nexta(&bar(12.0, 10.0)), None);
+        assert_eq!(pivots.nexta(&bar(11.0, 8.0)), None);
+        assert_eq!(pivots.nexta(&bar(11.5, 9.0)), None);
+        // 12.0 is higher than one bar before and three bars after it
+        assert_eq!(pivots.nexta(&bar(11.0, 9.5)), pivot(Some(12.0), None, 3));
+        // 8.0 is lower than one bar before and three bars after it
+        assert_eq!(pivots.nexta(&bar(10.5, 9.0)), pivot(None, Some(8.0), 3));
+        assert_eq!(pivots.nexta(&bar(10.8, 9.2)), pivot(Some(11.5), None, 3));
+        assert_eq!(pivots.nexta(&bar(10.0, 9.1)), None);
+    }
+
+    #[test]
+    fn test_equal_highs() {
+        let mut pivots = PivotDetector::new(1, 1).unwrap();
+
+        pivots.nexta(&bar(10.0, 9.0));
+        pivots.nexta(&bar(12.0, 9.5));
+        assert_eq!(pivots.nexta(&bar(12.0, 10.0)), None);
+        assert_eq!(pivots.nexta(&bar(11.0, 10.5)), None);
+    }
+
+    #[test]
+    fn test_wide_bar() {
+        let mut pivots = PivotDetector::new(1, 2).unwrap();
+
+        pivots.nexta(&bar(10.0, 9.0));
+        pivots.nexta(&bar(12.0, 7.0));
+        pivots.nexta(&bar(11.0, 8.0));
+        assert_eq!(
+            pivots.nexta(&bar(10.0, 9.0)),
+            pivot(Some(12.0), Some(7.0), 2)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pivots = PivotDetector::new(1, 1).unwrap();
+
+        pivots.nexta(10.0);
+        pivots.nexta(12.0);
+        pivots.reset();
+        assert_eq!(pivots.nexta(11.0), None);
+        assert_eq!(pivots.nexta(13.0), None);
+        assert_eq!(pivots.nexta(12.0), pivot(Some(13.0), None, 1));
+    }
+
+    #[test]
+    fn test_default() {
+        PivotDetector::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let pivots = PivotDetector::new(3, 2).unwrap();
+        assert_eq!(format!("{}", pivots), "PIVOTS(3, 2)");
+    }
+}
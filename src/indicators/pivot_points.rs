@@ -0,0 +1,308 @@
+use std::fmt;
+
+use crate::{Close, High, Low, Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pivot Points (PP).
+///
+/// Support and resistance levels of the current session derived from the high, low and close of
+/// the previous session.
+///
+/// Every bar passed to `nexta` is accumulated into the current session. Calling
+/// [new_session](struct.PivotPoints.html#method.new_session) closes the current session and
+/// calculates the levels that are returned for the bars of the next session. Until the first
+/// session is closed there are no levels and `nexta` returns `None`.
+///
+/// # Formula
+///
+/// PP = (High + Low + Close) / 3, Range = High - Low
+///
+/// [Classic](enum.PivotKind.html#variant.Classic):
+///
+/// * R1 = 2 * PP - Low, S1 = 2 * PP - High
+/// * R2 = PP + Range, S2 = PP - Range
+/// * R3 = High + 2 * (PP - Low), S3 = Low - 2 * (High - PP)
+///
+/// [Fibonacci](enum.PivotKind.html#variant.Fibonacci):
+///
+/// * R1 = PP + 0.382 * Range, S1 = PP - 0.382 * Range
+/// * R2 = PP + 0.618 * Range, S2 = PP - 0.618 * Range
+/// * R3 = PP + Range, S3 = PP - Range
+///
+/// [Camarilla](enum.PivotKind.html#variant.Camarilla):
+///
+/// * R1 = Close + Range * 1.1 / 12, S1 = Close - Range * 1.1 / 12
+/// * R2 = Close + Range * 1.1 / 6, S2 = Close - Range * 1.1 / 6
+/// * R3 = Close + Range * 1.1 / 4, S3 = Close - Range * 1.1 / 4
+///
+/// # Parameters
+///
+/// * _kind_ - formula used for the levels. Default is [Classic](enum.PivotKind.html#variant.Classic).
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{PivotKind, PivotPoints};
+/// use tars::{Nexta, DataItema};
+///
+/// let mut pivots = PivotPoints::new(PivotKind::Classic);
+///
+/// let di = DataItema::builder()
+///             .high(110.0)
+///             .low(90.0)
+///             .close(103.0)
+///             .open(95.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// assert_eq!(pivots.nexta(&di), None);
+///
+/// pivots.new_session();
+/// let levels = pivots.nexta(&di).unwrap();
+/// assert_eq!(levels.pp, 101.0);
+/// assert_eq!(levels.r1, 112.0);
+/// assert_eq!(levels.s1, 92.0);
+/// ```
+///
+/// # Links
+///
+/// * [Pivot point, Wikipedia](https://en.wikipedia.org/wiki/Pivot_point_(technical_analysis))
+/// * [Pivot Points Standard, TradingView](https://www.tradingview.com/support/solutions/43000521824-pivot-points-standard/)
+///
+#[doc(alias = "PP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PivotPoints {
+    kind: PivotKind,
+    session: Option<(f64, f64, f64)>,
+    levels: Option<PivotLevels>,
+}
+
+/// Formula used by [PivotPoints](struct.PivotPoints.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PivotKind {
+    Classic,
+    Fibonacci,
+    Camarilla,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotLevels {
+    pub pp: f64,
+    pub r1: f64,
+    pub r2: f64,
+    pub r3: f64,
+    pub s1: f64,
+    pub s2: f64,
+    pub s3: f64,
+}
+
+impl PivotLevels {
+    /// Calculates the levels from the high, low and close of a session.
+    pub fn calculate(kind: PivotKind, high: f64, low: f64, close: f64) -> Self {
+        let pp = (high + low + close) / 3.0;
+        let range = high - low;
+
+        match kind {
+            PivotKind::Classic => Self {
+                pp,
+                r1: 2.0 * pp - low,
+                r2: pp + range,
+                r3: high + 2.0 * (pp - low),
+                s1: 2.0 * pp - high,
+                s2: pp - range,
+                s3: low - 2.0 * (high - pp),
+            },
+            PivotKind::Fibonacci => Self {
+                pp,
+                r1: pp + 0.382 * range,
+                r2: pp + 0.618 * range,
+                r3: pp + range,
+                s1: pp - 0.382 * range,
+                s2: pp - 0.618 * range,
+                s3: pp - range,
+            },
+            PivotKind::Camarilla => {
+                let step = range * 1.1;
+                Self {
+                    pp,
+                    r1: close + step / 12.0,
+                    r2: close + step / 6.0,
+                    r3: close + step / 4.0,
+                    s1: close - step / 12.0,
+                    s2: close - step / 6.0,
+                    s3: close - step / 4.0,
+                }
+            }
+        }
+    }
+}
+
+impl PivotPoints {
+    pub fn new(kind: PivotKind) -> Self {
+        Self {
+            kind,
+            session: None,
+            levels: None,
+        }
+    }
+
+    pub fn kind(&self) -> PivotKind {
+        self.kind
+    }
+
+    /// Returns the levels of the current session, calculated from the previous one.
+    pub fn levels(&self) -> Option<&PivotLevels> {
+        self.levels.as_ref()
+    }
+
+    /// Closes the current session and calculates the levels for the next one.
+    ///
+    /// If no bars were received since the last roll over, the current levels are kept.
+    pub fn new_session(&mut self) -> Option<&PivotLevels> {
+        if let Some((high, low, close)) = self.session.take() {
+            self.levels = Some(PivotLevels::calculate(self.kind, high, low, close));
+        }
+        self.levels.as_ref()
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for PivotPoints {
+    type Output = Option<PivotLevels>;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let (high, low) = match self.session {
+            Some((high, low, _)) => (high.max(input.high()), low.min(input.low())),
+            None => (input.high(), input.low()),
+        };
+        self.session = Some((high, low, input.close()));
+
+        self.levels.clone()
+    }
+}
+
+impl Reset for PivotPoints {
+    fn reset(&mut self) {
+        self.session = None;
+        self.levels = None;
+    }
+}
+
+impl Default for PivotPoints {
+    fn default() -> Self {
+        Self::new(PivotKind::Classic)
+    }
+}
+
+impl fmt::Display for PivotPoints {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self.kind {
+            PivotKind::Classic => "CLASSIC",
+            PivotKind::Fibonacci => "FIBONACCI",
+            PivotKind::Camarilla => "CAMARILLA",
+        };
+        write!(f, "PP({})", kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round_levels(levels: &PivotLevels) -> [f64; 7] {
+        [
+            round(levels.pp),
+            round(levels.r1),
+            round(levels.r2),
+            round(levels.r3),
+            round(levels.s1),
+            round(levels.s2),
+            round(levels.s3),
+        ]
+    }
+
+    #[test]
+    fn test_calculate() {
+        let classic = PivotLevels::calculate(PivotKind::Classic, 110.0, 90.0, 105.0);
+        assert_eq!(
+            round_levels(&classic),
+            [101.667, 113.333, 121.667, 133.333, 93.333, 81.667, 73.333]
+        );
+
+        let fibonacci = PivotLevels::calculate(PivotKind::Fibonacci, 110.0, 90.0, 105.0);
+        assert_eq!(
+            round_levels(&fibonacci),
+            [101.667, 109.307, 114.027, 121.667, 94.027, 89.307, 81.667]
+        );
+
+        let camarilla = PivotLevels::calculate(PivotKind::Camarilla, 110.0, 90.0, 105.0);
+        assert_eq!(
+            round_levels(&camarilla),
+            [101.667, 106.833, 108.667, 110.5, 103.167, 101.333, 99.5]
+        );
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut pivots = PivotPoints::new(PivotKind::Classic);
+
+        let bar1 = Bar::new().high(100).low(90).close(95);
+        let bar2 = Bar::new().high(110).low(95).close(100);
+        let bar3 = Bar::new().high(104).low(98).close(105);
+
+        assert_eq!(pivots.nexta(&bar1), None);
+        assert_eq!(pivots.nexta(&bar2), None);
+        assert_eq!(pivots.nexta(&bar3), None);
+
+        // session high = 110, low = 90, close = 105
+        let expected = PivotLevels::calculate(PivotKind::Classic, 110.0, 90.0, 105.0);
+        assert_eq!(pivots.new_session(), Some(&expected));
+        assert_eq!(pivots.nexta(&bar1), Some(expected.clone()));
+        assert_eq!(pivots.nexta(&bar2), Some(expected));
+
+        // session high = 110, low = 90, close = 100
+        pivots.new_session();
+        assert_eq!(round(pivots.levels().unwrap().pp), 100.0);
+    }
+
+    #[test]
+    fn test_new_session_without_bars() {
+        let mut pivots = PivotPoints::new(PivotKind::Camarilla);
+        assert_eq!(pivots.new_session(), None);
+
+        pivots.nexta(&Bar::new().high(110).low(90).close(105));
+        let levels = pivots.new_session().cloned();
+        assert!(levels.is_some());
+
+        // an empty session keeps the previous levels
+        assert_eq!(pivots.new_session().cloned(), levels);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pivots = PivotPoints::default();
+
+        pivots.nexta(&Bar::new().high(110).low(90).close(105));
+        pivots.new_session();
+        assert!(pivots.levels().is_some());
+
+        pivots.reset();
+        assert_eq!(pivots.levels(), None);
+        assert_eq!(pivots.new_session(), None);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(PivotPoints::default().kind(), PivotKind::Classic);
+    }
+
+    #[test]
+    fn test_display() {
+        let pivots = PivotPoints::new(PivotKind::Fibonacci);
+        assert_eq!(format!("{}", pivots), "PP(FIBONACCI)");
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{AverageTrueRange, SimpleMovingAverage};
+use crate::{Close, High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pretty Good Oscillator (PGO).
+///
+/// Developed by Mark Johnson, the oscillator measures the distance of the current close from its
+/// simple moving average, expressed in units of average true range. Values above 3.0 are
+/// traditionally read as the start of a long-term uptrend and values below -3.0 as the start of a
+/// long-term downtrend.
+///
+/// # Formula
+///
+/// PGO = (Close - SMA(_period_) of Close) / ATR(_period_)
+///
+/// Where:
+///
+/// * _SMA_ - [simple moving average](struct.SimpleMovingAverage.html)
+/// * _ATR_ - [average true range](struct.AverageTrueRange.html), EMA of the true range
+///
+/// When ATR is 0 (e.g. on the first input) the oscillator returns 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::PrettyGoodOscillator;
+/// use tars::Nexta;
+///
+/// let mut pgo = PrettyGoodOscillator::new(3).unwrap();
+/// assert_eq!(pgo.nexta(10.0), 0.0);
+/// assert_eq!(pgo.nexta(11.0), 1.0);
+/// assert_eq!((pgo.nexta(12.0) * 100.0).round() / 100.0, 1.33);
+/// ```
+///
+/// # Links
+///
+/// * [Pretty Good Oscillator, TradingView](https://www.tradingview.com/script/lq6sqJvB-Pretty-Good-Oscillator/)
+///
+#[doc(alias = "PGO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PrettyGoodOscillator {
+    sma: SimpleMovingAverage,
+    atr: AverageTrueRange,
+}
+
+impl PrettyGoodOscillator {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: SimpleMovingAverage::new(period)?,
+            atr: AverageTrueRange::new(period)?,
+        })
+    }
+
+    fn calculate(close: f64, sma: f64, atr: f64) -> f64 {
+        if atr == 0.0 {
+            0.0
+        } else {
+            (close - sma) / atr
+        }
+    }
+}
+
+impl Period for PrettyGoodOscillator {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Nexta<f64> for PrettyGoodOscillator {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let sma = self.sma.nexta(input);
+        let atr = self.atr.nexta(input);
+        Self::calculate(input, sma, atr)
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for PrettyGoodOscillator {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let sma = self.sma.nexta(input.close());
+        let atr = self.atr.nexta(input);
+        Self::calculate(input.close(), sma, atr)
+    }
+}
+
+impl Reset for PrettyGoodOscillator {
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.atr.reset();
+    }
+}
+
+impl Default for PrettyGoodOscillator {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for PrettyGoodOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PGO({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(PrettyGoodOscillator);
+
+    #[test]
+    fn test_new() {
+        assert!(PrettyGoodOscillator::new(0).is_err());
+        assert!(PrettyGoodOscillator::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pgo = PrettyGoodOscillator::new(3).unwrap();
+
+        assert_eq!(pgo.nexta(10.0), 0.0);
+        assert_eq!(pgo.nexta(11.0), 1.0);
+        assert_eq!(round(pgo.nexta(12.0)), 1.333);
+        assert_eq!(round(pgo.nexta(10.0)), -0.727);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut pgo = PrettyGoodOscillator::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).close(9);
+        let bar2 = Bar::new().high(12).low(9).close(11);
+        let bar3 = Bar::new().high(13).low(11).close(13);
+
+        // sma = 9, atr = 2
+        assert_eq!(pgo.nexta(&bar1), 0.0);
+        // sma = 10, atr = 0.5 * 3 + 0.5 * 2 = 2.5
+        assert_eq!(pgo.nexta(&bar2), 0.4);
+        // sma = 11, atr = 0.5 * 2 + 0.5 * 2.5 = 2.25
+        assert_eq!(round(pgo.nexta(&bar3)), 0.889);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pgo = PrettyGoodOscillator::new(3).unwrap();
+
+        pgo.nexta(10.0);
+        pgo.nexta(11.0);
+
+        pgo.reset();
+        assert_eq!(pgo.nexta(10.0), 0.0);
+        assert_eq!(pgo.nexta(11.0), 1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        PrettyGoodOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = PrettyGoodOscillator::new(7).unwrap();
+        assert_eq!(format!("{}", indicator), "PGO(7)");
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt;
+
+use crate::{Close, High, Low, Nexta, Open, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Prior period open, high, low and close.
+///
+/// Streams the open, high, low and close of the previous period, e.g. yesterday's levels on
+/// intraday bars or the prior week's levels on daily bars, which many intraday systems use as
+/// reference levels.
+///
+/// Bars carry no timestamps, so the timeframe is given by the caller: calling
+/// [new_session](struct.PriorPeriodLevels.html#method.new_session) before the first bar of a
+/// day, week or month closes the current period, whose levels are then reported on every bar of
+/// the next one. Until the first period is closed `nexta` returns `None`. Calls without bars
+/// in between do not change the levels.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::PriorPeriodLevels;
+/// use tars::{DataItema, Nexta};
+///
+/// fn bar(open: f64, high: f64, low: f64, close: f64) -> DataItema {
+///     DataItema::builder()
+///         .open(open)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// }
+///
+/// let mut prior = PriorPeriodLevels::new();
+///
+/// assert!(prior.nexta(&bar(10.0, 12.0, 9.0, 11.0)).is_none());
+/// prior.nexta(&bar(11.0, 13.0, 10.0, 12.0));
+///
+/// prior.new_session();
+/// let levels = prior.nexta(&bar(12.0, 12.5, 11.5, 12.0)).unwrap();
+/// assert_eq!(levels.open, 10.0);
+/// assert_eq!((levels.high, levels.low), (13.0, 9.0));
+/// assert_eq!(levels.close, 12.0);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PriorPeriodLevels {
+    current: Option<PriorPeriodLevelsOutput>,
+    prior: Option<PriorPeriodLevelsOutput>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriorPeriodLevelsOutput {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl PriorPeriodLevels {
+    pub fn new() -> Self {
+        Self {
+            current: None,
+            prior: None,
+        }
+    }
+
+    /// Closes the current period, its levels are reported from the next bar on.
+    pub fn new_session(&mut self) {
+        if let Some(current) = self.current.take() {
+            self.prior = Some(current);
+        }
+    }
+
+    /// Returns the levels of the prior period or `None` if no period has been closed yet.
+    pub fn levels(&self) -> Option<PriorPeriodLevelsOutput> {
+        self.prior.clone()
+    }
+}
+
+impl<T: Open + High + Low + Close> Nexta<&T> for PriorPeriodLevels {
+    type Output = Option<PriorPeriodLevelsOutput>;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        match &mut self.current {
+            Some(current) => {
+                current.high = current.high.max(input.high());
+                current.low = current.low.min(input.low());
+                current.close = input.close();
+            }
+            None => {
+                self.current = Some(PriorPeriodLevelsOutput {
+                    open: input.open(),
+                    high: input.high(),
+                    low: input.low(),
+                    close: input.close(),
+                });
+            }
+        }
+
+        self.levels()
+    }
+}
+
+impl Reset for PriorPeriodLevels {
+    fn reset(&mut self) {
+        self.current = None;
+        self.prior = None;
+    }
+}
+
+impl Default for PriorPeriodLevels {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for PriorPeriodLevels {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PRIORLEVELS")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().open(open).high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_next() {
+        let mut prior = PriorPeriodLevels::new();
+
+        assert_eq!(prior.nexta(&bar(10.0, 12.0, 9.0, 11.0)), None);
+        assert_eq!(prior.nexta(&bar(11.0, 13.0, 10.0, 12.0)), None);
+
+        prior.new_session();
+        let first = PriorPeriodLevelsOutput {
+            open: 10.0,
+            high: 13.0,
+            low: 9.0,
+            close: 12.0,
+        };
+        assert_eq!(
+            prior.nexta(&bar(12.0, 15.0, 8.0, 14.0)),
+            Some(first.clone())
+        );
+        assert_eq!(prior.nexta(&bar(14.0, 14.5, 13.0, 13.5)), Some(first));
+
+        prior.new_session();
+        let second = PriorPeriodLevelsOutput {
+            open: 12.0,
+            high: 15.0,
+            low: 8.0,
+            close: 13.5,
+        };
+        assert_eq!(prior.nexta(&bar(13.0, 14.0, 12.0, 13.0)), Some(second));
+    }
+
+    #[test]
+    fn test_new_session_without_bars() {
+        let mut prior = PriorPeriodLevels::new();
+
+        prior.new_session();
+        assert_eq!(prior.levels(), None);
+
+        prior.nexta(&bar(10.0, 12.0, 9.0, 11.0));
+        prior.new_session();
+        prior.new_session();
+        assert_eq!(prior.levels().map(|l| l.close), Some(11.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut prior = PriorPeriodLevels::new();
+
+        prior.nexta(&bar(10.0, 12.0, 9.0, 11.0));
+        prior.new_session();
+        prior.reset();
+        assert_eq!(prior.nexta(&bar(10.0, 12.0, 9.0, 11.0)), None);
+    }
+
+    #[test]
+    fn test_default() {
+        PriorPeriodLevels::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let prior = PriorPeriodLevels::new();
+        assert_eq!(format!("{}", prior), "PRIORLEVELS");
+    }
+}
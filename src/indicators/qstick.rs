@@ -0,0 +1,153 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{MovingAverage, MovingAverageKind};
+use crate::{Close, Nexta, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Qstick.
+///
+/// Developed by Tushar Chande, the indicator is a moving average of the candle bodies. Positive
+/// values indicate that closes tend to be above opens (buying pressure), negative values that
+/// they tend to be below opens (selling pressure).
+///
+/// # Formula
+///
+/// Qstick = MA(_period_) of (Close - Open)
+///
+/// # Parameters
+///
+/// * _kind_ - kind of the moving average. Default is simple.
+/// * _period_ - number of periods (integer greater than 0). Default is 8.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{MovingAverageKind, Qstick};
+/// use tars::{Nexta, DataItema};
+///
+/// fn bar(open: f64, close: f64) -> DataItema {
+///     DataItema::builder()
+///         .open(open)
+///         .high(open.max(close))
+///         .low(open.min(close))
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// }
+///
+/// let mut qstick = Qstick::new(MovingAverageKind::Simple, 2).unwrap();
+///
+/// assert_eq!(qstick.nexta(&bar(10.0, 12.0)), 2.0);
+/// assert_eq!(qstick.nexta(&bar(12.0, 11.0)), 0.5);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Qstick {
+    ma: MovingAverage,
+}
+
+impl Qstick {
+    pub fn new(kind: MovingAverageKind, period: usize) -> Result<Self> {
+        Ok(Self {
+            ma: MovingAverage::new(kind, period)?,
+        })
+    }
+
+    pub fn kind(&self) -> MovingAverageKind {
+        self.ma.kind()
+    }
+}
+
+impl Period for Qstick {
+    fn period(&self) -> usize {
+        self.ma.period()
+    }
+}
+
+impl<T: Open + Close> Nexta<&T> for Qstick {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.ma.nexta(input.close() - input.open())
+    }
+}
+
+impl Reset for Qstick {
+    fn reset(&mut self) {
+        self.ma.reset();
+    }
+}
+
+impl Default for Qstick {
+    fn default() -> Self {
+        Self::new(MovingAverageKind::Simple, 8).unwrap()
+    }
+}
+
+impl fmt::Display for Qstick {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "QSTICK({})", self.ma)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, close: f64) -> Bar {
+        Bar::new().open(open).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Qstick::new(MovingAverageKind::Simple, 0).is_err());
+        assert!(Qstick::new(MovingAverageKind::Exponential, 0).is_err());
+        assert!(Qstick::new(MovingAverageKind::Simple, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next_simple() {
+        let mut qstick = Qstick::new(MovingAverageKind::Simple, 3).unwrap();
+
+        assert_eq!(qstick.nexta(&bar(10.0, 12.0)), 2.0);
+        assert_eq!(qstick.nexta(&bar(12.0, 11.0)), 0.5);
+        assert_eq!(round(qstick.nexta(&bar(11.0, 8.0))), -0.667);
+        assert_eq!(qstick.nexta(&bar(8.0, 12.0)), 0.0);
+    }
+
+    #[test]
+    fn test_next_exponential() {
+        let mut qstick = Qstick::new(MovingAverageKind::Exponential, 3).unwrap();
+
+        assert_eq!(qstick.nexta(&bar(10.0, 12.0)), 2.0);
+        assert_eq!(qstick.nexta(&bar(12.0, 11.0)), 0.5);
+        assert_eq!(qstick.nexta(&bar(11.0, 8.0)), -1.25);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut qstick = Qstick::new(MovingAverageKind::Simple, 3).unwrap();
+
+        qstick.nexta(&bar(10.0, 12.0));
+        qstick.reset();
+        assert_eq!(qstick.nexta(&bar(12.0, 11.0)), -1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        let qstick = Qstick::default();
+        assert_eq!(qstick.kind(), MovingAverageKind::Simple);
+        assert_eq!(qstick.period(), 8);
+    }
+
+    #[test]
+    fn test_display() {
+        let qstick = Qstick::new(MovingAverageKind::Exponential, 10).unwrap();
+        assert_eq!(format!("{}", qstick), "QSTICK(EMA(10))");
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::RollingOrderStatistics;
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling quantile (percentile).
+///
+/// Returns the _q_-th quantile of the last _period_ values. Quantiles are robust to outliers,
+/// which makes them useful for percentile bands and percentile-rank style systems. A quantile of
+/// 0.5 is the [Median](crate::indicators::Median).
+///
+/// # Formula
+///
+/// With the window sorted ascending as x<sub>0</sub> ≤ … ≤ x<sub>n-1</sub>, h = _q_ × (n - 1)
+/// and k = ⌊h⌋:
+///
+/// Q = x<sub>k</sub> + (h - k) × (x<sub>k+1</sub> - x<sub>k</sub>)
+///
+/// This is linear interpolation between the closest ranks, the default of most statistics
+/// packages. Before the window is full, n is the number of values seen so far.
+///
+/// # Parameters
+///
+/// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
+/// * _q_ - quantile (number between 0 and 1). Default value is 0.5.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::Quantile;
+/// use tars::Nexta;
+///
+/// let mut quantile = Quantile::new(5, 0.75).unwrap();
+/// quantile.nexta(1.0);
+/// quantile.nexta(5.0);
+/// quantile.nexta(2.0);
+/// quantile.nexta(4.0);
+/// assert_eq!(quantile.nexta(3.0), 4.0);
+/// ```
+///
+/// # Links
+///
+/// * [Quantile, Wikipedia](https://en.wikipedia.org/wiki/Quantile)
+///
+#[doc(alias = "Percentile")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Quantile {
+    q: f64,
+    window: RollingOrderStatistics,
+}
+
+impl Quantile {
+    pub fn new(period: usize, q: f64) -> Result<Self> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            q,
+            window: RollingOrderStatistics::new(period)?,
+        })
+    }
+
+    pub fn q(&self) -> f64 {
+        self.q
+    }
+}
+
+impl Period for Quantile {
+    fn period(&self) -> usize {
+        self.window.period()
+    }
+}
+
+impl Nexta<f64> for Quantile {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        self.window.push(input);
+        self.window.quantile(self.q)
+    }
+}
+
+impl<T: Close> Nexta<&T> for Quantile {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for Quantile {
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+impl Default for Quantile {
+    fn default() -> Self {
+        Self::new(14, 0.5).unwrap()
+    }
+}
+
+impl fmt::Display for Quantile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "QUANTILE({}, {})", self.window.period(), self.q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Quantile);
+
+    #[test]
+    fn test_new() {
+        assert!(Quantile::new(0, 0.5).is_err());
+        assert!(Quantile::new(5, -0.1).is_err());
+        assert!(Quantile::new(5, 1.1).is_err());
+        assert!(Quantile::new(5, f64::NAN).is_err());
+        assert!(Quantile::new(1, 0.0).is_ok());
+        assert!(Quantile::new(1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut quantile = Quantile::new(4, 0.25).unwrap();
+
+        assert_eq!(quantile.nexta(8.0), 8.0);
+        assert_eq!(quantile.nexta(4.0), 5.0);
+        assert_eq!(quantile.nexta(6.0), 5.0);
+        assert_eq!(quantile.nexta(2.0), 3.5);
+        // 8.0 leaves the window
+        assert_eq!(quantile.nexta(10.0), 3.5);
+        // 4.0 leaves the window
+        assert_eq!(quantile.nexta(12.0), 5.0);
+    }
+
+    #[test]
+    fn test_extremes() {
+        let mut min = Quantile::new(3, 0.0).unwrap();
+        let mut max = Quantile::new(3, 1.0).unwrap();
+
+        for &input in &[5.0, 1.0, 3.0, 4.0] {
+            min.nexta(input);
+            max.nexta(input);
+        }
+        assert_eq!(min.nexta(6.0), 3.0);
+        assert_eq!(max.nexta(6.0), 6.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut quantile = Quantile::new(3, 0.5).unwrap();
+
+        assert_eq!(quantile.nexta(&Bar::new().close(4)), 4.0);
+        assert_eq!(quantile.nexta(&Bar::new().close(8)), 6.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut quantile = Quantile::new(3, 0.9).unwrap();
+
+        quantile.nexta(4.0);
+        quantile.nexta(8.0);
+        quantile.reset();
+        assert_eq!(quantile.nexta(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Quantile::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let quantile = Quantile::new(20, 0.9).unwrap();
+        assert_eq!(format!("{}", quantile), "QUANTILE(20, 0.9)");
+    }
+}
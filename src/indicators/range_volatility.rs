@@ -0,0 +1,249 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, High, Low, Nexta, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Range-based volatility.
+///
+/// Estimates volatility from the open, high, low and close of each bar instead of the close
+/// alone, which needs far fewer bars than close-to-close
+/// [HistoricalVolatility](crate::indicators::HistoricalVolatility) for the same accuracy. The
+/// per-bar variances of the chosen estimator are averaged over the last _period_ bars and
+/// annualized.
+///
+/// # Formula
+///
+/// RangeVolatility = √(SMA(_period_) of σ<sup>2</sup> × _bars per year_)
+///
+/// Where the per-bar variance σ<sup>2</sup> is:
+///
+/// * _Parkinson_: ln(H / L)<sup>2</sup> / (4 ln 2)
+/// * _Garman-Klass_: 0.5 ln(H / L)<sup>2</sup> - (2 ln 2 - 1) ln(C / O)<sup>2</sup>
+/// * _Rogers-Satchell_: ln(H / C) ln(H / O) + ln(L / C) ln(L / O)
+///
+/// Parkinson and Garman-Klass assume no drift; Rogers-Satchell stays unbiased in trending
+/// markets. A negative average variance (possible with Garman-Klass) is treated as 0. Bars
+/// with a price less than or equal to 0 are skipped and the volatility stays unchanged.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 20.
+/// * _estimator_ - per-bar variance estimator. Default is Parkinson.
+/// * _bars_per_year_ - number of bars in a year, e.g. 252 for daily bars (integer greater than
+///   0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{RangeEstimator, RangeVolatility};
+/// use tars::{DataItema, Nexta};
+///
+/// let mut vol = RangeVolatility::new(20, RangeEstimator::GarmanKlass, 252).unwrap();
+///
+/// let bar = DataItema::builder()
+///     .open(100.0)
+///     .high(102.0)
+///     .low(99.0)
+///     .close(101.0)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// assert!(vol.nexta(&bar) > 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Volatility (finance), Wikipedia](https://en.wikipedia.org/wiki/Volatility_(finance))
+///
+#[doc(alias = "Parkinson")]
+#[doc(alias = "Garman-Klass")]
+#[doc(alias = "Rogers-Satchell")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RangeVolatility {
+    estimator: RangeEstimator,
+    bars_per_year: usize,
+    sma: Sma,
+    volatility: f64,
+}
+
+/// Per-bar variance estimator of [RangeVolatility](struct.RangeVolatility.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeEstimator {
+    /// High-low range only.
+    Parkinson,
+    /// High-low range and open-close move.
+    GarmanKlass,
+    /// High, low and close relative to the open; allows for drift.
+    RogersSatchell,
+}
+
+impl RangeEstimator {
+    fn variance(self, open: f64, high: f64, low: f64, close: f64) -> f64 {
+        match self {
+            RangeEstimator::Parkinson => (high / low).ln().powi(2) / (4.0 * std::f64::consts::LN_2),
+            RangeEstimator::GarmanKlass => {
+                0.5 * (high / low).ln().powi(2)
+                    - (2.0 * std::f64::consts::LN_2 - 1.0) * (close / open).ln().powi(2)
+            }
+            RangeEstimator::RogersSatchell => {
+                (high / close).ln() * (high / open).ln() + (low / close).ln() * (low / open).ln()
+            }
+        }
+    }
+}
+
+impl fmt::Display for RangeEstimator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RangeEstimator::Parkinson => write!(f, "PARKINSON"),
+            RangeEstimator::GarmanKlass => write!(f, "GK"),
+            RangeEstimator::RogersSatchell => write!(f, "RS"),
+        }
+    }
+}
+
+impl RangeVolatility {
+    pub fn new(period: usize, estimator: RangeEstimator, bars_per_year: usize) -> Result<Self> {
+        if bars_per_year == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            estimator,
+            bars_per_year,
+            sma: Sma::new(period)?,
+            volatility: 0.0,
+        })
+    }
+
+    pub fn estimator(&self) -> RangeEstimator {
+        self.estimator
+    }
+
+    pub fn bars_per_year(&self) -> usize {
+        self.bars_per_year
+    }
+}
+
+impl Period for RangeVolatility {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl<T: Open + High + Low + Close> Nexta<&T> for RangeVolatility {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let (open, high, low, close) = (input.open(), input.high(), input.low(), input.close());
+        if open > 0.0 && high > 0.0 && low > 0.0 && close > 0.0 {
+            let variance = self
+                .sma
+                .nexta(self.estimator.variance(open, high, low, close));
+            self.volatility = (variance.max(0.0) * self.bars_per_year as f64).sqrt();
+        }
+
+        self.volatility
+    }
+}
+
+impl Reset for RangeVolatility {
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.volatility = 0.0;
+    }
+}
+
+impl Default for RangeVolatility {
+    fn default() -> Self {
+        Self::new(20, RangeEstimator::Parkinson, 252).unwrap()
+    }
+}
+
+impl fmt::Display for RangeVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "RANGEVOL({}, {}, {})",
+            self.sma.period(),
+            self.estimator,
+            self.bars_per_year
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar() -> Bar {
+        Bar::new().open(100).high(110).low(90).close(105)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RangeVolatility::new(0, RangeEstimator::Parkinson, 252).is_err());
+        assert!(RangeVolatility::new(1, RangeEstimator::Parkinson, 0).is_err());
+        assert!(RangeVolatility::new(1, RangeEstimator::RogersSatchell, 1).is_ok());
+    }
+
+    #[test]
+    fn test_estimators() {
+        let mut vol = RangeVolatility::new(3, RangeEstimator::Parkinson, 1).unwrap();
+        assert_eq!(round(vol.nexta(&bar())), 0.121);
+
+        let mut vol = RangeVolatility::new(3, RangeEstimator::GarmanKlass, 1).unwrap();
+        assert_eq!(round(vol.nexta(&bar())), 0.139);
+
+        let mut vol = RangeVolatility::new(3, RangeEstimator::RogersSatchell, 1).unwrap();
+        assert_eq!(round(vol.nexta(&bar())), 0.144);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vol = RangeVolatility::new(2, RangeEstimator::Parkinson, 4).unwrap();
+        let flat = Bar::new().open(100).high(100).low(100).close(100);
+
+        assert_eq!(round(vol.nexta(&bar())), 0.241);
+        assert_eq!(round(vol.nexta(&flat)), 0.17);
+        assert_eq!(vol.nexta(&flat), 0.0);
+    }
+
+    #[test]
+    fn test_next_non_positive_price() {
+        let mut vol = RangeVolatility::new(2, RangeEstimator::GarmanKlass, 1).unwrap();
+
+        let out = vol.nexta(&bar());
+        assert_eq!(vol.nexta(&Bar::new().open(1).high(2).low(0).close(1)), out);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vol = RangeVolatility::new(2, RangeEstimator::Parkinson, 1).unwrap();
+
+        vol.nexta(&bar());
+        vol.reset();
+        assert_eq!(
+            vol.nexta(&Bar::new().open(100).high(100).low(100).close(100)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        RangeVolatility::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vol = RangeVolatility::new(10, RangeEstimator::GarmanKlass, 365).unwrap();
+        assert_eq!(format!("{}", vol), "RANGEVOL(10, GK, 365)");
+    }
+}
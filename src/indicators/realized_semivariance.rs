@@ -0,0 +1,221 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling realized semivariance.
+///
+/// Splits the realized variance of the returns in the window into the part contributed by
+/// negative returns (downside) and by positive returns (upside). Their sum is the realized
+/// variance. A growing downside share signals that the volatility is driven by sell-offs. The
+/// inputs are returns, not prices.
+///
+/// # Formula
+///
+/// * _RS<sup>-</sup>_ = Σ r<sub>i</sub><sup>2</sup> for r<sub>i</sub> < 0
+/// * _RS<sup>+</sup>_ = Σ r<sub>i</sub><sup>2</sup> for r<sub>i</sub> > 0
+///
+/// over the last _period_ returns.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{RealizedSemivariance, RealizedSemivarianceOutput};
+/// use tars::Nexta;
+///
+/// let mut rs = RealizedSemivariance::new(3).unwrap();
+///
+/// rs.nexta(0.5);
+/// assert_eq!(
+///     rs.nexta(-0.25),
+///     RealizedSemivarianceOutput { downside: 0.0625, upside: 0.25 }
+/// );
+/// ```
+///
+#[doc(alias = "RSV")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RealizedSemivariance {
+    period: usize,
+    index: usize,
+    count: usize,
+    downside: f64,
+    upside: f64,
+    // number of negative and positive returns in the window, a sum is zeroed at 0 to drop
+    // float residue
+    downs: usize,
+    ups: usize,
+    deque: Box<[f64]>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealizedSemivarianceOutput {
+    pub downside: f64,
+    pub upside: f64,
+}
+
+impl RealizedSemivariance {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                downside: 0.0,
+                upside: 0.0,
+                downs: 0,
+                ups: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for RealizedSemivariance {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for RealizedSemivariance {
+    type Output = RealizedSemivarianceOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old_val = self.deque[self.index];
+            if old_val < 0.0 {
+                self.downs -= 1;
+                self.downside = if self.downs > 0 {
+                    (self.downside - old_val * old_val).max(0.0)
+                } else {
+                    0.0
+                };
+            } else if old_val > 0.0 {
+                self.ups -= 1;
+                self.upside = if self.ups > 0 {
+                    (self.upside - old_val * old_val).max(0.0)
+                } else {
+                    0.0
+                };
+            }
+        }
+
+        if input < 0.0 {
+            self.downs += 1;
+            self.downside += input * input;
+        } else if input > 0.0 {
+            self.ups += 1;
+            self.upside += input * input;
+        }
+
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        RealizedSemivarianceOutput {
+            downside: self.downside,
+            upside: self.upside,
+        }
+    }
+}
+
+impl Reset for RealizedSemivariance {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.downside = 0.0;
+        self.upside = 0.0;
+        self.downs = 0;
+        self.ups = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for RealizedSemivariance {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for RealizedSemivariance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RSV({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RealizedSemivariance::new(0).is_err());
+        assert!(RealizedSemivariance::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rs = RealizedSemivariance::new(2).unwrap();
+
+        let mut next = |input| {
+            let out = rs.nexta(input);
+            (out.downside, out.upside)
+        };
+
+        assert_eq!(next(0.5), (0.0, 0.25));
+        assert_eq!(next(-0.25), (0.0625, 0.25));
+        // 0.5 leaves the window
+        assert_eq!(next(-0.5), (0.3125, 0.0));
+        assert_eq!(next(0.0), (0.25, 0.0));
+        assert_eq!(next(0.25), (0.0, 0.0625));
+    }
+
+    #[test]
+    fn test_next_side_leaves_window() {
+        let mut rs = RealizedSemivariance::new(2).unwrap();
+
+        for &x in &[-0.013, -0.029, -0.017, 0.013, 0.029, 0.017] {
+            rs.nexta(x);
+        }
+        assert_eq!(rs.nexta(0.031).downside, 0.0);
+
+        for &x in &[-0.013, -0.029] {
+            rs.nexta(x);
+        }
+        assert_eq!(rs.nexta(-0.017).upside, 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rs = RealizedSemivariance::new(2).unwrap();
+
+        rs.nexta(-0.5);
+        rs.reset();
+        assert_eq!(rs.nexta(0.5).downside, 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RealizedSemivariance::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rs = RealizedSemivariance::new(20).unwrap();
+        assert_eq!(format!("{}", rs), "RSV(20)");
+    }
+}
@@ -0,0 +1,583 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{EfficiencyRatio, StandardDeviation as Sd};
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Regime detector.
+///
+/// Classifies every bar into a market regime, trending or ranging, or low or high volatility,
+/// and reports how likely the regime is. The classification is done by one of the
+/// [RegimeModel](enum.RegimeModel.html)s:
+///
+/// * [Trend](enum.RegimeModel.html#variant.Trend) - the market trends while the
+///   [Efficiency Ratio](struct.EfficiencyRatio.html) of the last _period_ prices is at least
+///   _threshold_, otherwise it ranges.
+/// * [Volatility](enum.RegimeModel.html#variant.Volatility) - volatility is high while the
+///   standard deviation of the last _short period_ log returns exceeds _threshold_ times the
+///   standard deviation of the last _long period_ log returns, otherwise it is low.
+/// * [Hmm](enum.RegimeModel.html#variant.Hmm) - online two-state Gaussian hidden Markov model of
+///   the log returns, available with the `hmm` feature. See below.
+///
+/// The threshold rules are deterministic, so their probability is always 1. Before the first
+/// log return is known the Volatility model reports low volatility.
+///
+/// # Hidden Markov model
+///
+/// Both states model the log returns as normally distributed with zero mean, the low volatility
+/// state with the smaller variance. The state stays the same from one bar to the next with
+/// probability _persistence_. Every return updates the filtered state probabilities with the
+/// forward algorithm, and then the variance of each state with the return weighted by the
+/// state probability, forgetting old returns with the decay factor _lambda_.
+///
+/// The variances are seeded from the first 20 returns: a half and twice their mean square.
+/// Until then the low volatility state is reported with probability 0.5.
+///
+/// # Parameters
+///
+/// * _model_ - classification model. Default is
+///   [Trend](enum.RegimeModel.html#variant.Trend) with _period_ 10 and _threshold_ 0.3.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{RegimeDetector, RegimeLabel, RegimeModel};
+/// use tars::Nexta;
+///
+/// let model = RegimeModel::Trend { period: 4, threshold: 0.5 };
+/// let mut detector = RegimeDetector::new(model).unwrap();
+///
+/// detector.nexta(10.0);
+/// detector.nexta(11.0);
+/// assert_eq!(detector.nexta(12.0).regime, RegimeLabel::Trend);
+/// assert_eq!(detector.nexta(11.0).regime, RegimeLabel::Range);
+/// ```
+///
+#[doc(alias = "HMM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RegimeDetector {
+    model: RegimeModel,
+    classifier: Classifier,
+}
+
+/// Classification model of [RegimeDetector](struct.RegimeDetector.html).
+///
+/// The enum is non-exhaustive because the set of models depends on the enabled features.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum RegimeModel {
+    /// Efficiency Ratio over _period_ prices (integer greater than 0) compared with _threshold_
+    /// (number between 0 and 1).
+    Trend { period: usize, threshold: f64 },
+    /// Ratio of the short and long standard deviations of log returns (integers greater than 0,
+    /// the short period less than the long one) compared with _threshold_ (number greater
+    /// than 0).
+    Volatility {
+        short_period: usize,
+        long_period: usize,
+        threshold: f64,
+    },
+    /// Two-state Gaussian hidden Markov model with the state _persistence_ and the decay factor
+    /// _lambda_ (numbers between 0 and 1, exclusive).
+    #[cfg(feature = "hmm")]
+    Hmm { persistence: f64, lambda: f64 },
+}
+
+/// Market regime.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegimeLabel {
+    Trend,
+    Range,
+    LowVolatility,
+    HighVolatility,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegimeOutput {
+    pub regime: RegimeLabel,
+    /// Probability of the regime, between 0 and 1.
+    pub probability: f64,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+enum Classifier {
+    Trend(EfficiencyRatio),
+    Volatility {
+        prev: Option<f64>,
+        short_sd: Sd,
+        long_sd: Sd,
+        high: bool,
+    },
+    #[cfg(feature = "hmm")]
+    Hmm(hmm::GaussianHmm),
+}
+
+impl RegimeDetector {
+    pub fn new(model: RegimeModel) -> Result<Self> {
+        let classifier = match model {
+            RegimeModel::Trend { period, threshold } => {
+                if !(0.0..=1.0).contains(&threshold) {
+                    return Err(TaError::InvalidParameter);
+                }
+                Classifier::Trend(EfficiencyRatio::new(period)?)
+            }
+            RegimeModel::Volatility {
+                short_period,
+                long_period,
+                threshold,
+            } => {
+                if short_period >= long_period || !(threshold.is_finite() && threshold > 0.0) {
+                    return Err(TaError::InvalidParameter);
+                }
+                Classifier::Volatility {
+                    prev: None,
+                    short_sd: Sd::new(short_period)?,
+                    long_sd: Sd::new(long_period)?,
+                    high: false,
+                }
+            }
+            #[cfg(feature = "hmm")]
+            RegimeModel::Hmm {
+                persistence,
+                lambda,
+            } => Classifier::Hmm(hmm::GaussianHmm::new(persistence, lambda)?),
+        };
+
+        Ok(Self { model, classifier })
+    }
+
+    pub fn model(&self) -> RegimeModel {
+        self.model
+    }
+}
+
+impl Period for RegimeDetector {
+    /// Returns the _period_ of the Trend model, the _long period_ of the Volatility model and 0
+    /// for the hidden Markov model.
+    fn period(&self) -> usize {
+        match self.model {
+            RegimeModel::Trend { period, .. } => period,
+            RegimeModel::Volatility { long_period, .. } => long_period,
+            #[cfg(feature = "hmm")]
+            RegimeModel::Hmm { .. } => 0,
+        }
+    }
+}
+
+impl Nexta<f64> for RegimeDetector {
+    type Output = RegimeOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let model = self.model;
+        match (&mut self.classifier, model) {
+            (Classifier::Trend(er), RegimeModel::Trend { threshold, .. }) => {
+                let regime = if er.nexta(input) >= threshold {
+                    RegimeLabel::Trend
+                } else {
+                    RegimeLabel::Range
+                };
+                RegimeOutput {
+                    regime,
+                    probability: 1.0,
+                }
+            }
+            (
+                Classifier::Volatility {
+                    prev,
+                    short_sd,
+                    long_sd,
+                    high,
+                },
+                RegimeModel::Volatility { threshold, .. },
+            ) => {
+                // the regime is kept on returns of non-positive prices
+                match prev.replace(input) {
+                    Some(prev) if prev > 0.0 && input > 0.0 => {
+                        let ret = (input / prev).ln();
+                        let short = short_sd.nexta(ret);
+                        let long = long_sd.nexta(ret);
+                        *high = short > threshold * long;
+                    }
+                    _ => {}
+                }
+                let regime = if *high {
+                    RegimeLabel::HighVolatility
+                } else {
+                    RegimeLabel::LowVolatility
+                };
+                RegimeOutput {
+                    regime,
+                    probability: 1.0,
+                }
+            }
+            #[cfg(feature = "hmm")]
+            (Classifier::Hmm(model), _) => model.nexta(input),
+            _ => unreachable!("classifier is built from the model"),
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for RegimeDetector {
+    type Output = RegimeOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for RegimeDetector {
+    fn reset(&mut self) {
+        match &mut self.classifier {
+            Classifier::Trend(er) => er.reset(),
+            Classifier::Volatility {
+                prev,
+                short_sd,
+                long_sd,
+                high,
+            } => {
+                *prev = None;
+                short_sd.reset();
+                long_sd.reset();
+                *high = false;
+            }
+            #[cfg(feature = "hmm")]
+            Classifier::Hmm(model) => model.reset(),
+        }
+    }
+}
+
+impl Default for RegimeDetector {
+    fn default() -> Self {
+        Self::new(RegimeModel::Trend {
+            period: 10,
+            threshold: 0.3,
+        })
+        .unwrap()
+    }
+}
+
+impl fmt::Display for RegimeDetector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.model {
+            RegimeModel::Trend { period, threshold } => {
+                write!(f, "REGIME(ER({}), {})", period, threshold)
+            }
+            RegimeModel::Volatility {
+                short_period,
+                long_period,
+                threshold,
+            } => write!(
+                f,
+                "REGIME(VOL({}, {}), {})",
+                short_period, long_period, threshold
+            ),
+            #[cfg(feature = "hmm")]
+            RegimeModel::Hmm {
+                persistence,
+                lambda,
+            } => write!(f, "REGIME(HMM({}, {}))", persistence, lambda),
+        }
+    }
+}
+
+#[cfg(feature = "hmm")]
+mod hmm {
+    use super::{RegimeLabel, RegimeOutput};
+    use crate::errors::{Result, TaError};
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+
+    const WARMUP: usize = 20;
+
+    /// Zero-mean two-state Gaussian HMM of log returns, state 0 being the low volatility one.
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone)]
+    pub(super) struct GaussianHmm {
+        persistence: f64,
+        lambda: f64,
+        prev: Option<f64>,
+        count: usize,
+        sum_squares: f64,
+        variances: [f64; 2],
+        weights: [f64; 2],
+        probabilities: [f64; 2],
+    }
+
+    impl GaussianHmm {
+        pub(super) fn new(persistence: f64, lambda: f64) -> Result<Self> {
+            let in_unit = |x: f64| x > 0.0 && x < 1.0;
+            if !in_unit(persistence) || !in_unit(lambda) {
+                return Err(TaError::InvalidParameter);
+            }
+
+            Ok(Self {
+                persistence,
+                lambda,
+                prev: None,
+                count: 0,
+                sum_squares: 0.0,
+                variances: [0.0; 2],
+                weights: [0.0; 2],
+                probabilities: [0.5; 2],
+            })
+        }
+
+        pub(super) fn nexta(&mut self, input: f64) -> RegimeOutput {
+            if let Some(prev) = self
+                .prev
+                .replace(input)
+                .filter(|&prev| prev > 0.0 && input > 0.0)
+            {
+                let ret = (input / prev).ln();
+                if self.count < WARMUP {
+                    self.warm_up(ret * ret);
+                } else {
+                    self.update(ret * ret);
+                }
+            }
+
+            if self.probabilities[1] > self.probabilities[0] {
+                RegimeOutput {
+                    regime: RegimeLabel::HighVolatility,
+                    probability: self.probabilities[1],
+                }
+            } else {
+                RegimeOutput {
+                    regime: RegimeLabel::LowVolatility,
+                    probability: self.probabilities[0],
+                }
+            }
+        }
+
+        fn warm_up(&mut self, square: f64) {
+            self.count += 1;
+            self.sum_squares += square;
+
+            if self.count == WARMUP {
+                let variance = (self.sum_squares / WARMUP as f64).max(f64::MIN_POSITIVE);
+                self.variances = [variance / 2.0, variance * 2.0];
+                self.weights = [WARMUP as f64 / 2.0; 2];
+            }
+        }
+
+        fn update(&mut self, square: f64) {
+            let stay = self.persistence;
+            let switch = 1.0 - stay;
+            let prior = [
+                self.probabilities[0] * stay + self.probabilities[1] * switch,
+                self.probabilities[0] * switch + self.probabilities[1] * stay,
+            ];
+
+            // the constant factor of the normal density cancels out
+            let mut posterior = [0.0; 2];
+            for (k, p) in posterior.iter_mut().enumerate() {
+                let variance = self.variances[k];
+                *p = prior[k] * (-square / (2.0 * variance)).exp() / variance.sqrt();
+            }
+            let total = posterior[0] + posterior[1];
+            self.probabilities = if total > 0.0 && total.is_finite() {
+                [posterior[0] / total, posterior[1] / total]
+            } else {
+                prior
+            };
+
+            for k in 0..2 {
+                let weight = self.probabilities[k];
+                self.weights[k] = self.lambda * self.weights[k] + weight;
+                self.variances[k] += weight / self.weights[k] * (square - self.variances[k]);
+                self.variances[k] = self.variances[k].max(f64::MIN_POSITIVE);
+            }
+
+            // keep the low volatility state first
+            if self.variances[0] > self.variances[1] {
+                self.variances.swap(0, 1);
+                self.weights.swap(0, 1);
+                self.probabilities.swap(0, 1);
+            }
+        }
+
+        pub(super) fn reset(&mut self) {
+            self.prev = None;
+            self.count = 0;
+            self.sum_squares = 0.0;
+            self.variances = [0.0; 2];
+            self.weights = [0.0; 2];
+            self.probabilities = [0.5; 2];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RegimeDetector);
+
+    fn volatility(short_period: usize, long_period: usize, threshold: f64) -> RegimeModel {
+        RegimeModel::Volatility {
+            short_period,
+            long_period,
+            threshold,
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let trend = |period, threshold| RegimeModel::Trend { period, threshold };
+
+        assert!(RegimeDetector::new(trend(0, 0.3)).is_err());
+        assert!(RegimeDetector::new(trend(10, -0.1)).is_err());
+        assert!(RegimeDetector::new(trend(10, 1.1)).is_err());
+        assert!(RegimeDetector::new(trend(1, 0.0)).is_ok());
+
+        assert!(RegimeDetector::new(volatility(0, 50, 1.5)).is_err());
+        assert!(RegimeDetector::new(volatility(50, 50, 1.5)).is_err());
+        assert!(RegimeDetector::new(volatility(10, 50, 0.0)).is_err());
+        assert!(RegimeDetector::new(volatility(1, 2, 1.0)).is_ok());
+    }
+
+    #[test]
+    fn test_next_trend() {
+        let model = RegimeModel::Trend {
+            period: 4,
+            threshold: 0.6,
+        };
+        let mut detector = RegimeDetector::new(model).unwrap();
+
+        let mut next = |input: f64| detector.nexta(input).regime;
+
+        assert_eq!(next(10.0), RegimeLabel::Trend);
+        assert_eq!(next(13.0), RegimeLabel::Trend);
+        // ER = 0.5
+        assert_eq!(next(12.0), RegimeLabel::Range);
+        // ER = 0.6
+        assert_eq!(next(13.0), RegimeLabel::Trend);
+        assert_eq!(next(18.0), RegimeLabel::Trend);
+    }
+
+    #[test]
+    fn test_next_volatility() {
+        let mut detector = RegimeDetector::new(volatility(2, 4, 1.1)).unwrap();
+
+        let mut next = |input: f64| detector.nexta(input);
+
+        assert_eq!(
+            next(100.0),
+            RegimeOutput {
+                regime: RegimeLabel::LowVolatility,
+                probability: 1.0
+            }
+        );
+        for &input in &[101.0, 100.0, 101.0, 100.0] {
+            assert_eq!(next(input).regime, RegimeLabel::LowVolatility);
+        }
+        assert_eq!(next(110.0).regime, RegimeLabel::HighVolatility);
+        assert_eq!(next(100.0).regime, RegimeLabel::HighVolatility);
+    }
+
+    #[test]
+    fn test_next_volatility_non_positive_price() {
+        let mut detector = RegimeDetector::new(volatility(2, 4, 1.1)).unwrap();
+
+        for &input in &[100.0, 101.0, 100.0, 110.0] {
+            detector.nexta(input);
+        }
+        assert_eq!(detector.nexta(0.0).regime, RegimeLabel::HighVolatility);
+        for &input in &[100.0, 101.0, 100.0, 101.0, 100.0, 101.0] {
+            detector.nexta(input);
+        }
+        assert_eq!(detector.nexta(100.0).regime, RegimeLabel::LowVolatility);
+    }
+
+    #[cfg(feature = "hmm")]
+    #[test]
+    fn test_next_hmm() {
+        use crate::synthetic::{Process, SyntheticBars};
+
+        let model = RegimeModel::Hmm {
+            persistence: 0.98,
+            lambda: 0.99,
+        };
+        assert!(RegimeDetector::new(RegimeModel::Hmm {
+            persistence: 1.0,
+            lambda: 0.99
+        })
+        .is_err());
+        assert!(RegimeDetector::new(RegimeModel::Hmm {
+            persistence: 0.98,
+            lambda: 0.0
+        })
+        .is_err());
+
+        let mut detector = RegimeDetector::new(model).unwrap();
+        let warm_up = detector.nexta(100.0);
+        assert_eq!(
+            warm_up,
+            RegimeOutput {
+                regime: RegimeLabel::LowVolatility,
+                probability: 0.5
+            }
+        );
+
+        // calm market, then a volatile one, then calm again
+        let calm = Process::Gbm {
+            drift: 0.0,
+            volatility: 0.005,
+        };
+        let volatile = Process::Gbm {
+            drift: 0.0,
+            volatility: 0.05,
+        };
+
+        let mut last = 100.0;
+        let mut count_regime = |process: Process, seed: u64, regime: RegimeLabel| {
+            let bars = SyntheticBars::new(process, last, seed).unwrap();
+            let mut count = 0;
+            for bar in bars.take(100) {
+                last = bar.close();
+                let out = detector.nexta(last);
+                assert!(out.probability >= 0.5 && out.probability <= 1.0);
+                if out.regime == regime {
+                    count += 1;
+                }
+            }
+            count
+        };
+
+        count_regime(calm.clone(), 1, RegimeLabel::LowVolatility);
+        assert!(count_regime(volatile, 2, RegimeLabel::HighVolatility) > 90);
+        assert!(count_regime(calm, 3, RegimeLabel::LowVolatility) > 90);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut detector = RegimeDetector::new(volatility(2, 4, 1.2)).unwrap();
+
+        for &input in &[100.0, 101.0, 100.0, 101.0, 100.0, 110.0] {
+            detector.nexta(input);
+        }
+        detector.reset();
+        assert_eq!(detector.nexta(120.0).regime, RegimeLabel::LowVolatility);
+    }
+
+    #[test]
+    fn test_default() {
+        RegimeDetector::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let detector = RegimeDetector::default();
+        assert_eq!(format!("{}", detector), "REGIME(ER(10), 0.3)");
+
+        let detector = RegimeDetector::new(volatility(10, 50, 1.5)).unwrap();
+        assert_eq!(format!("{}", detector), "REGIME(VOL(10, 50), 1.5)");
+    }
+}
@@ -0,0 +1,272 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, High, Low, Nexta, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Relative Vigor Index (RVI).
+///
+/// Developed by John Ehlers, the indicator measures the conviction of a price move by comparing
+/// the candle body to the candle range: in rising markets closes tend to be above opens, in
+/// falling markets below. Crosses of the index and its signal line are used as signals.
+///
+/// # Formula
+///
+/// * _RVI_ = SMA(_period_) of Numerator / SMA(_period_) of Denominator
+/// * _Signal_ = SWMA of RVI
+///
+/// Where:
+///
+/// * _Numerator_ = SWMA of (Close - Open)
+/// * _Denominator_ = SWMA of (High - Low)
+/// * _SWMA_ - symmetric weighted moving average over the last 4 values with weights 1, 2, 2, 1:
+///   (x<sub>t</sub> + 2x<sub>t-1</sub> + 2x<sub>t-2</sub> + x<sub>t-3</sub>) / 6
+///
+/// Until 4 values are available SWMA uses the leading weights only. When the denominator is 0,
+/// i.e. all the bars it covers have a zero range, the RVI is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::RelativeVigorIndex;
+/// use tars::{Nexta, DataItema};
+///
+/// let mut rvi = RelativeVigorIndex::new(3).unwrap();
+///
+/// let di = DataItema::builder()
+///             .open(10.0)
+///             .high(12.0)
+///             .low(8.0)
+///             .close(11.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// let out = rvi.nexta(&di);
+/// assert_eq!((out.rvi, out.signal), (0.25, 0.25));
+/// ```
+///
+#[doc(alias = "RVI")]
+#[doc(alias = "RVGI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RelativeVigorIndex {
+    numerator: SymmetricWeightedAverage,
+    denominator: SymmetricWeightedAverage,
+    numerator_sma: Sma,
+    denominator_sma: Sma,
+    signal: SymmetricWeightedAverage,
+    count: usize,
+    // number of consecutive bars with a zero range, the denominator covers _period_ + 3 bars
+    flat_bars: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelativeVigorIndexOutput {
+    pub rvi: f64,
+    pub signal: f64,
+}
+
+impl From<RelativeVigorIndexOutput> for (f64, f64) {
+    fn from(ro: RelativeVigorIndexOutput) -> Self {
+        (ro.rvi, ro.signal)
+    }
+}
+
+impl RelativeVigorIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            numerator: SymmetricWeightedAverage::new(),
+            denominator: SymmetricWeightedAverage::new(),
+            numerator_sma: Sma::new(period)?,
+            denominator_sma: Sma::new(period)?,
+            signal: SymmetricWeightedAverage::new(),
+            count: 0,
+            flat_bars: 0,
+        })
+    }
+}
+
+impl Period for RelativeVigorIndex {
+    fn period(&self) -> usize {
+        self.numerator_sma.period()
+    }
+}
+
+impl<T: Open + High + Low + Close> Nexta<&T> for RelativeVigorIndex {
+    type Output = RelativeVigorIndexOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let range = input.high() - input.low();
+        self.flat_bars = if range == 0.0 { self.flat_bars + 1 } else { 0 };
+        if self.count < self.numerator_sma.period() + WEIGHTS.len() - 1 {
+            self.count += 1;
+        }
+
+        let numerator = self
+            .numerator_sma
+            .nexta(self.numerator.nexta(input.close() - input.open()));
+        let denominator = self.denominator_sma.nexta(self.denominator.nexta(range));
+
+        let rvi = if self.flat_bars >= self.count {
+            0.0
+        } else {
+            numerator / denominator
+        };
+        let signal = self.signal.nexta(rvi);
+
+        RelativeVigorIndexOutput { rvi, signal }
+    }
+}
+
+impl Reset for RelativeVigorIndex {
+    fn reset(&mut self) {
+        self.numerator.reset();
+        self.denominator.reset();
+        self.numerator_sma.reset();
+        self.denominator_sma.reset();
+        self.signal.reset();
+        self.count = 0;
+        self.flat_bars = 0;
+    }
+}
+
+impl Default for RelativeVigorIndex {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for RelativeVigorIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RVI({})", self.numerator_sma.period())
+    }
+}
+
+const WEIGHTS: [f64; 4] = [1.0, 2.0, 2.0, 1.0];
+
+/// Symmetric weighted moving average over the last 4 values.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct SymmetricWeightedAverage {
+    // the newest value first
+    values: [f64; 4],
+    count: usize,
+}
+
+impl SymmetricWeightedAverage {
+    fn new() -> Self {
+        Self {
+            values: [0.0; 4],
+            count: 0,
+        }
+    }
+
+    fn nexta(&mut self, input: f64) -> f64 {
+        self.values.rotate_right(1);
+        self.values[0] = input;
+        if self.count < WEIGHTS.len() {
+            self.count += 1;
+        }
+
+        let mut sum = 0.0;
+        let mut weights = 0.0;
+        for (value, weight) in self.values.iter().zip(WEIGHTS.iter()).take(self.count) {
+            sum += weight * value;
+            weights += weight;
+        }
+        sum / weights
+    }
+
+    fn reset(&mut self) {
+        self.values = [0.0; 4];
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().open(open).high(high).low(low).close(close)
+    }
+
+    fn round(nums: (f64, f64)) -> (f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        (n0, n1)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RelativeVigorIndex::new(0).is_err());
+        assert!(RelativeVigorIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rvi = RelativeVigorIndex::new(3).unwrap();
+
+        let mut next =
+            |open, high, low, close| round(rvi.nexta(&bar(open, high, low, close)).into());
+
+        assert_eq!(next(10.0, 12.0, 9.0, 11.0), (0.333, 0.333));
+        assert_eq!(next(11.0, 13.0, 10.0, 12.5), (0.361, 0.343));
+        assert_eq!(next(12.5, 13.0, 11.0, 11.5), (0.337, 0.345));
+        assert_eq!(next(11.5, 12.0, 10.0, 10.5), (0.257, 0.331));
+        assert_eq!(next(10.5, 12.0, 10.0, 11.8), (0.103, 0.275));
+        assert_eq!(next(11.8, 14.0, 11.5, 13.5), (0.027, 0.181));
+    }
+
+    #[test]
+    fn test_flat_bars() {
+        let mut rvi = RelativeVigorIndex::new(3).unwrap();
+
+        let out = rvi.nexta(&bar(10.0, 10.0, 10.0, 10.0));
+        assert_eq!((out.rvi, out.signal), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_flat_bars_after_ranges() {
+        let mut rvi = RelativeVigorIndex::new(2).unwrap();
+
+        rvi.nexta(&bar(0.442, 0.442, 0.1, 0.1));
+        rvi.nexta(&bar(0.1, 0.442, 0.1, 0.442));
+        rvi.nexta(&bar(0.129, 0.129, 0.113, 0.113));
+        // the denominator covers 5 bars
+        for _ in 0..4 {
+            rvi.nexta(&bar(0.142, 0.142, 0.142, 0.142));
+        }
+        assert_eq!(rvi.nexta(&bar(0.142, 0.142, 0.142, 0.142)).rvi, 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rvi = RelativeVigorIndex::new(3).unwrap();
+
+        rvi.nexta(&bar(10.0, 12.0, 9.0, 11.0));
+        rvi.nexta(&bar(11.0, 13.0, 10.0, 12.5));
+
+        rvi.reset();
+        let out = rvi.nexta(&bar(12.0, 14.0, 10.0, 10.0));
+        assert_eq!((out.rvi, out.signal), (-0.5, -0.5));
+    }
+
+    #[test]
+    fn test_default() {
+        RelativeVigorIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rvi = RelativeVigorIndex::new(10).unwrap();
+        assert_eq!(format!("{}", rvi), "RVI(10)");
+    }
+}
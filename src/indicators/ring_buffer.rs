@@ -0,0 +1,80 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ring buffer storage of windowed indicators, either in f64 or in f32 to halve the memory of
+/// long windows.
+///
+/// Values are rounded to the storage precision when they are stored, and `set` returns the
+/// rounded value: an indicator that accumulates what `set` returns adds exactly the value it
+/// will later subtract, so that the rounding does not build up in its running sums.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) enum RingBuffer {
+    F64(Box<[f64]>),
+    F32(Box<[f32]>),
+}
+
+impl RingBuffer {
+    pub(crate) fn new(len: usize, f32_storage: bool) -> Self {
+        if f32_storage {
+            RingBuffer::F32(vec![0.0; len].into_boxed_slice())
+        } else {
+            RingBuffer::F64(vec![0.0; len].into_boxed_slice())
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> f64 {
+        match self {
+            RingBuffer::F64(values) => values[index],
+            RingBuffer::F32(values) => f64::from(values[index]),
+        }
+    }
+
+    /// Stores the value and returns it as it was stored.
+    pub(crate) fn set(&mut self, index: usize, value: f64) -> f64 {
+        match self {
+            RingBuffer::F64(values) => {
+                values[index] = value;
+                value
+            }
+            RingBuffer::F32(values) => {
+                values[index] = value as f32;
+                f64::from(values[index])
+            }
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        match self {
+            RingBuffer::F64(values) => values.iter_mut().for_each(|v| *v = 0.0),
+            RingBuffer::F32(values) => values.iter_mut().for_each(|v| *v = 0.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f64() {
+        let mut buffer = RingBuffer::new(2, false);
+
+        assert_eq!(buffer.set(1, 0.1), 0.1);
+        assert_eq!(buffer.get(1), 0.1);
+        buffer.clear();
+        assert_eq!(buffer.get(1), 0.0);
+    }
+
+    #[test]
+    fn test_f32() {
+        let mut buffer = RingBuffer::new(2, true);
+
+        let stored = buffer.set(0, 0.1);
+        assert_eq!(stored, f64::from(0.1f32));
+        assert_eq!(buffer.get(0), stored);
+        assert_eq!(buffer.set(1, 1e40), f64::INFINITY);
+        buffer.clear();
+        assert_eq!(buffer.get(0), 0.0);
+    }
+}
@@ -0,0 +1,171 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::RollingCoMoments;
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling beta of an asset against a benchmark.
+///
+/// Returns the slope of the least-squares regression of the asset returns on the benchmark
+/// returns over the last _period_ pairs, e.g. the hedge ratio of a position against an index.
+/// Every input is a pair _(asset return, benchmark return)_. The intercept of the regression,
+/// the alpha, is available after every input with [alpha](#method.alpha).
+///
+/// # Formula
+///
+/// β = Cov(R<sub>a</sub>, R<sub>b</sub>) / Var(R<sub>b</sub>)
+///
+/// α = mean(R<sub>a</sub>) - β × mean(R<sub>b</sub>)
+///
+/// over the last _period_ pairs. While the benchmark returns are constant over the window beta
+/// is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of pairs (integer greater than 1). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::RollingBeta;
+/// use tars::Nexta;
+///
+/// let mut beta = RollingBeta::new(3).unwrap();
+///
+/// beta.nexta((2.0, 1.0));
+/// assert_eq!(beta.nexta((4.0, 2.0)), 2.0);
+/// assert_eq!(beta.alpha(), 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Beta (finance), Wikipedia](https://en.wikipedia.org/wiki/Beta_(finance))
+///
+#[doc(alias = "Beta")]
+#[doc(alias = "Hedge ratio")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingBeta {
+    moments: RollingCoMoments,
+}
+
+impl RollingBeta {
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            moments: RollingCoMoments::new(period)?,
+        })
+    }
+
+    /// Intercept of the regression after the last input.
+    pub fn alpha(&self) -> f64 {
+        self.moments.mean_x() - self.moments.slope() * self.moments.mean_y()
+    }
+}
+
+impl Period for RollingBeta {
+    fn period(&self) -> usize {
+        self.moments.period()
+    }
+}
+
+impl Nexta<(f64, f64)> for RollingBeta {
+    type Output = f64;
+
+    fn nexta(&mut self, (asset, benchmark): (f64, f64)) -> Self::Output {
+        self.moments.push(asset, benchmark);
+        self.moments.slope()
+    }
+}
+
+impl Reset for RollingBeta {
+    fn reset(&mut self) {
+        self.moments.reset();
+    }
+}
+
+impl Default for RollingBeta {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for RollingBeta {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BETA({})", self.moments.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingBeta::new(1).is_err());
+        assert!(RollingBeta::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut beta = RollingBeta::new(3).unwrap();
+
+        assert_eq!(beta.nexta((2.0, 1.0)), 0.0);
+        assert_eq!(beta.alpha(), 2.0);
+        assert_eq!(round(beta.nexta((4.0, 2.0))), 2.0);
+        assert_eq!(round(beta.alpha()), 0.0);
+        assert_eq!(round(beta.nexta((5.0, 3.0))), 1.5);
+        assert_eq!(round(beta.alpha()), 0.667);
+        // (2, 1) leaves the window
+        assert_eq!(round(beta.nexta((1.0, 4.0))), -1.5);
+        assert_eq!(round(beta.alpha()), 7.833);
+    }
+
+    #[test]
+    fn test_constant_benchmark() {
+        let mut beta = RollingBeta::new(3).unwrap();
+
+        beta.nexta((0.01, 0.02));
+        beta.nexta((0.03, 0.02));
+        assert_eq!(beta.nexta((-0.01, 0.02)), 0.0);
+    }
+
+    #[test]
+    fn test_constant_benchmark_after_varying() {
+        let mut beta = RollingBeta::new(3).unwrap();
+
+        for &(asset, benchmark) in &[(0.1, 0.013), (0.3, 0.029), (0.129, 0.3), (0.113, 0.429)] {
+            beta.nexta((asset, benchmark));
+        }
+        beta.nexta((0.142, 0.429));
+        assert_eq!(beta.nexta((0.442, 0.429)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut beta = RollingBeta::new(3).unwrap();
+
+        beta.nexta((2.0, 1.0));
+        beta.nexta((4.0, 2.0));
+        beta.reset();
+        assert_eq!(beta.nexta((1.0, 3.0)), 0.0);
+        assert_eq!(beta.alpha(), 1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingBeta::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let beta = RollingBeta::new(60).unwrap();
+        assert_eq!(format!("{}", beta), "BETA(60)");
+    }
+}
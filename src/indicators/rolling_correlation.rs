@@ -0,0 +1,169 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::RollingCoMoments;
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling correlation of two series.
+///
+/// Returns the Pearson correlation of the last _period_ pairs of values. Every input is a pair
+/// of values _(X, Y)_, e.g. the returns of the two legs of a pair trade. Unlike the
+/// [exponentially weighted correlation](struct.ExponentiallyWeightedCorrelation.html) all the
+/// pairs in the window weigh the same.
+///
+/// # Formula
+///
+/// ρ = Σ (x<sub>i</sub> - x̄)(y<sub>i</sub> - ȳ) / √(Σ (x<sub>i</sub> - x̄)<sup>2</sup> × Σ (y<sub>i</sub> - ȳ)<sup>2</sup>)
+///
+/// over the last _period_ pairs. While either series is constant over the window the
+/// correlation is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of pairs (integer greater than 1). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::RollingCorrelation;
+/// use tars::Nexta;
+///
+/// let mut corr = RollingCorrelation::new(3).unwrap();
+///
+/// assert_eq!(corr.nexta((1.0, 2.0)), 0.0);
+/// assert_eq!(corr.nexta((2.0, 4.0)), 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Pearson correlation coefficient, Wikipedia](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient)
+///
+#[doc(alias = "CORR")]
+#[doc(alias = "Pearson")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingCorrelation {
+    moments: RollingCoMoments,
+}
+
+impl RollingCorrelation {
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            moments: RollingCoMoments::new(period)?,
+        })
+    }
+}
+
+impl Period for RollingCorrelation {
+    fn period(&self) -> usize {
+        self.moments.period()
+    }
+}
+
+impl Nexta<(f64, f64)> for RollingCorrelation {
+    type Output = f64;
+
+    fn nexta(&mut self, (x, y): (f64, f64)) -> Self::Output {
+        self.moments.push(x, y);
+        self.moments.correlation()
+    }
+}
+
+impl Reset for RollingCorrelation {
+    fn reset(&mut self) {
+        self.moments.reset();
+    }
+}
+
+impl Default for RollingCorrelation {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for RollingCorrelation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CORR({})", self.moments.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingCorrelation::new(1).is_err());
+        assert!(RollingCorrelation::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut corr = RollingCorrelation::new(3).unwrap();
+
+        assert_eq!(corr.nexta((1.0, 2.0)), 0.0);
+        assert_eq!(round(corr.nexta((2.0, 4.0))), 1.0);
+        assert_eq!(round(corr.nexta((3.0, 5.0))), 0.982);
+        // (1, 2) leaves the window
+        assert_eq!(round(corr.nexta((4.0, 1.0))), -0.721);
+    }
+
+    #[test]
+    fn test_next_opposite() {
+        let mut corr = RollingCorrelation::new(4).unwrap();
+
+        let mut out = 0.0;
+        for &x in &[0.01, -0.02, 0.03, 0.0, -0.01, 0.02] {
+            out = corr.nexta((x, -2.0 * x));
+        }
+        assert_eq!(round(out), -1.0);
+    }
+
+    #[test]
+    fn test_constant_series() {
+        let mut corr = RollingCorrelation::new(3).unwrap();
+
+        corr.nexta((1.0, 5.0));
+        corr.nexta((2.0, 5.0));
+        assert_eq!(corr.nexta((3.0, 5.0)), 0.0);
+    }
+
+    #[test]
+    fn test_constant_after_varying() {
+        let mut corr = RollingCorrelation::new(3).unwrap();
+
+        for &(x, y) in &[(0.013, 0.1), (0.029, 0.3), (0.3, 0.129), (0.429, 0.113)] {
+            corr.nexta((x, y));
+        }
+        corr.nexta((0.429, 0.142));
+        assert_eq!(corr.nexta((0.429, 0.442)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut corr = RollingCorrelation::new(3).unwrap();
+
+        corr.nexta((1.0, 2.0));
+        corr.nexta((2.0, 4.0));
+        corr.reset();
+        assert_eq!(corr.nexta((3.0, 1.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingCorrelation::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let corr = RollingCorrelation::new(60).unwrap();
+        assert_eq!(format!("{}", corr), "CORR(60)");
+    }
+}
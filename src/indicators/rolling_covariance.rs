@@ -0,0 +1,176 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RollingCoMoments, VarianceMode};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling covariance of two series.
+///
+/// Returns the covariance of the last _period_ pairs of values. Every input is a pair of values
+/// _(X, Y)_, e.g. the returns of two assets of a portfolio. The covariance is accumulated the
+/// same way as in [RollingCorrelation](struct.RollingCorrelation.html) and
+/// [RollingBeta](struct.RollingBeta.html).
+///
+/// # Formula
+///
+/// Cov = Σ (x<sub>i</sub> - x̄)(y<sub>i</sub> - ȳ) / n
+///
+/// Where _n_ is the number of pairs in the window in the population mode, and the number of
+/// pairs minus 1 in the sample mode. With a single pair the sample covariance is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of pairs (integer greater than 0). Default is 20.
+/// * _mode_ - [VarianceMode](enum.VarianceMode.html). Default is population.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{RollingCovariance, VarianceMode};
+/// use tars::Nexta;
+///
+/// let mut cov = RollingCovariance::new(3, VarianceMode::Population).unwrap();
+/// assert_eq!(cov.nexta((1.0, 2.0)), 0.0);
+/// assert_eq!(cov.nexta((2.0, 4.0)), 0.5);
+///
+/// let mut cov = RollingCovariance::new(3, VarianceMode::Sample).unwrap();
+/// assert_eq!(cov.nexta((1.0, 2.0)), 0.0);
+/// assert_eq!(cov.nexta((2.0, 4.0)), 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Covariance, Wikipedia](https://en.wikipedia.org/wiki/Covariance)
+///
+#[doc(alias = "COV")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingCovariance {
+    mode: VarianceMode,
+    moments: RollingCoMoments,
+}
+
+impl RollingCovariance {
+    pub fn new(period: usize, mode: VarianceMode) -> Result<Self> {
+        Ok(Self {
+            mode,
+            moments: RollingCoMoments::new(period)?,
+        })
+    }
+
+    pub fn mode(&self) -> VarianceMode {
+        self.mode
+    }
+}
+
+impl Period for RollingCovariance {
+    fn period(&self) -> usize {
+        self.moments.period()
+    }
+}
+
+impl Nexta<(f64, f64)> for RollingCovariance {
+    type Output = f64;
+
+    fn nexta(&mut self, (x, y): (f64, f64)) -> Self::Output {
+        self.moments.push(x, y);
+
+        let count = self.moments.count();
+        let divisor = match self.mode {
+            VarianceMode::Population => count,
+            VarianceMode::Sample if count > 1 => count - 1,
+            VarianceMode::Sample => return 0.0,
+        };
+        self.moments.c_xy() / divisor as f64
+    }
+}
+
+impl Reset for RollingCovariance {
+    fn reset(&mut self) {
+        self.moments.reset();
+    }
+}
+
+impl Default for RollingCovariance {
+    fn default() -> Self {
+        Self::new(20, VarianceMode::Population).unwrap()
+    }
+}
+
+impl fmt::Display for RollingCovariance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mode {
+            VarianceMode::Population => write!(f, "COV({})", self.moments.period()),
+            VarianceMode::Sample => write!(f, "COV({}, SAMPLE)", self.moments.period()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingCovariance::new(0, VarianceMode::Population).is_err());
+        assert!(RollingCovariance::new(1, VarianceMode::Sample).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cov = RollingCovariance::new(3, VarianceMode::Population).unwrap();
+
+        assert_eq!(cov.nexta((1.0, 2.0)), 0.0);
+        assert_eq!(round(cov.nexta((2.0, 4.0))), 0.5);
+        assert_eq!(round(cov.nexta((3.0, 5.0))), 1.0);
+        // (1, 2) leaves the window
+        assert_eq!(round(cov.nexta((4.0, 1.0))), -1.0);
+    }
+
+    #[test]
+    fn test_constant_after_varying() {
+        let mut cov = RollingCovariance::new(3, VarianceMode::Population).unwrap();
+
+        for &(x, y) in &[(0.013, 0.1), (0.029, 0.3), (0.3, 0.129), (0.429, 0.113)] {
+            cov.nexta((x, y));
+        }
+        cov.nexta((0.429, 0.142));
+        assert_eq!(cov.nexta((0.429, 0.442)), 0.0);
+    }
+
+    #[test]
+    fn test_next_sample() {
+        let mut cov = RollingCovariance::new(3, VarianceMode::Sample).unwrap();
+
+        assert_eq!(cov.nexta((1.0, 2.0)), 0.0);
+        assert_eq!(round(cov.nexta((2.0, 4.0))), 1.0);
+        assert_eq!(round(cov.nexta((3.0, 5.0))), 1.5);
+        assert_eq!(round(cov.nexta((4.0, 1.0))), -1.5);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cov = RollingCovariance::new(3, VarianceMode::Sample).unwrap();
+
+        cov.nexta((1.0, 2.0));
+        cov.nexta((2.0, 4.0));
+        cov.reset();
+        assert_eq!(cov.nexta((3.0, 1.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingCovariance::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cov = RollingCovariance::new(5, VarianceMode::Population).unwrap();
+        assert_eq!(format!("{}", cov), "COV(5)");
+        let cov = RollingCovariance::new(5, VarianceMode::Sample).unwrap();
+        assert_eq!(format!("{}", cov), "COV(5, SAMPLE)");
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling mean absolute error (MAE).
+///
+/// Measures the accuracy of a prediction over the last _period_ bars. Every input is a pair
+/// _(prediction, actual)_, e.g. the forecast of a signal model made for a bar and the value
+/// observed on it. The error is in the units of the series, and unlike the
+/// [RMSE](struct.RollingRmse.html) it weights all the errors equally.
+///
+/// # Formula
+///
+/// MAE = Σ |a<sub>i</sub> - p<sub>i</sub>| / n
+///
+/// Where _p<sub>i</sub>_ and _a<sub>i</sub>_ are the predicted and the actual values of the last
+/// _n_ pairs.
+///
+/// # Parameters
+///
+/// * _period_ - number of pairs (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::RollingMae;
+/// use tars::Nexta;
+///
+/// let mut mae = RollingMae::new(3).unwrap();
+///
+/// assert_eq!(mae.nexta((1.0, 2.0)), 1.0);
+/// assert_eq!(mae.nexta((2.0, 2.0)), 0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Mean absolute error, Wikipedia](https://en.wikipedia.org/wiki/Mean_absolute_error)
+///
+#[doc(alias = "MAE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingMae {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum: f64,
+    deque: Box<[f64]>,
+}
+
+impl RollingMae {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum: 0.0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for RollingMae {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<(f64, f64)> for RollingMae {
+    type Output = f64;
+
+    fn nexta(&mut self, (prediction, actual): (f64, f64)) -> Self::Output {
+        let error = (actual - prediction).abs();
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            self.sum -= self.deque[self.index];
+        }
+        self.sum += error;
+        if self.sum < 0.0 {
+            self.sum = 0.0;
+        }
+
+        self.deque[self.index] = error;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        self.sum / self.count as f64
+    }
+}
+
+impl Reset for RollingMae {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for RollingMae {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for RollingMae {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MAE({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingMae::new(0).is_err());
+        assert!(RollingMae::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mae = RollingMae::new(3).unwrap();
+
+        assert_eq!(mae.nexta((1.0, 2.0)), 1.0);
+        assert_eq!(mae.nexta((2.0, 2.0)), 0.5);
+        assert_eq!(round(mae.nexta((4.0, 1.0))), 1.333);
+        // (1, 2) leaves the window
+        assert_eq!(mae.nexta((0.0, 3.0)), 2.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mae = RollingMae::new(3).unwrap();
+
+        mae.nexta((1.0, 2.0));
+        mae.reset();
+        assert_eq!(mae.nexta((2.0, 2.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingMae::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let mae = RollingMae::new(50).unwrap();
+        assert_eq!(format!("{}", mae), "MAE(50)");
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling mean absolute percentage error (MAPE).
+///
+/// Measures the accuracy of a prediction over the last _period_ bars relative to the actual
+/// values, so that it can be compared across series of different scales. Every input is a pair
+/// _(prediction, actual)_.
+///
+/// # Formula
+///
+/// MAPE = Σ |a<sub>i</sub> - p<sub>i</sub>| / |a<sub>i</sub>| / n × 100
+///
+/// Where _p<sub>i</sub>_ and _a<sub>i</sub>_ are the predicted and the actual values of the last
+/// _period_ pairs. The pairs with an actual value of 0 are left out, and _n_ is the number of
+/// the remaining ones. When no pair is left the MAPE is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of pairs (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::RollingMape;
+/// use tars::Nexta;
+///
+/// let mut mape = RollingMape::new(3).unwrap();
+///
+/// assert_eq!(mape.nexta((1.0, 2.0)), 50.0);
+/// assert_eq!(mape.nexta((2.0, 2.0)), 25.0);
+/// ```
+///
+/// # Links
+///
+/// * [Mean absolute percentage error, Wikipedia](https://en.wikipedia.org/wiki/Mean_absolute_percentage_error)
+///
+#[doc(alias = "MAPE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingMape {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum: f64,
+    // number of pairs in the window with a non-zero actual value
+    valid: usize,
+    deque: Box<[Option<f64>]>,
+}
+
+impl RollingMape {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum: 0.0,
+                valid: 0,
+                deque: vec![None; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for RollingMape {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<(f64, f64)> for RollingMape {
+    type Output = f64;
+
+    fn nexta(&mut self, (prediction, actual): (f64, f64)) -> Self::Output {
+        let pct = if actual != 0.0 {
+            Some((actual - prediction).abs() / actual.abs() * 100.0)
+        } else {
+            None
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        } else if let Some(old_pct) = self.deque[self.index] {
+            self.sum -= old_pct;
+            self.valid -= 1;
+        }
+        if let Some(pct) = pct {
+            self.sum += pct;
+            self.valid += 1;
+        }
+        if self.sum < 0.0 || self.valid == 0 {
+            self.sum = 0.0;
+        }
+
+        self.deque[self.index] = pct;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.valid > 0 {
+            self.sum / self.valid as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Reset for RollingMape {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        self.valid = 0;
+        for i in 0..self.period {
+            self.deque[i] = None;
+        }
+    }
+}
+
+impl Default for RollingMape {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for RollingMape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MAPE({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingMape::new(0).is_err());
+        assert!(RollingMape::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mape = RollingMape::new(3).unwrap();
+
+        assert_eq!(mape.nexta((1.0, 2.0)), 50.0);
+        assert_eq!(mape.nexta((2.0, 2.0)), 25.0);
+        assert_eq!(round(mape.nexta((4.0, 1.0))), 116.667);
+        // (1, 2) leaves the window, (0, 0) is left out
+        assert_eq!(round(mape.nexta((0.0, 0.0))), 150.0);
+        assert_eq!(round(mape.nexta((1.0, 0.0))), 300.0);
+    }
+
+    #[test]
+    fn test_zero_actual_values() {
+        let mut mape = RollingMape::new(2).unwrap();
+
+        assert_eq!(mape.nexta((1.0, 0.0)), 0.0);
+        assert_eq!(mape.nexta((3.0, -2.0)), 250.0);
+        assert_eq!(mape.nexta((2.0, 0.0)), 250.0);
+        assert_eq!(mape.nexta((1.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mape = RollingMape::new(3).unwrap();
+
+        mape.nexta((1.0, 2.0));
+        mape.reset();
+        assert_eq!(mape.nexta((2.0, 2.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingMape::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let mape = RollingMape::new(50).unwrap();
+        assert_eq!(format!("{}", mape), "MAPE(50)");
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling mutual information of two series.
+///
+/// Measures how much knowing one series tells about the other over the last _period_ pairs of
+/// values. Unlike correlation it also captures non-linear dependencies: it is 0 for independent
+/// series and grows with the strength of any relationship between them. Every input is a pair of
+/// values _(X, Y)_, e.g. the returns of two instruments or a feature and a target.
+///
+/// The estimate is binned: the range of each series in the window is divided into _bins_ equal
+/// intervals, and the probabilities are the relative frequencies of the values and the pairs in
+/// the intervals. The histograms are rebuilt every bar, which takes O(_period_ + _bins_²).
+///
+/// # Formula
+///
+/// MI = Σ p(x, y) × ln(p(x, y) / (p(x) × p(y)))
+///
+/// over all pairs of bins with p(x, y) > 0. The result is in nats; it is at most ln(_bins_).
+/// When a series is constant over the window all its values fall in one bin and MI is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of pairs (integer greater than 1). Default is 20.
+/// * _bins_ - number of bins per series (integer greater than 1). Default is 4.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::RollingMutualInformation;
+/// use tars::Nexta;
+///
+/// let mut mi = RollingMutualInformation::new(4, 2).unwrap();
+///
+/// mi.nexta((1.0, 1.0));
+/// mi.nexta((2.0, 4.0));
+/// mi.nexta((3.0, 9.0));
+/// let out = mi.nexta((4.0, 16.0));
+/// assert_eq!(out, 2f64.ln());
+/// ```
+///
+/// # Links
+///
+/// * [Mutual information, Wikipedia](https://en.wikipedia.org/wiki/Mutual_information)
+///
+#[doc(alias = "MI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingMutualInformation {
+    period: usize,
+    bins: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[(f64, f64)]>,
+}
+
+impl RollingMutualInformation {
+    pub fn new(period: usize, bins: usize) -> Result<Self> {
+        if period < 2 || bins < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            period,
+            bins,
+            index: 0,
+            count: 0,
+            deque: vec![(0.0, 0.0); period].into_boxed_slice(),
+        })
+    }
+
+    pub fn bins(&self) -> usize {
+        self.bins
+    }
+
+    fn mutual_information(&self) -> f64 {
+        let values = &self.deque[..self.count];
+        let x_bins = Bins::new(values.iter().map(|v| v.0), self.bins);
+        let y_bins = Bins::new(values.iter().map(|v| v.1), self.bins);
+
+        let mut joint = vec![0usize; self.bins * self.bins];
+        let mut x_counts = vec![0usize; self.bins];
+        let mut y_counts = vec![0usize; self.bins];
+        for &(x, y) in values {
+            let i = x_bins.index(x);
+            let j = y_bins.index(y);
+            joint[i * self.bins + j] += 1;
+            x_counts[i] += 1;
+            y_counts[j] += 1;
+        }
+
+        let n = self.count as f64;
+        let mut mi = 0.0;
+        for (k, &count) in joint.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let p_xy = count as f64 / n;
+            let p_x = x_counts[k / self.bins] as f64 / n;
+            let p_y = y_counts[k % self.bins] as f64 / n;
+            mi += p_xy * (p_xy / (p_x * p_y)).ln();
+        }
+
+        mi.max(0.0)
+    }
+}
+
+/// Equal-width bins spanning the range of a set of values.
+struct Bins {
+    min: f64,
+    width: f64,
+    count: usize,
+}
+
+impl Bins {
+    fn new<I: Iterator<Item = f64>>(values: I, count: usize) -> Self {
+        let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+        Self {
+            min,
+            width: (max - min) / count as f64,
+            count,
+        }
+    }
+
+    fn index(&self, value: f64) -> usize {
+        if self.width > 0.0 {
+            (((value - self.min) / self.width) as usize).min(self.count - 1)
+        } else {
+            0
+        }
+    }
+}
+
+impl Period for RollingMutualInformation {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<(f64, f64)> for RollingMutualInformation {
+    type Output = f64;
+
+    fn nexta(&mut self, input: (f64, f64)) -> Self::Output {
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        self.mutual_information()
+    }
+}
+
+impl Reset for RollingMutualInformation {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = (0.0, 0.0);
+        }
+    }
+}
+
+impl Default for RollingMutualInformation {
+    fn default() -> Self {
+        Self::new(20, 4).unwrap()
+    }
+}
+
+impl fmt::Display for RollingMutualInformation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MI({}, {})", self.period, self.bins)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingMutualInformation::new(1, 4).is_err());
+        assert!(RollingMutualInformation::new(20, 1).is_err());
+        assert!(RollingMutualInformation::new(2, 2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mi = RollingMutualInformation::new(4, 2).unwrap();
+
+        assert_eq!(mi.nexta((1.0, 1.0)), 0.0);
+        assert_eq!(round(mi.nexta((2.0, 2.0))), 0.693);
+        assert_eq!(round(mi.nexta((1.0, 2.0))), 0.174);
+        // independent: every pair of bins once
+        assert_eq!(round(mi.nexta((2.0, 1.0))), 0.0);
+        // (1, 1) leaves the window
+        assert_eq!(round(mi.nexta((1.0, 3.0))), 0.216);
+    }
+
+    #[test]
+    fn test_next_non_linear() {
+        let mut mi = RollingMutualInformation::new(8, 2).unwrap();
+
+        // y = x² is uncorrelated with x, two bins are too coarse to see the dependency
+        let mut out = 0.0;
+        for &x in &[-2.0, -1.0, 1.0, 2.0, -2.0, -1.0, 1.0, 2.0] {
+            out = mi.nexta((x, x * x));
+        }
+        assert_eq!(round(out), 0.0);
+
+        let mut mi = RollingMutualInformation::new(8, 4).unwrap();
+        for &x in &[-2.0, -1.0, 1.0, 2.0, -2.0, -1.0, 1.0, 2.0] {
+            out = mi.nexta((x, x * x));
+        }
+        assert_eq!(round(out), 0.347);
+    }
+
+    #[test]
+    fn test_constant_series() {
+        let mut mi = RollingMutualInformation::new(3, 2).unwrap();
+
+        mi.nexta((1.0, 5.0));
+        mi.nexta((2.0, 5.0));
+        assert_eq!(mi.nexta((3.0, 5.0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mi = RollingMutualInformation::new(4, 2).unwrap();
+
+        mi.nexta((1.0, 1.0));
+        mi.nexta((2.0, 2.0));
+        mi.reset();
+        assert_eq!(mi.nexta((2.0, 2.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingMutualInformation::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let mi = RollingMutualInformation::new(50, 8).unwrap();
+        assert_eq!(format!("{}", mi), "MI(50, 8)");
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling root mean square error (RMSE).
+///
+/// Measures the accuracy of a prediction over the last _period_ bars. Every input is a pair
+/// _(prediction, actual)_. The error is in the units of the series; squaring the errors makes it
+/// punish large misses more than the [MAE](struct.RollingMae.html) does.
+///
+/// # Formula
+///
+/// RMSE = √(Σ (a<sub>i</sub> - p<sub>i</sub>)<sup>2</sup> / n)
+///
+/// Where _p<sub>i</sub>_ and _a<sub>i</sub>_ are the predicted and the actual values of the last
+/// _n_ pairs.
+///
+/// # Parameters
+///
+/// * _period_ - number of pairs (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::RollingRmse;
+/// use tars::Nexta;
+///
+/// let mut rmse = RollingRmse::new(2).unwrap();
+///
+/// assert_eq!(rmse.nexta((1.0, 4.0)), 3.0);
+/// assert_eq!(rmse.nexta((5.0, 4.0)), 5f64.sqrt());
+/// ```
+///
+/// # Links
+///
+/// * [Root-mean-square deviation, Wikipedia](https://en.wikipedia.org/wiki/Root-mean-square_deviation)
+///
+#[doc(alias = "RMSE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingRmse {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum: f64,
+    deque: Box<[f64]>,
+}
+
+impl RollingRmse {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum: 0.0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for RollingRmse {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<(f64, f64)> for RollingRmse {
+    type Output = f64;
+
+    fn nexta(&mut self, (prediction, actual): (f64, f64)) -> Self::Output {
+        let error = actual - prediction;
+        let squared = error * error;
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            self.sum -= self.deque[self.index];
+        }
+        self.sum += squared;
+        if self.sum < 0.0 {
+            self.sum = 0.0;
+        }
+
+        self.deque[self.index] = squared;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        (self.sum / self.count as f64).sqrt()
+    }
+}
+
+impl Reset for RollingRmse {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for RollingRmse {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for RollingRmse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RMSE({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingRmse::new(0).is_err());
+        assert!(RollingRmse::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rmse = RollingRmse::new(3).unwrap();
+
+        assert_eq!(rmse.nexta((1.0, 2.0)), 1.0);
+        assert_eq!(round(rmse.nexta((2.0, 2.0))), 0.707);
+        assert_eq!(round(rmse.nexta((4.0, 1.0))), 1.826);
+        // (1, 2) leaves the window
+        assert_eq!(round(rmse.nexta((0.0, 3.0))), 2.449);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rmse = RollingRmse::new(3).unwrap();
+
+        rmse.nexta((1.0, 2.0));
+        rmse.reset();
+        assert_eq!(rmse.nexta((2.0, 2.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingRmse::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rmse = RollingRmse::new(50).unwrap();
+        assert_eq!(format!("{}", rmse), "RMSE(50)");
+    }
+}
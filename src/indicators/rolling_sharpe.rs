@@ -0,0 +1,217 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::StandardDeviation as Sd;
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling Sharpe ratio.
+///
+/// Relates the mean excess return over the risk-free rate to the standard deviation of the
+/// returns over a rolling window, so that live systems can monitor their risk-adjusted
+/// performance bar by bar. The inputs are returns, not prices.
+///
+/// # Formula
+///
+/// Sharpe = mean(r<sub>i</sub> - _risk free_) / σ × √_annualization_
+///
+/// Where _σ_ is the [standard deviation](struct.StandardDeviation.html) of the last _period_
+/// returns. When _σ_ is 0, i.e. all the returns in the window are equal, the ratio is infinite
+/// with the sign of the excess return, or 0 if the returns equal the risk-free rate, like the
+/// [Sortino ratio](struct.RollingSortino.html).
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 0). Default is 20.
+/// * _risk_free_ - risk-free rate per period, in the units of the returns (number). Default is 0.
+/// * _annualization_ - number of periods per year, e.g. 252 for daily returns, or 1 to keep the
+///   ratio per period (number greater than 0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::RollingSharpe;
+/// use tars::Nexta;
+///
+/// // returns in percent, not annualized
+/// let mut sharpe = RollingSharpe::new(2, 0.0, 1.0).unwrap();
+///
+/// assert_eq!(sharpe.nexta(1.0), f64::INFINITY);
+/// assert_eq!(sharpe.nexta(3.0), 2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Sharpe ratio, Wikipedia](https://en.wikipedia.org/wiki/Sharpe_ratio)
+///
+#[doc(alias = "Sharpe")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingSharpe {
+    risk_free: f64,
+    annualization: f64,
+    sd: Sd,
+    count: usize,
+    // number of consecutive equal returns, σ of a window of equal returns is exactly 0
+    prev: Option<f64>,
+    run: usize,
+}
+
+impl RollingSharpe {
+    pub fn new(period: usize, risk_free: f64, annualization: f64) -> Result<Self> {
+        if !(risk_free.is_finite() && annualization.is_finite() && annualization > 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            risk_free,
+            annualization,
+            sd: Sd::new(period)?,
+            count: 0,
+            prev: None,
+            run: 0,
+        })
+    }
+
+    pub fn risk_free(&self) -> f64 {
+        self.risk_free
+    }
+
+    pub fn annualization(&self) -> f64 {
+        self.annualization
+    }
+}
+
+impl Period for RollingSharpe {
+    fn period(&self) -> usize {
+        self.sd.period()
+    }
+}
+
+impl Nexta<f64> for RollingSharpe {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        self.run = match self.prev {
+            Some(prev) if prev == input => self.run + 1,
+            _ => 1,
+        };
+        self.prev = Some(input);
+        if self.count < self.sd.period() {
+            self.count += 1;
+        }
+
+        let sd = self.sd.nexta(input);
+        if self.run >= self.count {
+            let excess = input - self.risk_free;
+            if excess > 0.0 {
+                f64::INFINITY
+            } else if excess < 0.0 {
+                f64::NEG_INFINITY
+            } else {
+                0.0
+            }
+        } else {
+            (self.sd.mean() - self.risk_free) / sd * self.annualization.sqrt()
+        }
+    }
+}
+
+impl Reset for RollingSharpe {
+    fn reset(&mut self) {
+        self.sd.reset();
+        self.count = 0;
+        self.prev = None;
+        self.run = 0;
+    }
+}
+
+impl Default for RollingSharpe {
+    fn default() -> Self {
+        Self::new(20, 0.0, 252.0).unwrap()
+    }
+}
+
+impl fmt::Display for RollingSharpe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SHARPE({}, {}, {})",
+            self.sd.period(),
+            self.risk_free,
+            self.annualization
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingSharpe::new(0, 0.0, 252.0).is_err());
+        assert!(RollingSharpe::new(20, f64::NAN, 252.0).is_err());
+        assert!(RollingSharpe::new(20, 0.0, 0.0).is_err());
+        assert!(RollingSharpe::new(20, 0.0, f64::INFINITY).is_err());
+        assert!(RollingSharpe::new(1, -0.01, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sharpe = RollingSharpe::new(3, 0.0, 1.0).unwrap();
+
+        assert_eq!(sharpe.nexta(0.01), f64::INFINITY);
+        assert_eq!(round(sharpe.nexta(0.03)), 2.0);
+        assert_eq!(round(sharpe.nexta(-0.01)), 0.612);
+        assert_eq!(round(sharpe.nexta(0.05)), 0.935);
+    }
+
+    #[test]
+    fn test_next_risk_free_annualized() {
+        let mut sharpe = RollingSharpe::new(3, 0.01, 4.0).unwrap();
+
+        assert_eq!(sharpe.nexta(0.01), 0.0);
+        assert_eq!(round(sharpe.nexta(0.03)), 2.0);
+        assert_eq!(round(sharpe.nexta(-0.01)), 0.0);
+        assert_eq!(round(sharpe.nexta(-0.02)), -0.926);
+    }
+
+    #[test]
+    fn test_constant_returns() {
+        let mut sharpe = RollingSharpe::new(3, 0.0, 1.0).unwrap();
+
+        for &r in &[0.013, -0.029, 0.3, 0.003, 0.003] {
+            sharpe.nexta(r);
+        }
+        assert_eq!(sharpe.nexta(0.003), f64::INFINITY);
+
+        let mut sharpe = RollingSharpe::new(2, 0.003, 1.0).unwrap();
+        sharpe.nexta(0.013);
+        sharpe.nexta(0.003);
+        assert_eq!(sharpe.nexta(0.003), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sharpe = RollingSharpe::new(3, 0.0, 1.0).unwrap();
+
+        sharpe.nexta(0.01);
+        sharpe.nexta(0.03);
+        sharpe.reset();
+        assert_eq!(sharpe.nexta(-0.03), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingSharpe::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let sharpe = RollingSharpe::new(60, 0.0001, 252.0).unwrap();
+        assert_eq!(format!("{}", sharpe), "SHARPE(60, 0.0001, 252)");
+    }
+}
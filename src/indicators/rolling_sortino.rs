@@ -0,0 +1,192 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{DownsideDeviation, SimpleMovingAverage as Sma};
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling Sortino ratio.
+///
+/// Like the [Sharpe ratio](struct.RollingSharpe.html), but divides the mean excess return by
+/// the [downside deviation](struct.DownsideDeviation.html) instead of the standard deviation, so
+/// that only the returns below the target count as risk. The inputs are returns, not prices.
+///
+/// # Formula
+///
+/// Sortino = (mean(r<sub>i</sub>) - _target_) / DD × √_annualization_
+///
+/// Where _DD_ is the downside deviation of the last _period_ returns below _target_. When there
+/// is no return below the target the ratio is infinite, or 0 if the mean return is not above
+/// the target either.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 0). Default is 20.
+/// * _target_ - minimum acceptable return per period, in the units of the returns (number).
+///   Default is 0.
+/// * _annualization_ - number of periods per year, e.g. 252 for daily returns, or 1 to keep the
+///   ratio per period (number greater than 0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::RollingSortino;
+/// use tars::Nexta;
+///
+/// // returns in percent, not annualized
+/// let mut sortino = RollingSortino::new(4, 0.0, 1.0).unwrap();
+///
+/// assert_eq!(sortino.nexta(4.0), f64::INFINITY);
+/// sortino.nexta(4.0);
+/// sortino.nexta(4.0);
+/// assert_eq!(sortino.nexta(-2.0), 2.5);
+/// ```
+///
+/// # Links
+///
+/// * [Sortino ratio, Wikipedia](https://en.wikipedia.org/wiki/Sortino_ratio)
+///
+#[doc(alias = "Sortino")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingSortino {
+    annualization: f64,
+    mean: Sma,
+    dd: DownsideDeviation,
+}
+
+impl RollingSortino {
+    pub fn new(period: usize, target: f64, annualization: f64) -> Result<Self> {
+        if !(annualization.is_finite() && annualization > 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            annualization,
+            mean: Sma::new(period)?,
+            dd: DownsideDeviation::new(period, target)?,
+        })
+    }
+
+    pub fn target(&self) -> f64 {
+        self.dd.target()
+    }
+
+    pub fn annualization(&self) -> f64 {
+        self.annualization
+    }
+}
+
+impl Period for RollingSortino {
+    fn period(&self) -> usize {
+        self.dd.period()
+    }
+}
+
+impl Nexta<f64> for RollingSortino {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let excess = self.mean.nexta(input) - self.dd.target();
+        let dd = self.dd.nexta(input);
+
+        if dd > 0.0 {
+            excess / dd * self.annualization.sqrt()
+        } else if excess > 0.0 {
+            f64::INFINITY
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Reset for RollingSortino {
+    fn reset(&mut self) {
+        self.mean.reset();
+        self.dd.reset();
+    }
+}
+
+impl Default for RollingSortino {
+    fn default() -> Self {
+        Self::new(20, 0.0, 252.0).unwrap()
+    }
+}
+
+impl fmt::Display for RollingSortino {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SORTINO({}, {}, {})",
+            self.dd.period(),
+            self.dd.target(),
+            self.annualization
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingSortino::new(0, 0.0, 252.0).is_err());
+        assert!(RollingSortino::new(20, f64::NAN, 252.0).is_err());
+        assert!(RollingSortino::new(20, 0.0, 0.0).is_err());
+        assert!(RollingSortino::new(1, 0.01, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sortino = RollingSortino::new(3, 0.0, 1.0).unwrap();
+
+        assert_eq!(sortino.nexta(0.02), f64::INFINITY);
+        assert_eq!(round(sortino.nexta(-0.03)), -0.236);
+        assert_eq!(round(sortino.nexta(-0.04)), -0.577);
+        // 0.02 leaves the window
+        assert_eq!(round(sortino.nexta(0.01)), -0.693);
+        assert_eq!(round(sortino.nexta(0.05)), 0.289);
+    }
+
+    #[test]
+    fn test_next_shortfalls_leave_window() {
+        let mut sortino = RollingSortino::new(3, 0.0, 1.0).unwrap();
+
+        for &x in &[-0.013, -0.029, -0.017, -0.3, 0.01, 0.02] {
+            sortino.nexta(x);
+        }
+        assert_eq!(sortino.nexta(0.03), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_next_target_annualized() {
+        let mut sortino = RollingSortino::new(2, 0.01, 4.0).unwrap();
+
+        assert_eq!(sortino.nexta(0.01), 0.0);
+        // mean 0, shortfalls 0 and -0.02
+        assert_eq!(round(sortino.nexta(-0.01)), -1.414);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sortino = RollingSortino::new(3, 0.0, 1.0).unwrap();
+
+        sortino.nexta(-0.03);
+        sortino.reset();
+        assert_eq!(sortino.nexta(0.01), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingSortino::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let sortino = RollingSortino::new(60, 0.0001, 252.0).unwrap();
+        assert_eq!(format!("{}", sortino), "SORTINO(60, 0.0001, 252)");
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{ExponentialMovingAverage as Ema, FastStochastic};
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Schaff Trend Cycle (STC).
+///
+/// Developed by Doug Schaff, the indicator passes the MACD line through two rounds of a
+/// smoothed stochastic calculation. The result oscillates between 0 and 100 and reacts faster
+/// than MACD; values above 75 are usually considered overbought and values below 25 oversold.
+///
+/// # Formula
+///
+/// * _MACD_ = EMA(_fast period_) - EMA(_slow period_)
+/// * _%D1_ = EMA(_smoothing_) of Fast Stochastic(_cycle_) of MACD
+/// * _STC_ = EMA(_smoothing_) of Fast Stochastic(_cycle_) of %D1
+///
+/// The smoothing EMA with period 3 corresponds to the smoothing factor 0.5 of the original
+/// formula. When the stochastic window is flat its value is 50.
+///
+/// # Parameters
+///
+/// * _fast_period_ - period of the fast EMA (integer greater than 0). Default is 23.
+/// * _slow_period_ - period of the slow EMA (integer greater than 0). Default is 50.
+/// * _cycle_ - stochastic window (integer greater than 0). Default is 10.
+/// * _smoothing_ - period of the smoothing EMAs (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::SchaffTrendCycle;
+/// use tars::Nexta;
+///
+/// let mut stc = SchaffTrendCycle::new(2, 4, 3, 3).unwrap();
+///
+/// assert_eq!(stc.nexta(10.0), 50.0);
+/// assert_eq!(stc.nexta(12.0), 75.0);
+/// assert_eq!(stc.nexta(15.0), 87.5);
+/// ```
+///
+#[doc(alias = "STC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SchaffTrendCycle {
+    cycle: usize,
+    smoothing: usize,
+    fast_ema: Ema,
+    slow_ema: Ema,
+    macd_stoch: FastStochastic,
+    macd_smoothing: Ema,
+    stoch: FastStochastic,
+    stoch_smoothing: Ema,
+}
+
+impl SchaffTrendCycle {
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        cycle: usize,
+        smoothing: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            cycle,
+            smoothing,
+            fast_ema: Ema::new(fast_period)?,
+            slow_ema: Ema::new(slow_period)?,
+            macd_stoch: FastStochastic::new(cycle)?,
+            macd_smoothing: Ema::new(smoothing)?,
+            stoch: FastStochastic::new(cycle)?,
+            stoch_smoothing: Ema::new(smoothing)?,
+        })
+    }
+}
+
+impl Nexta<f64> for SchaffTrendCycle {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let macd = self.fast_ema.nexta(input) - self.slow_ema.nexta(input);
+        let d1 = self.macd_smoothing.nexta(self.macd_stoch.nexta(macd));
+        self.stoch_smoothing.nexta(self.stoch.nexta(d1))
+    }
+}
+
+impl<T: Close> Nexta<&T> for SchaffTrendCycle {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for SchaffTrendCycle {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.macd_stoch.reset();
+        self.macd_smoothing.reset();
+        self.stoch.reset();
+        self.stoch_smoothing.reset();
+    }
+}
+
+impl Default for SchaffTrendCycle {
+    fn default() -> Self {
+        Self::new(23, 50, 10, 3).unwrap()
+    }
+}
+
+impl fmt::Display for SchaffTrendCycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "STC({}, {}, {}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.cycle,
+            self.smoothing
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(SchaffTrendCycle);
+
+    #[test]
+    fn test_new() {
+        assert!(SchaffTrendCycle::new(0, 50, 10, 3).is_err());
+        assert!(SchaffTrendCycle::new(23, 0, 10, 3).is_err());
+        assert!(SchaffTrendCycle::new(23, 50, 0, 3).is_err());
+        assert!(SchaffTrendCycle::new(23, 50, 10, 0).is_err());
+        assert!(SchaffTrendCycle::new(23, 50, 10, 3).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut stc = SchaffTrendCycle::new(2, 4, 3, 3).unwrap();
+
+        assert_eq!(round(stc.nexta(10.0)), 50.0);
+        assert_eq!(round(stc.nexta(12.0)), 75.0);
+        assert_eq!(round(stc.nexta(15.0)), 87.5);
+        assert_eq!(round(stc.nexta(14.0)), 43.75);
+        assert_eq!(round(stc.nexta(11.0)), 21.875);
+        assert_eq!(round(stc.nexta(9.0)), 10.938);
+        assert_eq!(round(stc.nexta(10.0)), 55.469);
+        assert_eq!(round(stc.nexta(13.0)), 77.734);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut stc = SchaffTrendCycle::new(2, 4, 3, 3).unwrap();
+
+        assert_eq!(round(stc.nexta(&Bar::new().close(10))), 50.0);
+        assert_eq!(round(stc.nexta(&Bar::new().close(12))), 75.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stc = SchaffTrendCycle::new(2, 4, 3, 3).unwrap();
+
+        stc.nexta(10.0);
+        stc.nexta(12.0);
+        stc.reset();
+        assert_eq!(stc.nexta(12.0), 50.0);
+        assert_eq!(stc.nexta(10.0), 25.0);
+    }
+
+    #[test]
+    fn test_default() {
+        SchaffTrendCycle::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let stc = SchaffTrendCycle::default();
+        assert_eq!(format!("{}", stc), "STC(23, 50, 10, 3)");
+    }
+}
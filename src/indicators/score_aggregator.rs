@@ -0,0 +1,247 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Weighted voting aggregator for multiple indicator conditions.
+///
+/// Combines several normalized conditions into a single composite score per bar. Every
+/// condition is expected to be normalized into the range -1..1, where -1 is fully bearish, 1 is
+/// fully bullish and 0 is neutral (e.g. `(rsi - 50.0) / 50.0` or `1.0` / `-1.0` for a crossover).
+/// Values outside of that range are clamped.
+///
+/// Conditions are registered by their weights in the order they are later passed to `nexta`.
+///
+/// # Formula
+///
+/// Score = Σ(w<sub>i</sub> * c<sub>i</sub>) / Σw<sub>i</sub>
+///
+/// Where:
+///
+/// * _w<sub>i</sub>_ - weight of i-th condition
+/// * _c<sub>i</sub>_ - value of i-th condition, clamped to -1..1
+///
+/// When a threshold is configured, the score is also turned into a signal:
+/// [Long](enum.ScoreSignal.html#variant.Long) when score >= threshold,
+/// [Short](enum.ScoreSignal.html#variant.Short) when score <= -threshold and
+/// [Neutral](enum.ScoreSignal.html#variant.Neutral) otherwise.
+///
+/// # Parameters
+///
+/// * _weights_ - non-negative weight of every condition, at least one must be greater than 0.
+/// * _threshold_ - optional signal threshold in the range 0..1.
+///
+/// # Panics
+///
+/// `nexta` panics if the number of conditions differs from the number of registered weights.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{ScoreAggregator, ScoreSignal};
+/// use tars::Nexta;
+///
+/// let mut score = ScoreAggregator::new(&[1.0, 3.0])
+///     .unwrap()
+///     .with_threshold(0.5)
+///     .unwrap();
+///
+/// let rsi = 70.0;
+/// let macd_above_signal = true;
+///
+/// let out = score.nexta(&[(rsi - 50.0) / 50.0, if macd_above_signal { 1.0 } else { -1.0 }]);
+/// assert_eq!(out.score, 0.85);
+/// assert_eq!(out.signal, Some(ScoreSignal::Long));
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ScoreAggregator {
+    weights: Box<[f64]>,
+    total_weight: f64,
+    threshold: Option<f64>,
+}
+
+/// Signal derived from the composite score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreSignal {
+    Long,
+    Neutral,
+    Short,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreAggregatorOutput {
+    pub score: f64,
+    pub signal: Option<ScoreSignal>,
+}
+
+impl ScoreAggregator {
+    pub fn new(weights: &[f64]) -> Result<Self> {
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+        let total_weight: f64 = weights.iter().sum();
+        if total_weight <= 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            weights: weights.into(),
+            total_weight,
+            threshold: None,
+        })
+    }
+
+    /// Enables the signal output with the given threshold.
+    pub fn with_threshold(mut self, threshold: f64) -> Result<Self> {
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(TaError::InvalidParameter);
+        }
+        self.threshold = Some(threshold);
+        Ok(self)
+    }
+
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    pub fn threshold(&self) -> Option<f64> {
+        self.threshold
+    }
+}
+
+impl Nexta<&[f64]> for ScoreAggregator {
+    type Output = ScoreAggregatorOutput;
+
+    fn nexta(&mut self, conditions: &[f64]) -> Self::Output {
+        assert_eq!(
+            conditions.len(),
+            self.weights.len(),
+            "number of conditions must match number of weights"
+        );
+
+        let weighted_sum: f64 = self
+            .weights
+            .iter()
+            .zip(conditions)
+            .map(|(w, c)| w * c.clamp(-1.0, 1.0))
+            .sum();
+        let score = weighted_sum / self.total_weight;
+
+        let signal = self.threshold.map(|threshold| {
+            if score >= threshold {
+                ScoreSignal::Long
+            } else if score <= -threshold {
+                ScoreSignal::Short
+            } else {
+                ScoreSignal::Neutral
+            }
+        });
+
+        ScoreAggregatorOutput { score, signal }
+    }
+}
+
+impl<const N: usize> Nexta<&[f64; N]> for ScoreAggregator {
+    type Output = ScoreAggregatorOutput;
+
+    fn nexta(&mut self, conditions: &[f64; N]) -> Self::Output {
+        self.nexta(&conditions[..])
+    }
+}
+
+impl Reset for ScoreAggregator {
+    fn reset(&mut self) {}
+}
+
+impl fmt::Display for ScoreAggregator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SCORE(")?;
+        for (i, weight) in self.weights.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", weight)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ScoreAggregator::new(&[]).is_err());
+        assert!(ScoreAggregator::new(&[0.0]).is_err());
+        assert!(ScoreAggregator::new(&[1.0, -1.0]).is_err());
+        assert!(ScoreAggregator::new(&[1.0, f64::NAN]).is_err());
+        assert!(ScoreAggregator::new(&[0.0, 1.0]).is_ok());
+
+        let score = ScoreAggregator::new(&[1.0]).unwrap();
+        assert!(score.clone().with_threshold(-0.1).is_err());
+        assert!(score.clone().with_threshold(1.1).is_err());
+        assert!(score.with_threshold(0.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut score = ScoreAggregator::new(&[1.0, 2.0, 1.0]).unwrap();
+
+        let out = score.nexta(&[1.0, 1.0, 1.0]);
+        assert_eq!(out.score, 1.0);
+        assert_eq!(out.signal, None);
+
+        assert_eq!(score.nexta(&[1.0, -1.0, 0.0]).score, -0.25);
+        assert_eq!(score.nexta(&[0.5, 0.5, -1.0]).score, 0.125);
+
+        // values are clamped to -1..1
+        assert_eq!(score.nexta(&[-5.0, -1.0, 0.0]).score, -0.75);
+    }
+
+    #[test]
+    fn test_next_with_threshold() {
+        let mut score = ScoreAggregator::new(&[1.0, 1.0])
+            .unwrap()
+            .with_threshold(0.5)
+            .unwrap();
+
+        assert_eq!(score.nexta(&[1.0, 0.0]).signal, Some(ScoreSignal::Long));
+        assert_eq!(score.nexta(&[0.5, 0.0]).signal, Some(ScoreSignal::Neutral));
+        assert_eq!(score.nexta(&[-0.5, 0.0]).signal, Some(ScoreSignal::Neutral));
+        assert_eq!(score.nexta(&[-1.0, 0.0]).signal, Some(ScoreSignal::Short));
+    }
+
+    #[test]
+    fn test_next_slice() {
+        let mut score = ScoreAggregator::new(&[1.0, 1.0]).unwrap();
+        let conditions = vec![1.0, 0.0];
+        assert_eq!(score.nexta(conditions.as_slice()).score, 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_next_wrong_number_of_conditions() {
+        let mut score = ScoreAggregator::new(&[1.0, 1.0]).unwrap();
+        score.nexta(&[1.0]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut score = ScoreAggregator::new(&[1.0, 1.0]).unwrap();
+        assert_eq!(score.nexta(&[1.0, 0.0]).score, 0.5);
+
+        score.reset();
+        assert_eq!(score.nexta(&[1.0, 0.0]).score, 0.5);
+    }
+
+    #[test]
+    fn test_display() {
+        let score = ScoreAggregator::new(&[1.0, 2.5]).unwrap();
+        assert_eq!(format!("{}", score), "SCORE(1, 2.5)");
+    }
+}
@@ -0,0 +1,274 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Streaming seasonal decomposition.
+///
+/// Splits every value of a series with a fixed seasonal cycle, e.g. the hour of the day on
+/// hourly bars, into a trend, a seasonal and a residual part. Running other indicators on the
+/// deseasonalized series (trend plus residual) keeps recurring intraday patterns from being read
+/// as signals.
+///
+/// It is a lightweight, causal take on STL: the trend is the simple moving average of the last
+/// _season length_ values, which averages a whole cycle away, and the seasonal part of a phase of
+/// the cycle is the mean of its detrended values over the last _cycles_ cycles. Being trailing,
+/// the trend lags a trending series by about half a cycle, and the seasonal parts pick up some of
+/// that lag.
+///
+/// The first input is phase 0 of the cycle, so for hour-of-day seasonality the series should
+/// start at hour 0. Every bar takes O(_season length_).
+///
+/// # Formula
+///
+/// Trend<sub>t</sub> = SMA(_season length_)<sub>t</sub>
+///
+/// S<sub>p</sub> = mean of X - Trend at phase _p_ over the last _cycles_ cycles
+///
+/// Seasonal<sub>t</sub> = S<sub>p</sub> - mean(S), with _p_ the phase of _t_
+///
+/// Residual<sub>t</sub> = X<sub>t</sub> - Trend<sub>t</sub> - Seasonal<sub>t</sub>
+///
+/// The mean of the seasonal parts is taken over the phases seen so far and subtracted, so they
+/// sum to 0 over a cycle.
+///
+/// # Parameters
+///
+/// * _season_length_ - number of bars in a cycle (integer greater than 1). Default is 24.
+/// * _cycles_ - number of cycles the seasonal parts are averaged over (integer greater than 0).
+///   Default is 4.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::SeasonalDecomposition;
+/// use tars::Nexta;
+///
+/// let mut stl = SeasonalDecomposition::new(2, 1).unwrap();
+///
+/// stl.nexta(10.0);
+/// stl.nexta(12.0);
+/// let out = stl.nexta(10.0);
+/// assert_eq!((out.trend, out.seasonal, out.residual), (11.0, -1.0, 0.0));
+/// assert_eq!(out.deseasonalized(), 11.0);
+/// ```
+///
+/// # Links
+///
+/// * [Decomposition of time series, Wikipedia](https://en.wikipedia.org/wiki/Decomposition_of_time_series)
+///
+#[doc(alias = "STL")]
+#[doc(alias = "Deseasonalize")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SeasonalDecomposition {
+    season_length: usize,
+    cycles: usize,
+    phase: usize,
+    cycle: usize,
+    trend: Sma,
+    // detrended values, `cycles` per phase
+    history: Box<[f64]>,
+    // number of detrended values per phase
+    counts: Box<[usize]>,
+    means: Box<[f64]>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeasonalDecompositionOutput {
+    pub trend: f64,
+    pub seasonal: f64,
+    pub residual: f64,
+}
+
+impl SeasonalDecompositionOutput {
+    /// The value with the seasonal part taken out.
+    pub fn deseasonalized(&self) -> f64 {
+        self.trend + self.residual
+    }
+}
+
+impl SeasonalDecomposition {
+    pub fn new(season_length: usize, cycles: usize) -> Result<Self> {
+        if season_length < 2 || cycles == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            season_length,
+            cycles,
+            phase: 0,
+            cycle: 0,
+            trend: Sma::new(season_length)?,
+            history: vec![0.0; season_length * cycles].into_boxed_slice(),
+            counts: vec![0; season_length].into_boxed_slice(),
+            means: vec![0.0; season_length].into_boxed_slice(),
+        })
+    }
+
+    pub fn cycles(&self) -> usize {
+        self.cycles
+    }
+
+    /// Phase of the cycle the next input belongs to.
+    pub fn phase(&self) -> usize {
+        self.phase
+    }
+}
+
+impl Period for SeasonalDecomposition {
+    fn period(&self) -> usize {
+        self.season_length
+    }
+}
+
+impl Nexta<f64> for SeasonalDecomposition {
+    type Output = SeasonalDecompositionOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let trend = self.trend.nexta(input);
+
+        let p = self.phase;
+        let offset = p * self.cycles;
+        self.history[offset + self.cycle] = input - trend;
+        if self.counts[p] < self.cycles {
+            self.counts[p] += 1;
+        }
+        let count = self.counts[p];
+        self.means[p] = self.history[offset..offset + count].iter().sum::<f64>() / count as f64;
+
+        let (mut sum, mut phases) = (0.0, 0);
+        for (mean, &count) in self.means.iter().zip(self.counts.iter()) {
+            if count > 0 {
+                sum += mean;
+                phases += 1;
+            }
+        }
+        let seasonal = self.means[p] - sum / phases as f64;
+
+        self.phase += 1;
+        if self.phase == self.season_length {
+            self.phase = 0;
+            self.cycle = if self.cycle + 1 < self.cycles {
+                self.cycle + 1
+            } else {
+                0
+            };
+        }
+
+        SeasonalDecompositionOutput {
+            trend,
+            seasonal,
+            residual: input - trend - seasonal,
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for SeasonalDecomposition {
+    type Output = SeasonalDecompositionOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for SeasonalDecomposition {
+    fn reset(&mut self) {
+        self.phase = 0;
+        self.cycle = 0;
+        self.trend.reset();
+        for i in 0..self.history.len() {
+            self.history[i] = 0.0;
+        }
+        for i in 0..self.season_length {
+            self.counts[i] = 0;
+            self.means[i] = 0.0;
+        }
+    }
+}
+
+impl Default for SeasonalDecomposition {
+    fn default() -> Self {
+        Self::new(24, 4).unwrap()
+    }
+}
+
+impl fmt::Display for SeasonalDecomposition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SEASONAL({}, {})", self.season_length, self.cycles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(SeasonalDecomposition);
+
+    fn parts(out: SeasonalDecompositionOutput) -> (f64, f64, f64) {
+        (round(out.trend), round(out.seasonal), round(out.residual))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(SeasonalDecomposition::new(1, 4).is_err());
+        assert!(SeasonalDecomposition::new(24, 0).is_err());
+        assert!(SeasonalDecomposition::new(2, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut stl = SeasonalDecomposition::new(2, 2).unwrap();
+
+        assert_eq!(parts(stl.nexta(10.0)), (10.0, 0.0, 0.0));
+        assert_eq!(parts(stl.nexta(12.0)), (11.0, 0.5, 0.5));
+        assert_eq!(parts(stl.nexta(10.0)), (11.0, -0.75, -0.25));
+        assert_eq!(parts(stl.nexta(12.0)), (11.0, 0.75, 0.25));
+        // the first detrended value of phase 0 leaves its window
+        assert_eq!(parts(stl.nexta(10.0)), (11.0, -1.0, 0.0));
+        assert_eq!(parts(stl.nexta(12.0)), (11.0, 1.0, 0.0));
+        // a shock goes to the residual and the seasonal parts
+        let out = stl.nexta(16.0);
+        assert_eq!(parts(out), (14.0, -0.25, 2.25));
+        assert_eq!(round(out.deseasonalized()), 16.25);
+    }
+
+    #[test]
+    fn test_phase() {
+        let mut stl = SeasonalDecomposition::new(3, 1).unwrap();
+
+        assert_eq!(stl.phase(), 0);
+        stl.nexta(1.0);
+        stl.nexta(2.0);
+        assert_eq!(stl.phase(), 2);
+        stl.nexta(3.0);
+        assert_eq!(stl.phase(), 0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stl = SeasonalDecomposition::new(2, 2).unwrap();
+
+        stl.nexta(10.0);
+        stl.nexta(12.0);
+        stl.nexta(10.0);
+        stl.reset();
+        assert_eq!(stl.phase(), 0);
+        assert_eq!(parts(stl.nexta(20.0)), (20.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        SeasonalDecomposition::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let stl = SeasonalDecomposition::new(7, 3).unwrap();
+        assert_eq!(format!("{}", stl), "SEASONAL(7, 3)");
+    }
+}
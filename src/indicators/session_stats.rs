@@ -0,0 +1,287 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, High, Low, Nexta, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Session statistics and initial balance tracker.
+///
+/// Tracks running statistics of the current session: high, low, volume, VWAP and the percentage
+/// of bars that closed above VWAP, as well as the initial balance, which is the range of the first
+/// _period_ bars of the session.
+///
+/// Bars carry no timestamps, so the start of a session is signaled by calling
+/// [new_session](struct.SessionStats.html#method.new_session) before the first bar of the
+/// session. The bars received before the first call belong to the first session. The statistics
+/// are returned by `nexta` and can also be queried at any time with
+/// [stats](struct.SessionStats.html#method.stats).
+///
+/// # Formula
+///
+/// VWAP = Σ(Typical Price * Volume) / Σ(Volume)
+///
+/// Where:
+///
+/// * _Typical Price_ = (High + Low + Close) / 3
+///
+/// While the volume of the session is 0, VWAP is the typical price of the last bar.
+///
+/// _Time above VWAP_ = 100 * number of bars with close above VWAP / number of bars
+///
+/// # Parameters
+///
+/// * _period_ - number of bars of the initial balance (integer greater than 0). Default is 60.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::SessionStats;
+/// use tars::{Nexta, DataItema};
+///
+/// fn bar(high: f64, low: f64, close: f64, volume: f64) -> DataItema {
+///     DataItema::builder()
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .open(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// }
+///
+/// let mut session = SessionStats::new(1).unwrap();
+///
+/// session.nexta(&bar(10.0, 8.0, 9.0, 100.0));
+/// let stats = session.nexta(&bar(13.0, 10.0, 13.0, 300.0));
+/// assert_eq!(stats.vwap, 11.25);
+/// assert_eq!(stats.time_above_vwap, 50.0);
+/// assert_eq!((stats.high, stats.low), (13.0, 8.0));
+/// assert_eq!((stats.initial_balance_high, stats.initial_balance_low), (10.0, 8.0));
+///
+/// session.new_session();
+/// assert!(session.stats().is_none());
+/// ```
+///
+/// # Links
+///
+/// * [VWAP, Investopedia](https://www.investopedia.com/terms/v/vwap.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SessionStats {
+    period: usize,
+    count: usize,
+    above_vwap: usize,
+    high: f64,
+    low: f64,
+    initial_balance_high: f64,
+    initial_balance_low: f64,
+    volume: f64,
+    price_volume: f64,
+    last_price: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionStatsOutput {
+    pub high: f64,
+    pub low: f64,
+    pub volume: f64,
+    pub vwap: f64,
+    /// Percentage of bars of the session with close above VWAP.
+    pub time_above_vwap: f64,
+    pub initial_balance_high: f64,
+    pub initial_balance_low: f64,
+    /// Whether all the bars of the initial balance were received.
+    pub initial_balance_complete: bool,
+}
+
+impl SessionStats {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                count: 0,
+                above_vwap: 0,
+                high: f64::NEG_INFINITY,
+                low: f64::INFINITY,
+                initial_balance_high: f64::NEG_INFINITY,
+                initial_balance_low: f64::INFINITY,
+                volume: 0.0,
+                price_volume: 0.0,
+                last_price: 0.0,
+            }),
+        }
+    }
+
+    /// Starts a new session, all the statistics start over with the next bar.
+    pub fn new_session(&mut self) {
+        self.count = 0;
+        self.above_vwap = 0;
+        self.high = f64::NEG_INFINITY;
+        self.low = f64::INFINITY;
+        self.initial_balance_high = f64::NEG_INFINITY;
+        self.initial_balance_low = f64::INFINITY;
+        self.volume = 0.0;
+        self.price_volume = 0.0;
+        self.last_price = 0.0;
+    }
+
+    /// Returns the statistics of the current session or `None` if no bar has been received yet.
+    pub fn stats(&self) -> Option<SessionStatsOutput> {
+        if self.count == 0 {
+            return None;
+        }
+
+        Some(SessionStatsOutput {
+            high: self.high,
+            low: self.low,
+            volume: self.volume,
+            vwap: self.vwap(),
+            time_above_vwap: 100.0 * self.above_vwap as f64 / self.count as f64,
+            initial_balance_high: self.initial_balance_high,
+            initial_balance_low: self.initial_balance_low,
+            initial_balance_complete: self.count >= self.period,
+        })
+    }
+
+    fn vwap(&self) -> f64 {
+        if self.volume == 0.0 {
+            self.last_price
+        } else {
+            self.price_volume / self.volume
+        }
+    }
+}
+
+impl<T: High + Low + Close + Volume> Nexta<&T> for SessionStats {
+    type Output = SessionStatsOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let price = (input.high() + input.low() + input.close()) / 3.0;
+
+        self.count += 1;
+        self.high = self.high.max(input.high());
+        self.low = self.low.min(input.low());
+        if self.count <= self.period {
+            self.initial_balance_high = self.high;
+            self.initial_balance_low = self.low;
+        }
+        self.volume += input.volume();
+        self.price_volume += price * input.volume();
+        self.last_price = price;
+
+        if input.close() > self.vwap() {
+            self.above_vwap += 1;
+        }
+
+        self.stats().unwrap()
+    }
+}
+
+impl Reset for SessionStats {
+    fn reset(&mut self) {
+        self.new_session();
+    }
+}
+
+impl Default for SessionStats {
+    fn default() -> Self {
+        Self::new(60).unwrap()
+    }
+}
+
+impl fmt::Display for SessionStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SESSION({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SessionStats::new(0).is_err());
+        assert!(SessionStats::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut session = SessionStats::new(2).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).close(9).volume(100.0);
+        let bar2 = Bar::new().high(12).low(9).close(12).volume(200.0);
+        let bar3 = Bar::new().high(13).low(11).close(11).volume(100.0);
+
+        let out = session.nexta(&bar1);
+        assert_eq!(out.vwap, 9.0);
+        assert_eq!(out.time_above_vwap, 0.0);
+        assert!(!out.initial_balance_complete);
+
+        let out = session.nexta(&bar2);
+        assert_eq!(round(out.vwap), 10.333);
+        assert_eq!(out.time_above_vwap, 50.0);
+        assert_eq!(out.volume, 300.0);
+        assert_eq!(
+            (out.initial_balance_high, out.initial_balance_low),
+            (12.0, 8.0)
+        );
+        assert!(out.initial_balance_complete);
+
+        let out = session.nexta(&bar3);
+        assert_eq!(round(out.vwap), 10.667);
+        assert_eq!(round(out.time_above_vwap), 66.667);
+        assert_eq!((out.high, out.low), (13.0, 8.0));
+        assert_eq!(
+            (out.initial_balance_high, out.initial_balance_low),
+            (12.0, 8.0)
+        );
+
+        assert_eq!(session.stats(), Some(out));
+    }
+
+    #[test]
+    fn test_new_session() {
+        let mut session = SessionStats::new(2).unwrap();
+
+        session.nexta(&Bar::new().high(10).low(8).close(9).volume(100.0));
+
+        session.new_session();
+        assert_eq!(session.stats(), None);
+
+        // zero volume: VWAP is the typical price
+        let out = session.nexta(&Bar::new().high(5).low(4).close(4.5).volume(0.0));
+        assert_eq!(out.vwap, 4.5);
+        assert_eq!(out.volume, 0.0);
+        assert_eq!((out.high, out.low), (5.0, 4.0));
+        assert_eq!(
+            (out.initial_balance_high, out.initial_balance_low),
+            (5.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut session = SessionStats::new(2).unwrap();
+
+        session.nexta(&Bar::new().high(10).low(8).close(9).volume(100.0));
+
+        session.reset();
+        assert_eq!(session.stats(), None);
+    }
+
+    #[test]
+    fn test_default() {
+        SessionStats::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let session = SessionStats::new(30).unwrap();
+        assert_eq!(format!("{}", session), "SESSION(30)");
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
+use crate::indicators::RingBuffer;
 use crate::{Close, Nexta, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -21,6 +22,15 @@ use serde::{Deserialize, Serialize};
 ///
 /// * _period_ - number of periods (integer greater than 0)
 ///
+/// # F32 storage
+///
+/// Created with [with_f32_storage](#method.with_f32_storage) the window is stored as f32, which
+/// halves its memory for periods in the thousands, while the sum is still accumulated in f64.
+/// Every input is rounded to f32 when it is stored, so the average is the exact average of the
+/// rounded inputs: its absolute error is at most 2<sup>-24</sup> (about 6·10<sup>-8</sup>) times
+/// the largest absolute input in the window. Inputs beyond the f32 range (about ±3.4·10<sup>38</sup>)
+/// become infinite.
+///
 /// # Example
 ///
 /// ```
@@ -46,11 +56,20 @@ pub struct SimpleMovingAverage {
     index: usize,
     count: usize,
     sum: f64,
-    deque: Box<[f64]>,
+    deque: RingBuffer,
 }
 
 impl SimpleMovingAverage {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_storage(period, false)
+    }
+
+    /// Creates the average with the window stored as f32, see [F32 storage](#f32-storage).
+    pub fn with_f32_storage(period: usize) -> Result<Self> {
+        Self::with_storage(period, true)
+    }
+
+    fn with_storage(period: usize, f32_storage: bool) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
             _ => Ok(Self {
@@ -58,7 +77,7 @@ impl SimpleMovingAverage {
                 index: 0,
                 count: 0,
                 sum: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                deque: RingBuffer::new(period, f32_storage),
             }),
         }
     }
@@ -74,8 +93,8 @@ impl Nexta<f64> for SimpleMovingAverage {
     type Output = f64;
 
     fn nexta(&mut self, input: f64) -> Self::Output {
-        let old_val = self.deque[self.index];
-        self.deque[self.index] = input;
+        let old_val = self.deque.get(self.index);
+        let input = self.deque.set(self.index, input);
 
         self.index = if self.index + 1 < self.period {
             self.index + 1
@@ -105,9 +124,7 @@ impl Reset for SimpleMovingAverage {
         self.index = 0;
         self.count = 0;
         self.sum = 0.0;
-        for i in 0..self.period {
-            self.deque[i] = 0.0;
-        }
+        self.deque.clear();
     }
 }
 
@@ -177,6 +194,32 @@ mod tests {
         SimpleMovingAverage::default();
     }
 
+    #[test]
+    fn test_f32_storage() {
+        assert!(SimpleMovingAverage::with_f32_storage(0).is_err());
+
+        // values representable in f32 give the same averages
+        let mut sma = SimpleMovingAverage::with_f32_storage(4).unwrap();
+        assert_eq!(sma.nexta(4.0), 4.0);
+        assert_eq!(sma.nexta(5.0), 4.5);
+        assert_eq!(sma.nexta(6.0), 5.0);
+        assert_eq!(sma.nexta(6.0), 5.25);
+        assert_eq!(sma.nexta(6.0), 5.75);
+    }
+
+    #[test]
+    fn test_f32_storage_precision() {
+        let mut sma = SimpleMovingAverage::new(2000).unwrap();
+        let mut compact = SimpleMovingAverage::with_f32_storage(2000).unwrap();
+
+        let mut price = 100.0;
+        for i in 0..10_000 {
+            price += ((i * 7919) % 201) as f64 / 1000.0 - 0.1;
+            let error = (sma.nexta(price) - compact.nexta(price)).abs();
+            assert!(error <= 200.0 * 2f64.powi(-24));
+        }
+    }
+
     #[test]
     fn test_display() {
         let sma = SimpleMovingAverage::new(5).unwrap();
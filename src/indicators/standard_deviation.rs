@@ -1,6 +1,7 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
+use crate::indicators::RingBuffer;
 use crate::{Close, Nexta, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -23,6 +24,15 @@ use serde::{Deserialize, Serialize};
 ///
 /// * _period_ - number of periods (integer greater than 0)
 ///
+/// # F32 storage
+///
+/// Created with [with_f32_storage](#method.with_f32_storage) the window is stored as f32, which
+/// halves its memory for periods in the thousands, while the moments are still accumulated in
+/// f64. Every input is rounded to f32 when it is stored, so the result is the standard deviation
+/// of the rounded inputs: its absolute error is at most 2<sup>-24</sup> (about 6·10<sup>-8</sup>)
+/// times the largest absolute input in the window. Inputs beyond the f32 range (about
+/// ±3.4·10<sup>38</sup>) become infinite.
+///
 /// # Example
 ///
 /// ```
@@ -47,11 +57,20 @@ pub struct StandardDeviation {
     count: usize,
     m: f64,
     m2: f64,
-    deque: Box<[f64]>,
+    deque: RingBuffer,
 }
 
 impl StandardDeviation {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_storage(period, false)
+    }
+
+    /// Creates the indicator with the window stored as f32, see [F32 storage](#f32-storage).
+    pub fn with_f32_storage(period: usize) -> Result<Self> {
+        Self::with_storage(period, true)
+    }
+
+    fn with_storage(period: usize, f32_storage: bool) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
             _ => Ok(Self {
@@ -60,7 +79,7 @@ impl StandardDeviation {
                 count: 0,
                 m: 0.0,
                 m2: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                deque: RingBuffer::new(period, f32_storage),
             }),
         }
     }
@@ -68,6 +87,16 @@ impl StandardDeviation {
     pub(super) fn mean(&self) -> f64 {
         self.m
     }
+
+    /// Sum of squared deviations from the mean of the values in the window.
+    pub(super) fn sum_of_squares(&self) -> f64 {
+        self.m2
+    }
+
+    /// Number of values in the window.
+    pub(super) fn count(&self) -> usize {
+        self.count
+    }
 }
 
 impl Period for StandardDeviation {
@@ -80,8 +109,8 @@ impl Nexta<f64> for StandardDeviation {
     type Output = f64;
 
     fn nexta(&mut self, input: f64) -> Self::Output {
-        let old_val = self.deque[self.index];
-        self.deque[self.index] = input;
+        let old_val = self.deque.get(self.index);
+        let input = self.deque.set(self.index, input);
 
         self.index = if self.index + 1 < self.period {
             self.index + 1
@@ -124,9 +153,7 @@ impl Reset for StandardDeviation {
         self.count = 0;
         self.m = 0.0;
         self.m2 = 0.0;
-        for i in 0..self.period {
-            self.deque[i] = 0.0;
-        }
+        self.deque.clear();
     }
 }
 
@@ -218,6 +245,32 @@ mod tests {
         StandardDeviation::default();
     }
 
+    #[test]
+    fn test_f32_storage() {
+        assert!(StandardDeviation::with_f32_storage(0).is_err());
+
+        // values representable in f32 give the same results
+        let mut sd = StandardDeviation::with_f32_storage(4).unwrap();
+        assert_eq!(sd.nexta(10.0), 0.0);
+        assert_eq!(sd.nexta(20.0), 5.0);
+        assert_eq!(round(sd.nexta(30.0)), 8.165);
+        assert_eq!(round(sd.nexta(20.0)), 7.071);
+        assert_eq!(round(sd.nexta(10.0)), 7.071);
+    }
+
+    #[test]
+    fn test_f32_storage_precision() {
+        let mut sd = StandardDeviation::new(2000).unwrap();
+        let mut compact = StandardDeviation::with_f32_storage(2000).unwrap();
+
+        let mut price = 100.0;
+        for i in 0..10_000 {
+            price += ((i * 7919) % 201) as f64 / 1000.0 - 0.1;
+            let error = (sd.nexta(price) - compact.nexta(price)).abs();
+            assert!(error <= 200.0 * 2f64.powi(-24));
+        }
+    }
+
     #[test]
     fn test_display() {
         let sd = StandardDeviation::new(5).unwrap();
@@ -0,0 +1,204 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{AverageTrueRange, SimpleMovingAverage as Sma};
+use crate::{Close, High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stoller Average Range Channel (STARC) Bands.
+///
+/// Developed by Manning Stoller, the bands are placed a multiple of the Average True Range (ATR)
+/// above and below a simple moving average of the close. Prices near the upper band are a poor
+/// place to buy and prices near the lower band a poor place to sell.
+///
+/// # Formula
+///
+/// See SMA, ATR documentation.
+///
+/// STARC Bands are composed as:
+///
+///  * _STARC<sub>Middle Band</sub>_ = SMA(_sma period_) of Close
+///  * _STARC<sub>Upper Band</sub>_ = SMA + ATR(_atr period_) × _multiplier_
+///  * _STARC<sub>Lower Band</sub>_ = SMA - ATR(_atr period_) × _multiplier_
+///
+/// # Parameters
+///
+/// * _sma_period_ - period of the moving average (integer greater than 0). Default is 6.
+/// * _atr_period_ - period of the ATR (integer greater than 0). Default is 15.
+/// * _multiplier_ - ATR multiplier (non-negative number). A multiplier of 0 collapses both bands
+///   onto the middle band. Default is 2.
+///
+/// # Example
+///
+///```
+/// use tars::indicators::StarcBands;
+/// use tars::Nexta;
+///
+/// let mut starc = StarcBands::new(3, 3, 2.0).unwrap();
+///
+/// starc.nexta(2.0);
+/// let out = starc.nexta(5.0);
+///
+/// assert_eq!(out.average, 3.5);
+/// assert_eq!(out.upper, 6.5);
+/// assert_eq!(out.lower, 0.5);
+/// ```
+///
+#[doc(alias = "STARC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StarcBands {
+    multiplier: f64,
+    sma: Sma,
+    atr: AverageTrueRange,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StarcBandsOutput {
+    pub average: f64,
+    pub upper: f64,
+    pub lower: f64,
+}
+
+impl StarcBands {
+    pub fn new(sma_period: usize, atr_period: usize, multiplier: f64) -> Result<Self> {
+        if !(multiplier.is_finite() && multiplier >= 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            multiplier,
+            sma: Sma::new(sma_period)?,
+            atr: AverageTrueRange::new(atr_period)?,
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    fn bands(&self, average: f64, atr: f64) -> StarcBandsOutput {
+        StarcBandsOutput {
+            average,
+            upper: average + atr * self.multiplier,
+            lower: average - atr * self.multiplier,
+        }
+    }
+}
+
+impl Nexta<f64> for StarcBands {
+    type Output = StarcBandsOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let average = self.sma.nexta(input);
+        let atr = self.atr.nexta(input);
+        self.bands(average, atr)
+    }
+}
+
+impl<T: Close + High + Low> Nexta<&T> for StarcBands {
+    type Output = StarcBandsOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let average = self.sma.nexta(input.close());
+        let atr = self.atr.nexta(input);
+        self.bands(average, atr)
+    }
+}
+
+impl Reset for StarcBands {
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.atr.reset();
+    }
+}
+
+impl Default for StarcBands {
+    fn default() -> Self {
+        Self::new(6, 15, 2.0).unwrap()
+    }
+}
+
+impl fmt::Display for StarcBands {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "STARC({}, {}, {})",
+            self.sma.period(),
+            self.atr.period(),
+            self.multiplier
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(StarcBands);
+
+    #[test]
+    fn test_new() {
+        assert!(StarcBands::new(0, 15, 2.0).is_err());
+        assert!(StarcBands::new(6, 0, 2.0).is_err());
+        assert!(StarcBands::new(1, 1, 2.0).is_ok());
+        assert!(StarcBands::new(1, 1, 0.0).is_ok());
+        assert!(StarcBands::new(1, 1, -2.0).is_err());
+        assert!(StarcBands::new(1, 1, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut starc = StarcBands::new(3, 3, 2.0).unwrap();
+
+        let mut next = |input: f64| {
+            let out = starc.nexta(input);
+            (round(out.average), round(out.upper), round(out.lower))
+        };
+
+        assert_eq!(next(2.0), (2.0, 2.0, 2.0));
+        assert_eq!(next(5.0), (3.5, 6.5, 0.5));
+        assert_eq!(next(1.0), (2.667, 8.167, -2.833));
+        assert_eq!(next(6.25), (4.083, 12.083, -3.917));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut starc = StarcBands::new(3, 3, 2.0).unwrap();
+
+        let mut next = |high: f64, low: f64, close: f64| {
+            let out = starc.nexta(&Bar::new().high(high).low(low).close(close));
+            (round(out.average), round(out.upper), round(out.lower))
+        };
+
+        assert_eq!(next(12.0, 8.0, 10.0), (10.0, 18.0, 2.0));
+        assert_eq!(next(14.0, 10.0, 13.0), (11.5, 19.5, 3.5));
+        // true range = 15 - 9 = 6, ATR = 5
+        assert_eq!(next(15.0, 9.0, 11.0), (11.333, 21.333, 1.333));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut starc = StarcBands::new(3, 3, 2.0).unwrap();
+
+        starc.nexta(2.0);
+        starc.nexta(5.0);
+        starc.reset();
+
+        let out = starc.nexta(3.0);
+        assert_eq!((out.average, out.upper, out.lower), (3.0, 3.0, 3.0));
+    }
+
+    #[test]
+    fn test_default() {
+        StarcBands::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let starc = StarcBands::default();
+        assert_eq!(format!("{}", starc), "STARC(6, 15, 2)");
+    }
+}
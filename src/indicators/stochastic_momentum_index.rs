@@ -0,0 +1,225 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{ExponentialMovingAverage as Ema, Maximum, Minimum};
+use crate::{Close, High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic Momentum Index (SMI).
+///
+/// Developed by William Blau, the index refines the stochastic oscillator: instead of the
+/// position of the close within the high/low range it measures the distance of the close from
+/// the midpoint of the range, and smooths both the distance and the range twice. The index is
+/// bounded between -100 and +100; values above +40 are usually considered overbought and values
+/// below -40 oversold.
+///
+/// # Formula
+///
+/// * _SMI_ = 100 × EMA(EMA(Distance)) / (EMA(EMA(Range)) / 2)
+/// * _Signal_ = EMA(_signal period_) of SMI
+///
+/// Where:
+///
+/// * _Distance_ = Close - (Highest High + Lowest Low) / 2
+/// * _Range_ = Highest High - Lowest Low
+/// * highest high and lowest low are taken over the last _period_ bars
+/// * both nested EMAs use the _smoothing_ period
+///
+/// When the smoothed range is 0 the SMI is 0.
+///
+/// # Parameters
+///
+/// * _period_ - high/low window (integer greater than 0). Default is 10.
+/// * _smoothing_ - period of the double smoothing EMAs (integer greater than 0). Default is 3.
+/// * _signal_period_ - period of the signal EMA (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::StochasticMomentumIndex;
+/// use tars::{Nexta, DataItema};
+///
+/// let mut smi = StochasticMomentumIndex::new(3, 2, 2).unwrap();
+///
+/// let di = DataItema::builder()
+///             .high(12.0)
+///             .low(8.0)
+///             .close(11.0)
+///             .open(9.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// let out = smi.nexta(&di);
+/// assert_eq!((out.smi, out.signal), (50.0, 50.0));
+/// ```
+///
+#[doc(alias = "SMI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StochasticMomentumIndex {
+    period: usize,
+    maximum: Maximum,
+    minimum: Minimum,
+    distance_ema: Ema,
+    distance_ema2: Ema,
+    range_ema: Ema,
+    range_ema2: Ema,
+    signal_ema: Ema,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticMomentumIndexOutput {
+    pub smi: f64,
+    pub signal: f64,
+}
+
+impl From<StochasticMomentumIndexOutput> for (f64, f64) {
+    fn from(so: StochasticMomentumIndexOutput) -> Self {
+        (so.smi, so.signal)
+    }
+}
+
+impl StochasticMomentumIndex {
+    pub fn new(period: usize, smoothing: usize, signal_period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            maximum: Maximum::new(period)?,
+            minimum: Minimum::new(period)?,
+            distance_ema: Ema::new(smoothing)?,
+            distance_ema2: Ema::new(smoothing)?,
+            range_ema: Ema::new(smoothing)?,
+            range_ema2: Ema::new(smoothing)?,
+            signal_ema: Ema::new(signal_period)?,
+        })
+    }
+}
+
+impl Period for StochasticMomentumIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for StochasticMomentumIndex {
+    type Output = StochasticMomentumIndexOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let highest = self.maximum.nexta(input.high());
+        let lowest = self.minimum.nexta(input.low());
+
+        let distance = input.close() - (highest + lowest) / 2.0;
+        let distance = self.distance_ema2.nexta(self.distance_ema.nexta(distance));
+        let range = self
+            .range_ema2
+            .nexta(self.range_ema.nexta(highest - lowest));
+
+        let smi = if range == 0.0 {
+            0.0
+        } else {
+            100.0 * distance / (range / 2.0)
+        };
+        let signal = self.signal_ema.nexta(smi);
+
+        StochasticMomentumIndexOutput { smi, signal }
+    }
+}
+
+impl Reset for StochasticMomentumIndex {
+    fn reset(&mut self) {
+        self.maximum.reset();
+        self.minimum.reset();
+        self.distance_ema.reset();
+        self.distance_ema2.reset();
+        self.range_ema.reset();
+        self.range_ema2.reset();
+        self.signal_ema.reset();
+    }
+}
+
+impl Default for StochasticMomentumIndex {
+    fn default() -> Self {
+        Self::new(10, 3, 3).unwrap()
+    }
+}
+
+impl fmt::Display for StochasticMomentumIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SMI({}, {}, {})",
+            self.period,
+            self.distance_ema.period(),
+            self.signal_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    fn round(nums: (f64, f64)) -> (f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        (n0, n1)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(StochasticMomentumIndex::new(0, 3, 3).is_err());
+        assert!(StochasticMomentumIndex::new(10, 0, 3).is_err());
+        assert!(StochasticMomentumIndex::new(10, 3, 0).is_err());
+        assert!(StochasticMomentumIndex::new(10, 3, 3).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut smi = StochasticMomentumIndex::new(3, 2, 2).unwrap();
+
+        let mut next = |high, low, close| round(smi.nexta(&bar(high, low, close)).into());
+
+        assert_eq!(next(12.0, 8.0, 11.0), (50.0, 50.0));
+        assert_eq!(next(13.0, 9.0, 12.0), (55.0, 53.333));
+        assert_eq!(next(14.0, 10.0, 10.0), (10.0, 24.444));
+        assert_eq!(next(12.0, 9.0, 9.0), (-41.429, -19.471));
+        assert_eq!(next(11.0, 8.0, 10.5), (-34.866, -29.735));
+        assert_eq!(next(13.0, 10.0, 12.5), (13.604, -0.842));
+    }
+
+    #[test]
+    fn test_flat_bars() {
+        let mut smi = StochasticMomentumIndex::new(3, 2, 2).unwrap();
+
+        let out = smi.nexta(&bar(10.0, 10.0, 10.0));
+        assert_eq!((out.smi, out.signal), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut smi = StochasticMomentumIndex::new(3, 2, 2).unwrap();
+
+        smi.nexta(&bar(12.0, 8.0, 11.0));
+        smi.nexta(&bar(13.0, 9.0, 12.0));
+
+        smi.reset();
+        let out = smi.nexta(&bar(12.0, 8.0, 9.0));
+        assert_eq!((out.smi, out.signal), (-50.0, -50.0));
+    }
+
+    #[test]
+    fn test_default() {
+        StochasticMomentumIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let smi = StochasticMomentumIndex::default();
+        assert_eq!(format!("{}", smi), "SMI(10, 3, 3)");
+    }
+}
@@ -0,0 +1,188 @@
+use std::f64::consts::{PI, SQRT_2};
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ehlers SuperSmoother filter (SSF).
+///
+/// Developed by John Ehlers, the filter is a two-pole Butterworth style low-pass filter fed with
+/// the average of the last two inputs. It removes the noise with cycles shorter than the period
+/// with much less lag than a moving average of similar smoothness, so it can replace a moving
+/// average as the input of other indicators.
+///
+/// # Formula
+///
+/// SSF<sub>t</sub> = c<sub>1</sub> × (x<sub>t</sub> + x<sub>t-1</sub>) / 2 + c<sub>2</sub> × SSF<sub>t-1</sub> + c<sub>3</sub> × SSF<sub>t-2</sub>
+///
+/// Where:
+///
+/// * _a_ = e<sup>-√2 × π / _period_</sup>
+/// * _c<sub>2</sub>_ = 2 × _a_ × cos(√2 × π / _period_)
+/// * _c<sub>3</sub>_ = -_a_<sup>2</sup>
+/// * _c<sub>1</sub>_ = 1 - _c<sub>2</sub>_ - _c<sub>3</sub>_
+///
+/// The filter starts in the steady state of the first input, so the first output equals the
+/// first input.
+///
+/// # Parameters
+///
+/// * _period_ - critical period (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::SuperSmoother;
+/// use tars::Nexta;
+///
+/// let mut ssf = SuperSmoother::new(10).unwrap();
+///
+/// assert_eq!(ssf.nexta(10.0), 10.0);
+/// assert!(ssf.nexta(12.0) < 11.0);
+/// ```
+///
+#[doc(alias = "SSF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SuperSmoother {
+    period: usize,
+    c1: f64,
+    c2: f64,
+    c3: f64,
+    // previous input and the last two outputs
+    state: Option<(f64, f64, f64)>,
+}
+
+impl SuperSmoother {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => {
+                let arg = SQRT_2 * PI / period as f64;
+                let a = (-arg).exp();
+                let c2 = 2.0 * a * arg.cos();
+                let c3 = -a * a;
+                Ok(Self {
+                    period,
+                    c1: 1.0 - c2 - c3,
+                    c2,
+                    c3,
+                    state: None,
+                })
+            }
+        }
+    }
+}
+
+impl Period for SuperSmoother {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for SuperSmoother {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let (prev_input, filt1, filt2) = self.state.unwrap_or((input, input, input));
+        let filt = self.c1 * (input + prev_input) / 2.0 + self.c2 * filt1 + self.c3 * filt2;
+        self.state = Some((input, filt, filt1));
+        filt
+    }
+}
+
+impl<T: Close> Nexta<&T> for SuperSmoother {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for SuperSmoother {
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+impl Default for SuperSmoother {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for SuperSmoother {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SSF({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(SuperSmoother);
+
+    #[test]
+    fn test_new() {
+        assert!(SuperSmoother::new(0).is_err());
+        assert!(SuperSmoother::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ssf = SuperSmoother::new(4).unwrap();
+
+        assert_eq!(round(ssf.nexta(10.0)), 10.0);
+        assert_eq!(round(ssf.nexta(10.0)), 10.0);
+        assert_eq!(round(ssf.nexta(12.0)), 10.816);
+        assert_eq!(round(ssf.nexta(11.0)), 11.463);
+        assert_eq!(round(ssf.nexta(15.0)), 12.787);
+        assert_eq!(round(ssf.nexta(14.0)), 14.329);
+    }
+
+    #[test]
+    fn test_converges() {
+        let mut ssf = SuperSmoother::new(10).unwrap();
+
+        ssf.nexta(0.0);
+        let mut output = 0.0;
+        for _ in 0..200 {
+            output = ssf.nexta(5.0);
+        }
+        assert_eq!(round(output), 5.0);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut ssf = SuperSmoother::new(4).unwrap();
+
+        assert_eq!(round(ssf.nexta(&Bar::new().close(10))), 10.0);
+        assert_eq!(round(ssf.nexta(&Bar::new().close(12))), 10.816);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ssf = SuperSmoother::new(4).unwrap();
+
+        ssf.nexta(10.0);
+        ssf.nexta(12.0);
+
+        ssf.reset();
+        assert_eq!(round(ssf.nexta(20.0)), 20.0);
+    }
+
+    #[test]
+    fn test_default() {
+        SuperSmoother::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ssf = SuperSmoother::new(10).unwrap();
+        assert_eq!(format!("{}", ssf), "SSF(10)");
+    }
+}
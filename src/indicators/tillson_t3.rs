@@ -0,0 +1,212 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Tillson T3 moving average (T3).
+///
+/// Developed by Tim Tillson, T3 is a low-lag smoother built from six cascaded exponential moving
+/// averages. The outputs of the last four EMAs are combined with weights derived from the
+/// _volume factor_, which controls how aggressively the lag is compensated.
+///
+/// # Formula
+///
+/// T3 = c<sub>1</sub> * e<sub>6</sub> + c<sub>2</sub> * e<sub>5</sub> + c<sub>3</sub> * e<sub>4</sub> + c<sub>4</sub> * e<sub>3</sub>
+///
+/// Where:
+///
+/// * _e<sub>1</sub>_ = EMA(_period_) of input, _e<sub>i</sub>_ = EMA(_period_) of _e<sub>i-1</sub>_
+/// * _c<sub>1</sub>_ = -_v_<sup>3</sup>
+/// * _c<sub>2</sub>_ = 3_v_<sup>2</sup> + 3_v_<sup>3</sup>
+/// * _c<sub>3</sub>_ = -6_v_<sup>2</sup> - 3_v_ - 3_v_<sup>3</sup>
+/// * _c<sub>4</sub>_ = 1 + 3_v_ + _v_<sup>3</sup> + 3_v_<sup>2</sup>
+/// * _v_ - volume factor
+///
+/// # Parameters
+///
+/// * _period_ - period of every EMA (integer greater than 0). Default is 5.
+/// * _volume_factor_ - number between 0 and 1. Default is 0.7.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::TillsonT3;
+/// use tars::Nexta;
+///
+/// let mut t3 = TillsonT3::new(3, 0.7).unwrap();
+/// assert_eq!(t3.nexta(2.0), 2.0);
+/// assert_eq!((t3.nexta(5.0) * 100.0).round() / 100.0, 2.92);
+/// ```
+///
+/// # Links
+///
+/// * [T3 Moving Average, TradingView](https://www.tradingview.com/script/gXb4qJlB-T3-Moving-Average/)
+///
+#[doc(alias = "T3")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TillsonT3 {
+    period: usize,
+    volume_factor: f64,
+    c1: f64,
+    c2: f64,
+    c3: f64,
+    c4: f64,
+    emas: [Ema; 6],
+}
+
+impl TillsonT3 {
+    pub fn new(period: usize, volume_factor: f64) -> Result<Self> {
+        if !(0.0..=1.0).contains(&volume_factor) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let v = volume_factor;
+        let v2 = v * v;
+        let v3 = v2 * v;
+        let ema = Ema::new(period)?;
+
+        Ok(Self {
+            period,
+            volume_factor,
+            c1: -v3,
+            c2: 3.0 * v2 + 3.0 * v3,
+            c3: -6.0 * v2 - 3.0 * v - 3.0 * v3,
+            c4: 1.0 + 3.0 * v + v3 + 3.0 * v2,
+            emas: [
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema,
+            ],
+        })
+    }
+
+    pub fn volume_factor(&self) -> f64 {
+        self.volume_factor
+    }
+}
+
+impl Period for TillsonT3 {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for TillsonT3 {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let mut e = [0.0; 6];
+        let mut value = input;
+        for (ema, out) in self.emas.iter_mut().zip(e.iter_mut()) {
+            value = ema.nexta(value);
+            *out = value;
+        }
+
+        self.c1 * e[5] + self.c2 * e[4] + self.c3 * e[3] + self.c4 * e[2]
+    }
+}
+
+impl<T: Close> Nexta<&T> for TillsonT3 {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for TillsonT3 {
+    fn reset(&mut self) {
+        for ema in self.emas.iter_mut() {
+            ema.reset();
+        }
+    }
+}
+
+impl Default for TillsonT3 {
+    fn default() -> Self {
+        Self::new(5, 0.7).unwrap()
+    }
+}
+
+impl fmt::Display for TillsonT3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "T3({}, {})", self.period, self.volume_factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(TillsonT3);
+
+    #[test]
+    fn test_new() {
+        assert!(TillsonT3::new(0, 0.7).is_err());
+        assert!(TillsonT3::new(1, -0.1).is_err());
+        assert!(TillsonT3::new(1, 1.1).is_err());
+        assert!(TillsonT3::new(1, f64::NAN).is_err());
+        assert!(TillsonT3::new(1, 0.0).is_ok());
+        assert!(TillsonT3::new(1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut t3 = TillsonT3::new(3, 0.7).unwrap();
+
+        assert_eq!(round(t3.nexta(2.0)), 2.0);
+        assert_eq!(round(t3.nexta(5.0)), 2.923);
+        assert_eq!(round(t3.nexta(1.5)), 2.871);
+        assert_eq!(round(t3.nexta(6.25)), 3.849);
+    }
+
+    #[test]
+    fn test_next_constant() {
+        let mut t3 = TillsonT3::new(4, 0.5).unwrap();
+        for _ in 0..10 {
+            assert_eq!(round(t3.nexta(7.5)), 7.5);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut t3 = TillsonT3::new(3, 0.7).unwrap();
+        assert_eq!(round(t3.nexta(&bar(2.0))), 2.0);
+        assert_eq!(round(t3.nexta(&bar(5.0))), 2.923);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut t3 = TillsonT3::new(3, 0.7).unwrap();
+
+        t3.nexta(2.0);
+        t3.nexta(5.0);
+
+        t3.reset();
+        assert_eq!(round(t3.nexta(2.0)), 2.0);
+        assert_eq!(round(t3.nexta(5.0)), 2.923);
+    }
+
+    #[test]
+    fn test_default() {
+        TillsonT3::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = TillsonT3::new(8, 0.5).unwrap();
+        assert_eq!(format!("{}", indicator), "T3(8, 0.5)");
+    }
+}
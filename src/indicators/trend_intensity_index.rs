@@ -0,0 +1,275 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Trend Intensity Index (TII).
+///
+/// Developed by M. H. Pee, the TII measures how consistently the close stays on one side of its
+/// moving average. It is the share of the deviations from the SMA over the last
+/// _deviation_period_ bars that are above the SMA, weighted by their size. Values above 80
+/// indicate a strong uptrend and values below 20 a strong downtrend.
+///
+/// # Formula
+///
+/// Dev = Close - SMA(_period_)
+///
+/// TII = 100 × SD<sup>+</sup> / (SD<sup>+</sup> + SD<sup>-</sup>)
+///
+/// Where _SD<sup>+</sup>_ is the sum of the positive deviations and _SD<sup>-</sup>_ the sum of
+/// the absolute negative deviations of the last _deviation_period_ bars. When all the deviations
+/// are 0 the TII is 50.
+///
+/// # Parameters
+///
+/// * _period_ - period of the SMA (integer greater than 0). Default is 60.
+/// * _deviation_period_ - number of deviations (integer greater than 0), usually half of the
+///   _period_. Default is 30.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::TrendIntensityIndex as Tii;
+/// use tars::Nexta;
+///
+/// let mut tii = Tii::new(2, 2).unwrap();
+/// assert_eq!(tii.nexta(10.0), 50.0);
+/// assert_eq!(tii.nexta(12.0), 100.0);
+/// assert_eq!(tii.nexta(16.0), 100.0);
+/// assert_eq!(tii.nexta(10.0), 40.0);
+/// ```
+///
+/// # Links
+///
+/// * [Trend Intensity Index, StockCharts](https://chartschool.stockcharts.com/table-of-contents/technical-indicators-and-overlays/technical-indicators/trend-intensity-index)
+///
+#[doc(alias = "TII")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TrendIntensityIndex {
+    sma: Sma,
+    deviation_period: usize,
+    index: usize,
+    count: usize,
+    positive: f64,
+    negative: f64,
+    // number of positive and negative deviations in the window, a sum is zeroed at 0 to drop
+    // float residue
+    positives: usize,
+    negatives: usize,
+    // number of consecutive equal closes, the SMA of a flat window equals the close
+    prev: Option<f64>,
+    run: usize,
+    deque: Box<[f64]>,
+}
+
+impl TrendIntensityIndex {
+    pub fn new(period: usize, deviation_period: usize) -> Result<Self> {
+        if deviation_period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            sma: Sma::new(period)?,
+            deviation_period,
+            index: 0,
+            count: 0,
+            positive: 0.0,
+            negative: 0.0,
+            positives: 0,
+            negatives: 0,
+            prev: None,
+            run: 0,
+            deque: vec![0.0; deviation_period].into_boxed_slice(),
+        })
+    }
+
+    pub fn deviation_period(&self) -> usize {
+        self.deviation_period
+    }
+}
+
+impl Period for TrendIntensityIndex {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Nexta<f64> for TrendIntensityIndex {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        self.run = match self.prev {
+            Some(prev) if prev == input => self.run + 1,
+            _ => 1,
+        };
+        self.prev = Some(input);
+
+        let sma = self.sma.nexta(input);
+        let deviation = if self.run >= self.sma.period() {
+            0.0
+        } else {
+            input - sma
+        };
+
+        if self.count < self.deviation_period {
+            self.count += 1;
+        } else {
+            let old = self.deque[self.index];
+            if old > 0.0 {
+                self.positives -= 1;
+                self.positive = if self.positives > 0 {
+                    (self.positive - old).max(0.0)
+                } else {
+                    0.0
+                };
+            } else if old < 0.0 {
+                self.negatives -= 1;
+                self.negative = if self.negatives > 0 {
+                    (self.negative + old).max(0.0)
+                } else {
+                    0.0
+                };
+            }
+        }
+        if deviation > 0.0 {
+            self.positives += 1;
+            self.positive += deviation;
+        } else if deviation < 0.0 {
+            self.negatives += 1;
+            self.negative -= deviation;
+        }
+
+        self.deque[self.index] = deviation;
+        self.index = if self.index + 1 < self.deviation_period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let total = self.positive + self.negative;
+        if total > 0.0 {
+            100.0 * self.positive / total
+        } else {
+            50.0
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for TrendIntensityIndex {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for TrendIntensityIndex {
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.index = 0;
+        self.count = 0;
+        self.positive = 0.0;
+        self.negative = 0.0;
+        self.positives = 0;
+        self.negatives = 0;
+        self.prev = None;
+        self.run = 0;
+        for i in 0..self.deviation_period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for TrendIntensityIndex {
+    fn default() -> Self {
+        Self::new(60, 30).unwrap()
+    }
+}
+
+impl fmt::Display for TrendIntensityIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TII({}, {})", self.sma.period(), self.deviation_period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Tii = TrendIntensityIndex;
+
+    test_indicator!(Tii);
+
+    #[test]
+    fn test_new() {
+        assert!(Tii::new(0, 1).is_err());
+        assert!(Tii::new(1, 0).is_err());
+        assert!(Tii::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut tii = Tii::new(2, 2).unwrap();
+
+        assert_eq!(tii.nexta(10.0), 50.0);
+        assert_eq!(tii.nexta(12.0), 100.0);
+        assert_eq!(round(tii.nexta(11.0)), 66.667);
+        // the +1 deviation leaves the window
+        assert_eq!(round(tii.nexta(11.5)), 33.333);
+    }
+
+    #[test]
+    fn test_downtrend() {
+        let mut tii = Tii::new(3, 2).unwrap();
+
+        tii.nexta(20.0);
+        for &close in &[19.0, 18.0, 17.0, 16.0] {
+            assert_eq!(tii.nexta(close), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_flat_after_trend() {
+        let mut tii = Tii::new(3, 2).unwrap();
+
+        for &close in &[0.1, 0.113, 0.142, 0.442, 0.429, 0.429, 0.429] {
+            tii.nexta(close);
+        }
+        assert_eq!(tii.nexta(0.429), 50.0);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut tii = Tii::new(2, 2).unwrap();
+
+        tii.nexta(&Bar::new().close(10));
+        assert_eq!(tii.nexta(&Bar::new().close(12)), 100.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut tii = Tii::new(2, 2).unwrap();
+
+        tii.nexta(10.0);
+        tii.nexta(12.0);
+        tii.reset();
+        assert_eq!(tii.nexta(12.0), 50.0);
+        assert_eq!(tii.nexta(10.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Tii::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let tii = Tii::new(30, 15).unwrap();
+        assert_eq!(format!("{}", tii), "TII(30, 15)");
+    }
+}
@@ -0,0 +1,236 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{
+    BollingerBands, BollingerBandsOutput, KeltnerChannel, KeltnerChannelOutput, LinearRegression,
+    Maximum, Minimum,
+};
+use crate::{Close, High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// TTM Squeeze.
+///
+/// Developed by John Carter, the TTM Squeeze spots periods of low volatility that often precede
+/// a strong move. The squeeze is on when the [Bollinger Bands](struct.BollingerBands.html) are
+/// inside the [Keltner Channel](struct.KeltnerChannel.html); when it turns off the move is
+/// expected in the direction of the momentum.
+///
+/// # Formula
+///
+/// * _Squeeze_ is on when BB<sub>upper</sub> < KC<sub>upper</sub> and BB<sub>lower</sub> >
+///   KC<sub>lower</sub>
+/// * _Momentum_ = LSMA(_period_) of (Close - ((Highest High + Lowest Low) / 2 + SMA) / 2)
+///
+/// Where the bands, the channel, the highest high, the lowest low and the SMA of the close are
+/// all over _period_ bars, and the LSMA is the end point of the
+/// [linear regression](struct.LinearRegression.html) of the de-meaned close.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _bb_multiplier_ - SD multiplier of the Bollinger Bands (non-negative number). Default is 2.
+/// * _kc_multiplier_ - ATR multiplier of the Keltner Channel (non-negative number). Default is
+///   1.5.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::TtmSqueeze;
+/// use tars::{DataItema, Nexta};
+///
+/// let mut ttm = TtmSqueeze::new(3, 2.0, 1.5).unwrap();
+///
+/// let bar = |high: f64, low: f64, close: f64| {
+///     DataItema::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// ttm.nexta(&bar(11.0, 9.0, 10.0));
+/// let out = ttm.nexta(&bar(11.0, 10.0, 10.5));
+/// assert!(out.squeeze);
+/// assert!(out.momentum > 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Bollinger Bands, Wikipedia](https://en.wikipedia.org/wiki/Bollinger_Bands)
+/// * [Keltner channel, Wikipedia](https://en.wikipedia.org/wiki/Keltner_channel)
+///
+#[doc(alias = "TTM")]
+#[doc(alias = "Squeeze")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TtmSqueeze {
+    bb: BollingerBands,
+    kc: KeltnerChannel,
+    max: Maximum,
+    min: Minimum,
+    lr: LinearRegression,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TtmSqueezeOutput {
+    /// Whether the Bollinger Bands are inside the Keltner Channel.
+    pub squeeze: bool,
+    pub momentum: f64,
+}
+
+impl TtmSqueeze {
+    pub fn new(period: usize, bb_multiplier: f64, kc_multiplier: f64) -> Result<Self> {
+        Ok(Self {
+            bb: BollingerBands::new(period, bb_multiplier)?,
+            kc: KeltnerChannel::new(period, kc_multiplier)?,
+            max: Maximum::new(period)?,
+            min: Minimum::new(period)?,
+            lr: LinearRegression::new(period)?,
+        })
+    }
+
+    pub fn bb_multiplier(&self) -> f64 {
+        self.bb.multiplier()
+    }
+
+    pub fn kc_multiplier(&self) -> f64 {
+        self.kc.multiplier()
+    }
+
+    fn calculate(
+        &mut self,
+        bb: BollingerBandsOutput,
+        kc: KeltnerChannelOutput,
+        high: f64,
+        low: f64,
+        close: f64,
+    ) -> TtmSqueezeOutput {
+        let middle = (self.max.nexta(high) + self.min.nexta(low)) / 2.0;
+        let delta = close - (middle + bb.average) / 2.0;
+
+        TtmSqueezeOutput {
+            squeeze: bb.upper < kc.upper && bb.lower > kc.lower,
+            momentum: self.lr.nexta(delta).value,
+        }
+    }
+}
+
+impl Period for TtmSqueeze {
+    fn period(&self) -> usize {
+        self.lr.period()
+    }
+}
+
+impl Nexta<f64> for TtmSqueeze {
+    type Output = TtmSqueezeOutput;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let bb = self.bb.nexta(input);
+        let kc = self.kc.nexta(input);
+        self.calculate(bb, kc, input, input, input)
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for TtmSqueeze {
+    type Output = TtmSqueezeOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let bb = self.bb.nexta(input);
+        let kc = self.kc.nexta(input);
+        self.calculate(bb, kc, input.high(), input.low(), input.close())
+    }
+}
+
+impl Reset for TtmSqueeze {
+    fn reset(&mut self) {
+        self.bb.reset();
+        self.kc.reset();
+        self.max.reset();
+        self.min.reset();
+        self.lr.reset();
+    }
+}
+
+impl Default for TtmSqueeze {
+    fn default() -> Self {
+        Self::new(20, 2.0, 1.5).unwrap()
+    }
+}
+
+impl fmt::Display for TtmSqueeze {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "TTM({}, {}, {})",
+            self.lr.period(),
+            self.bb.multiplier(),
+            self.kc.multiplier()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(TtmSqueeze);
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(TtmSqueeze::new(0, 2.0, 1.5).is_err());
+        assert!(TtmSqueeze::new(20, -2.0, 1.5).is_err());
+        assert!(TtmSqueeze::new(20, 2.0, f64::NAN).is_err());
+        assert!(TtmSqueeze::new(1, 2.0, 1.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ttm = TtmSqueeze::new(3, 2.0, 1.5).unwrap();
+
+        let bars = [
+            (bar(11.0, 9.0, 10.0), true, 0.0),
+            (bar(11.0, 10.0, 10.5), true, 0.375),
+            (bar(11.2, 10.4, 11.0), true, 0.708),
+            (bar(11.6, 10.8, 11.4), true, 0.601),
+            (bar(13.0, 11.0, 12.8), true, 0.958),
+            // a large close-to-close move on a narrow bar widens the Bollinger Bands the most
+            (bar(15.1, 14.9, 15.0), false, 1.935),
+        ];
+        for (bar, squeeze, momentum) in bars.iter() {
+            let out = ttm.nexta(bar);
+            assert_eq!(out.squeeze, *squeeze);
+            assert_eq!(round(out.momentum), *momentum);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ttm = TtmSqueeze::new(3, 2.0, 1.5).unwrap();
+
+        ttm.nexta(&bar(11.0, 9.0, 10.0));
+        let out = ttm.nexta(&bar(11.0, 10.0, 10.5));
+        ttm.reset();
+        ttm.nexta(&bar(11.0, 9.0, 10.0));
+        assert_eq!(ttm.nexta(&bar(11.0, 10.0, 10.5)), out);
+    }
+
+    #[test]
+    fn test_default() {
+        TtmSqueeze::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ttm = TtmSqueeze::new(20, 2.0, 1.5).unwrap();
+        assert_eq!(format!("{}", ttm), "TTM(20, 2, 1.5)");
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{wilder, ExponentialMovingAverage as Ema};
+use crate::{Close, High, Low, Nexta, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Twiggs Money Flow (TMF).
+///
+/// Developed by Colin Twiggs as an improvement over the Chaikin Money Flow. The location of the
+/// close is measured within the true range instead of the high-low range, so gaps between bars
+/// count as buying or selling pressure, and the money flow volume and the volume are smoothed
+/// with Wilder's smoothing instead of summed over a window, which avoids jumps when a large bar
+/// leaves the window. Values range from -1 to 1; positive values indicate accumulation.
+///
+/// # Formula
+///
+/// * _TRH_ = max(High, Close<sub>prev</sub>), _TRL_ = min(Low, Close<sub>prev</sub>)
+/// * _AD_ = Volume × ((Close - TRL) - (TRH - Close)) / (TRH - TRL)
+/// * _TMF_ = W(AD) / W(Volume)
+///
+/// Where _W_ is Wilder's smoothing over _period_ bars, an EMA with α = 1 / _period_. On the first
+/// bar the true range is the high-low range. AD is 0 when the true range is 0 and TMF is 0 while
+/// the smoothed volume is 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 21.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::TwiggsMoneyFlow as Tmf;
+/// use tars::{DataItema, Nexta};
+///
+/// let mut tmf = Tmf::new(21).unwrap();
+///
+/// let bar = DataItema::builder()
+///     .open(10.0)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.5)
+///     .volume(100.0)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(tmf.nexta(&bar), 0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Twiggs Money Flow, Incredible Charts](https://www.incrediblecharts.com/indicators/twiggs_money_flow.php)
+///
+#[doc(alias = "TMF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TwiggsMoneyFlow {
+    period: usize,
+    ad: Ema,
+    volume: Ema,
+    prev_close: Option<f64>,
+}
+
+impl TwiggsMoneyFlow {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            ad: wilder(period)?,
+            volume: wilder(period)?,
+            prev_close: None,
+        })
+    }
+}
+
+impl Period for TwiggsMoneyFlow {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close + Volume> Nexta<&T> for TwiggsMoneyFlow {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        let (high, low) = match self.prev_close {
+            Some(prev) => (input.high().max(prev), input.low().min(prev)),
+            None => (input.high(), input.low()),
+        };
+        self.prev_close = Some(close);
+
+        let range = high - low;
+        let ad = if range > 0.0 {
+            input.volume() * ((close - low) - (high - close)) / range
+        } else {
+            0.0
+        };
+
+        let ad = self.ad.nexta(ad);
+        let volume = self.volume.nexta(input.volume());
+        if volume > 0.0 {
+            ad / volume
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Reset for TwiggsMoneyFlow {
+    fn reset(&mut self) {
+        self.ad.reset();
+        self.volume.reset();
+        self.prev_close = None;
+    }
+}
+
+impl Default for TwiggsMoneyFlow {
+    fn default() -> Self {
+        Self::new(21).unwrap()
+    }
+}
+
+impl fmt::Display for TwiggsMoneyFlow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TMF({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Tmf = TwiggsMoneyFlow;
+
+    fn bar(high: f64, low: f64, close: f64, volume: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close).volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Tmf::new(0).is_err());
+        assert!(Tmf::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut tmf = Tmf::new(2).unwrap();
+
+        assert_eq!(tmf.nexta(&bar(11.0, 9.0, 10.5, 100.0)), 0.5);
+        assert_eq!(round(tmf.nexta(&bar(12.0, 10.0, 10.5, 200.0))), -0.167);
+        // the gap down from 10.5 extends the true range above the high
+        assert_eq!(round(tmf.nexta(&bar(10.0, 9.0, 9.2, 100.0))), -0.393);
+    }
+
+    #[test]
+    fn test_zero_range_and_volume() {
+        let mut tmf = Tmf::new(2).unwrap();
+
+        assert_eq!(tmf.nexta(&bar(10.0, 10.0, 10.0, 100.0)), 0.0);
+        assert_eq!(tmf.nexta(&bar(10.0, 10.0, 10.0, 0.0)), 0.0);
+
+        let mut tmf = Tmf::new(2).unwrap();
+        assert_eq!(tmf.nexta(&bar(11.0, 9.0, 10.5, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut tmf = Tmf::new(2).unwrap();
+
+        tmf.nexta(&bar(11.0, 9.0, 10.5, 100.0));
+        tmf.nexta(&bar(12.0, 10.0, 10.5, 200.0));
+        tmf.reset();
+        assert_eq!(tmf.nexta(&bar(11.0, 9.0, 10.5, 100.0)), 0.5);
+    }
+
+    #[test]
+    fn test_default() {
+        Tmf::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let tmf = Tmf::new(21).unwrap();
+        assert_eq!(format!("{}", tmf), "TMF(21)");
+    }
+}
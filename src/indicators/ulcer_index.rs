@@ -0,0 +1,163 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, SimpleMovingAverage as Sma};
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ulcer Index (UI).
+///
+/// Developed by Peter Martin, the Ulcer Index measures downside volatility: the depth and the
+/// duration of the drawdowns from recent highs. Unlike the standard deviation or the ATR it is
+/// not raised by moves to the upside.
+///
+/// # Formula
+///
+/// R<sub>t</sub> = (Close<sub>t</sub> - Max(Close, _period_)<sub>t</sub>) / Max(Close, _period_)<sub>t</sub> * 100
+///
+/// UI = √(SMA(R<sup>2</sup>, _period_))
+///
+/// Where _Max(Close, period)_ is the highest close of the last _period_ bars, the current one
+/// included.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::UlcerIndex;
+/// use tars::Nexta;
+///
+/// let mut ui = UlcerIndex::new(3).unwrap();
+///
+/// assert_eq!(ui.nexta(10.0), 0.0);
+/// assert_eq!(ui.nexta(8.0), 200f64.sqrt());
+/// ```
+///
+/// # Links
+///
+/// * [Ulcer Index, Wikipedia](https://en.wikipedia.org/wiki/Ulcer_index)
+///
+#[doc(alias = "UI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct UlcerIndex {
+    max: Maximum,
+    sma: Sma,
+}
+
+impl UlcerIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            max: Maximum::new(period)?,
+            sma: Sma::new(period)?,
+        })
+    }
+}
+
+impl Period for UlcerIndex {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Nexta<f64> for UlcerIndex {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let max = self.max.nexta(input);
+        let drawdown = if max > 0.0 {
+            (input - max) / max * 100.0
+        } else {
+            0.0
+        };
+
+        self.sma.nexta(drawdown * drawdown).max(0.0).sqrt()
+    }
+}
+
+impl<T: Close> Nexta<&T> for UlcerIndex {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for UlcerIndex {
+    fn reset(&mut self) {
+        self.max.reset();
+        self.sma.reset();
+    }
+}
+
+impl Default for UlcerIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for UlcerIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "UI({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(UlcerIndex);
+
+    #[test]
+    fn test_new() {
+        assert!(UlcerIndex::new(0).is_err());
+        assert!(UlcerIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ui = UlcerIndex::new(3).unwrap();
+
+        assert_eq!(ui.nexta(10.0), 0.0);
+        assert_eq!(round(ui.nexta(8.0)), 14.142);
+        assert_eq!(round(ui.nexta(9.0)), 12.91);
+        // a new high, the drawdowns of 8 and 9 stay in the window
+        assert_eq!(round(ui.nexta(12.0)), 12.91);
+        assert_eq!(round(ui.nexta(6.0)), 29.439);
+    }
+
+    #[test]
+    fn test_next_rising() {
+        let mut ui = UlcerIndex::new(3).unwrap();
+
+        for &close in &[1.0, 2.0, 3.0, 4.0] {
+            assert_eq!(ui.nexta(&Bar::new().close(close)), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ui = UlcerIndex::new(3).unwrap();
+
+        ui.nexta(10.0);
+        ui.nexta(8.0);
+        ui.reset();
+        assert_eq!(ui.nexta(8.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        UlcerIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ui = UlcerIndex::new(14).unwrap();
+        assert_eq!(format!("{}", ui), "UI(14)");
+    }
+}
@@ -0,0 +1,272 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Up/Down Volume Ratio (UDVR).
+///
+/// Rolling ratio of the volume traded on up bars to the volume traded on down bars, a simple
+/// accumulation/distribution gauge. Values above 1 indicate accumulation, values below 1
+/// distribution.
+///
+/// # Formula
+///
+/// UDVR = Σ Up Volume / Σ Down Volume over the last _period_ bars
+///
+/// Where:
+///
+/// * _Up Volume_ - volume of a bar that closed above the previous close
+/// * _Down Volume_ - volume of a bar that closed below the previous close
+///
+/// Bars with unchanged close (including the first bar) are ignored. When there is no down volume
+/// in the window the ratio is infinite, or 1 if there is no up volume either.
+///
+/// With the log transform enabled the indicator returns ln(UDVR), which is symmetric around 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::UpDownVolumeRatio;
+/// use tars::{Nexta, DataItema};
+///
+/// fn bar(close: f64, volume: f64) -> DataItema {
+///     DataItema::builder()
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .open(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// }
+///
+/// let mut udvr = UpDownVolumeRatio::new(3).unwrap();
+///
+/// udvr.nexta(&bar(10.0, 100.0));
+/// udvr.nexta(&bar(11.0, 200.0));
+/// assert_eq!(udvr.nexta(&bar(10.0, 100.0)), 2.0);
+/// ```
+///
+#[doc(alias = "UDVR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct UpDownVolumeRatio {
+    period: usize,
+    log: bool,
+    index: usize,
+    count: usize,
+    up_volume: f64,
+    down_volume: f64,
+    // number of up and down bars in the window, the sum is zeroed at 0 to drop float residue
+    up_bars: usize,
+    down_bars: usize,
+    prev_close: Option<f64>,
+    deque: Box<[f64]>,
+}
+
+impl UpDownVolumeRatio {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                log: false,
+                index: 0,
+                count: 0,
+                up_volume: 0.0,
+                down_volume: 0.0,
+                up_bars: 0,
+                down_bars: 0,
+                prev_close: None,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+
+    /// Enables the log transform of the ratio.
+    pub fn with_log(mut self) -> Self {
+        self.log = true;
+        self
+    }
+}
+
+impl Period for UpDownVolumeRatio {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: Close + Volume> Nexta<&T> for UpDownVolumeRatio {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        // up volume is stored as a positive, down volume as a negative number
+        let signed_volume = match self.prev_close {
+            Some(prev) if input.close() > prev => input.volume(),
+            Some(prev) if input.close() < prev => -input.volume(),
+            _ => 0.0,
+        };
+        self.prev_close = Some(input.close());
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old_val = self.deque[self.index];
+            if old_val > 0.0 {
+                self.up_bars -= 1;
+                self.up_volume = if self.up_bars > 0 {
+                    self.up_volume - old_val
+                } else {
+                    0.0
+                };
+            } else if old_val < 0.0 {
+                self.down_bars -= 1;
+                self.down_volume = if self.down_bars > 0 {
+                    self.down_volume + old_val
+                } else {
+                    0.0
+                };
+            }
+        }
+
+        if signed_volume > 0.0 {
+            self.up_bars += 1;
+            self.up_volume += signed_volume;
+        } else if signed_volume < 0.0 {
+            self.down_bars += 1;
+            self.down_volume -= signed_volume;
+        }
+
+        self.deque[self.index] = signed_volume;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let ratio = if self.down_volume > 0.0 {
+            self.up_volume / self.down_volume
+        } else if self.up_volume > 0.0 {
+            f64::INFINITY
+        } else {
+            1.0
+        };
+
+        if self.log {
+            ratio.ln()
+        } else {
+            ratio
+        }
+    }
+}
+
+impl Reset for UpDownVolumeRatio {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.up_volume = 0.0;
+        self.down_volume = 0.0;
+        self.up_bars = 0;
+        self.down_bars = 0;
+        self.prev_close = None;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for UpDownVolumeRatio {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for UpDownVolumeRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.log {
+            write!(f, "UDVR({}, LOG)", self.period)
+        } else {
+            write!(f, "UDVR({})", self.period)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(close: f64, volume: f64) -> Bar {
+        Bar::new().close(close).volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(UpDownVolumeRatio::new(0).is_err());
+        assert!(UpDownVolumeRatio::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut udvr = UpDownVolumeRatio::new(3).unwrap();
+
+        assert_eq!(udvr.nexta(&bar(10.0, 100.0)), 1.0);
+        assert_eq!(udvr.nexta(&bar(11.0, 200.0)), f64::INFINITY);
+        assert_eq!(udvr.nexta(&bar(10.0, 100.0)), 2.0);
+        assert_eq!(udvr.nexta(&bar(10.5, 300.0)), 5.0);
+        assert_eq!(round(udvr.nexta(&bar(10.0, 250.0))), 0.857);
+        // unchanged close
+        assert_eq!(round(udvr.nexta(&bar(10.0, 500.0))), 1.2);
+    }
+
+    #[test]
+    fn test_next_down_bars_leave_window() {
+        let mut udvr = UpDownVolumeRatio::new(2).unwrap();
+
+        udvr.nexta(&bar(10.0, 1.0));
+        udvr.nexta(&bar(9.0, 0.1));
+        udvr.nexta(&bar(8.0, 0.2));
+        udvr.nexta(&bar(9.0, 0.3));
+        assert_eq!(udvr.nexta(&bar(10.0, 0.3)), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_next_log() {
+        let mut udvr = UpDownVolumeRatio::new(3).unwrap().with_log();
+
+        assert_eq!(udvr.nexta(&bar(10.0, 100.0)), 0.0);
+        udvr.nexta(&bar(11.0, 200.0));
+        assert_eq!(round(udvr.nexta(&bar(10.0, 100.0))), 0.693);
+        assert_eq!(round(udvr.nexta(&bar(9.0, 400.0))), -0.916);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut udvr = UpDownVolumeRatio::new(3).unwrap();
+
+        udvr.nexta(&bar(10.0, 100.0));
+        udvr.nexta(&bar(11.0, 200.0));
+
+        udvr.reset();
+        assert_eq!(udvr.nexta(&bar(9.0, 100.0)), 1.0);
+        assert_eq!(udvr.nexta(&bar(8.0, 100.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        UpDownVolumeRatio::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let udvr = UpDownVolumeRatio::new(10).unwrap();
+        assert_eq!(format!("{}", udvr), "UDVR(10)");
+        assert_eq!(format!("{}", udvr.with_log()), "UDVR(10, LOG)");
+    }
+}
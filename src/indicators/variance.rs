@@ -0,0 +1,213 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::StandardDeviation as Sd;
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Variance (VAR).
+///
+/// Returns the variance of the last n values, either of the population or of the sample. The
+/// running sums are shared with [StandardDeviation](crate::indicators::StandardDeviation), so the
+/// population variance is exactly the square of its output.
+///
+/// # Formula
+///
+/// * _Population_: σ² = Σ (x<sub>i</sub> - μ)² / N
+/// * _Sample_: s² = Σ (x<sub>i</sub> - μ)² / (N - 1)
+///
+/// Where:
+///
+/// * _μ_ - mean of the N values.
+/// * _N_ - number of probes in observation.
+/// * _x<sub>i</sub>_ - i-th observed value from N elements observation.
+///
+/// The sample variance of a single value is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+/// * _mode_ - population or sample variance. Default is population.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{Variance, VarianceMode};
+/// use tars::Nexta;
+///
+/// let mut var = Variance::new(3, VarianceMode::Population).unwrap();
+/// assert_eq!(var.nexta(10.0), 0.0);
+/// assert_eq!(var.nexta(20.0), 25.0);
+///
+/// let mut var = Variance::new(3, VarianceMode::Sample).unwrap();
+/// assert_eq!(var.nexta(10.0), 0.0);
+/// assert_eq!(var.nexta(20.0), 50.0);
+/// ```
+///
+/// # Links
+///
+/// * [Variance, Wikipedia](https://en.wikipedia.org/wiki/Variance)
+///
+#[doc(alias = "VAR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Variance {
+    mode: VarianceMode,
+    sd: Sd,
+}
+
+/// Kind of [Variance](struct.Variance.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarianceMode {
+    /// Divides by the number of values.
+    Population,
+    /// Divides by the number of values minus 1 (Bessel's correction).
+    Sample,
+}
+
+impl Variance {
+    pub fn new(period: usize, mode: VarianceMode) -> Result<Self> {
+        Ok(Self {
+            mode,
+            sd: Sd::new(period)?,
+        })
+    }
+
+    pub fn mode(&self) -> VarianceMode {
+        self.mode
+    }
+}
+
+impl Period for Variance {
+    fn period(&self) -> usize {
+        self.sd.period()
+    }
+}
+
+impl Nexta<f64> for Variance {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        self.sd.nexta(input);
+
+        let count = self.sd.count();
+        let divisor = match self.mode {
+            VarianceMode::Population => count,
+            VarianceMode::Sample if count > 1 => count - 1,
+            VarianceMode::Sample => return 0.0,
+        };
+        self.sd.sum_of_squares() / divisor as f64
+    }
+}
+
+impl<T: Close> Nexta<&T> for Variance {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for Variance {
+    fn reset(&mut self) {
+        self.sd.reset();
+    }
+}
+
+impl Default for Variance {
+    fn default() -> Self {
+        Self::new(9, VarianceMode::Population).unwrap()
+    }
+}
+
+impl fmt::Display for Variance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mode {
+            VarianceMode::Population => write!(f, "VAR({})", self.sd.period()),
+            VarianceMode::Sample => write!(f, "VAR({}, SAMPLE)", self.sd.period()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Variance);
+
+    #[test]
+    fn test_new() {
+        assert!(Variance::new(0, VarianceMode::Population).is_err());
+        assert!(Variance::new(1, VarianceMode::Sample).is_ok());
+    }
+
+    #[test]
+    fn test_next_population() {
+        let mut var = Variance::new(4, VarianceMode::Population).unwrap();
+
+        assert_eq!(var.nexta(10.0), 0.0);
+        assert_eq!(var.nexta(20.0), 25.0);
+        assert_eq!(round(var.nexta(30.0)), 66.667);
+        assert_eq!(round(var.nexta(20.0)), 50.0);
+        assert_eq!(round(var.nexta(10.0)), 50.0);
+        assert_eq!(round(var.nexta(100.0)), 1250.0);
+    }
+
+    #[test]
+    fn test_next_sample() {
+        let mut var = Variance::new(4, VarianceMode::Sample).unwrap();
+
+        assert_eq!(var.nexta(10.0), 0.0);
+        assert_eq!(var.nexta(20.0), 50.0);
+        assert_eq!(round(var.nexta(30.0)), 100.0);
+        assert_eq!(round(var.nexta(20.0)), 66.667);
+        assert_eq!(round(var.nexta(10.0)), 66.667);
+        assert_eq!(round(var.nexta(100.0)), 1666.667);
+    }
+
+    #[test]
+    fn test_matches_standard_deviation() {
+        let mut var = Variance::new(5, VarianceMode::Population).unwrap();
+        let mut sd = Sd::new(5).unwrap();
+
+        for &input in &[1.872, 1.0, 1.0, 3.5, 2.25, 1.0, 1.0, 7.0] {
+            let sd = sd.nexta(input);
+            assert_eq!(round(var.nexta(input)), round(sd * sd));
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut var = Variance::new(4, VarianceMode::Sample).unwrap();
+
+        assert_eq!(var.nexta(&Bar::new().close(10)), 0.0);
+        assert_eq!(var.nexta(&Bar::new().close(20)), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut var = Variance::new(4, VarianceMode::Population).unwrap();
+
+        var.nexta(10.0);
+        var.nexta(20.0);
+        var.reset();
+        assert_eq!(var.nexta(20.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Variance::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let var = Variance::new(5, VarianceMode::Population).unwrap();
+        assert_eq!(format!("{}", var), "VAR(5)");
+
+        let var = Variance::new(5, VarianceMode::Sample).unwrap();
+        assert_eq!(format!("{}", var), "VAR(5, SAMPLE)");
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum};
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Vertical Horizontal Filter (VHF).
+///
+/// Developed by Adam White, the VHF tells trending from ranging markets by comparing the range
+/// of the closes over the last _period_ bars with the path the close travelled to cover it. In a
+/// trend most of the movement goes in one direction and the VHF is high; in a range the moves
+/// cancel out and the VHF is low.
+///
+/// # Formula
+///
+/// VHF = (Highest Close - Lowest Close) / Σ |Close<sub>i</sub> - Close<sub>i-1</sub>|
+///
+/// Where the highest and the lowest close are taken over the last _period_ closes and the sum
+/// over the last _period_ changes. The VHF is 0 while the sum is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 28.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::VerticalHorizontalFilter as Vhf;
+/// use tars::Nexta;
+///
+/// let mut vhf = Vhf::new(3).unwrap();
+/// assert_eq!(vhf.nexta(10.0), 0.0);
+/// assert_eq!(vhf.nexta(12.0), 1.0);
+/// assert_eq!(vhf.nexta(14.0), 1.0);
+/// assert_eq!(vhf.nexta(13.0), 0.4);
+/// ```
+///
+/// # Links
+///
+/// * [Vertical Horizontal Filter, Investopedia](https://www.investopedia.com/terms/v/vertical-horizontal-filter.asp)
+///
+#[doc(alias = "VHF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VerticalHorizontalFilter {
+    period: usize,
+    max: Maximum,
+    min: Minimum,
+    prev: Option<f64>,
+    index: usize,
+    count: usize,
+    sum: f64,
+    deque: Box<[f64]>,
+}
+
+impl VerticalHorizontalFilter {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            max: Maximum::new(period)?,
+            min: Minimum::new(period)?,
+            prev: None,
+            index: 0,
+            count: 0,
+            sum: 0.0,
+            deque: vec![0.0; period].into_boxed_slice(),
+        })
+    }
+}
+
+impl Period for VerticalHorizontalFilter {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for VerticalHorizontalFilter {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let range = self.max.nexta(input) - self.min.nexta(input);
+
+        if let Some(prev) = self.prev {
+            let change = (input - prev).abs();
+            if self.count < self.period {
+                self.count += 1;
+            } else {
+                self.sum -= self.deque[self.index];
+            }
+            self.sum += change;
+            if self.sum < 0.0 {
+                self.sum = 0.0;
+            }
+
+            self.deque[self.index] = change;
+            self.index = if self.index + 1 < self.period {
+                self.index + 1
+            } else {
+                0
+            };
+        }
+        self.prev = Some(input);
+
+        if self.sum > 0.0 {
+            range / self.sum
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for VerticalHorizontalFilter {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for VerticalHorizontalFilter {
+    fn reset(&mut self) {
+        self.max.reset();
+        self.min.reset();
+        self.prev = None;
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for VerticalHorizontalFilter {
+    fn default() -> Self {
+        Self::new(28).unwrap()
+    }
+}
+
+impl fmt::Display for VerticalHorizontalFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VHF({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Vhf = VerticalHorizontalFilter;
+
+    test_indicator!(Vhf);
+
+    #[test]
+    fn test_new() {
+        assert!(Vhf::new(0).is_err());
+        assert!(Vhf::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vhf = Vhf::new(3).unwrap();
+
+        assert_eq!(vhf.nexta(10.0), 0.0);
+        assert_eq!(vhf.nexta(12.0), 1.0);
+        assert_eq!(round(vhf.nexta(11.0)), 0.667);
+        assert_eq!(vhf.nexta(14.0), 0.5);
+        assert_eq!(vhf.nexta(13.0), 0.6);
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut vhf = Vhf::new(3).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(vhf.nexta(10.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut vhf = Vhf::new(3).unwrap();
+
+        vhf.nexta(&Bar::new().close(10));
+        assert_eq!(vhf.nexta(&Bar::new().close(12)), 1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vhf = Vhf::new(3).unwrap();
+
+        vhf.nexta(10.0);
+        vhf.nexta(12.0);
+        vhf.reset();
+        assert_eq!(vhf.nexta(12.0), 0.0);
+        assert_eq!(round(vhf.nexta(11.0)), 1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Vhf::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vhf = Vhf::new(14).unwrap();
+        assert_eq!(format!("{}", vhf), "VHF(14)");
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Nexta, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume Profile (VP).
+///
+/// Distributes the volume of the last _period_ bars over price levels and reports the point of
+/// control, the price level where the most volume was traded, and the value area, the range of
+/// price levels around it where _value area_ of the volume was traded.
+///
+/// The price axis is divided into bins of _bin size_, bin _k_ covering prices from
+/// _k_ × _bin size_ up to (_k_ + 1) × _bin size_. The volume of a bar is spread evenly over the
+/// bins touched by its range from low to high. When a bar leaves the window its volume is taken
+/// out of the same bins again, so adding and removing a bar costs O(log _bins_) per bin it
+/// touches; finding the point of control and the value area is linear in the number of bins in
+/// use.
+///
+/// # Formula
+///
+/// * _POC_ - center of the bin with the most volume; on ties the lowest one.
+/// * _Value area_ - starting from the POC bin, adds the adjacent bin above or below with more
+///   volume (above on ties) until the bins hold at least _value area_ of the total volume.
+/// * _VAH_ - upper edge of the highest bin of the value area.
+/// * _VAL_ - lower edge of the lowest bin of the value area.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 20.
+/// * _bin_size_ - height of a price level (number greater than 0). Default is 1.
+/// * _value_area_ - share of the volume in the value area (number greater than 0, at most 1).
+///   Default is 0.7.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::VolumeProfile;
+/// use tars::{Nexta, DataItema};
+///
+/// let mut vp = VolumeProfile::new(3, 1.0, 0.7).unwrap();
+///
+/// let di = DataItema::builder()
+///             .open(11.0)
+///             .high(11.5)
+///             .low(11.0)
+///             .close(11.2)
+///             .volume(500.0)
+///             .build().unwrap();
+///
+/// let out = vp.nexta(&di);
+/// assert_eq!(out.poc, 11.5);
+/// assert_eq!((out.value_area_low, out.value_area_high), (11.0, 12.0));
+/// ```
+///
+#[doc(alias = "VP")]
+#[doc(alias = "POC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeProfile {
+    period: usize,
+    bin_size: f64,
+    value_area: f64,
+    index: usize,
+    count: usize,
+    deque: Box<[BarBins]>,
+    bins: BTreeMap<i64, Bin>,
+    total: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeProfileOutput {
+    /// Point of control.
+    pub poc: f64,
+    /// Value area high.
+    pub value_area_high: f64,
+    /// Value area low.
+    pub value_area_low: f64,
+}
+
+/// Bins touched by a bar and the volume put into each of them.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Default)]
+struct BarBins {
+    low: i64,
+    high: i64,
+    volume: f64,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Default)]
+struct Bin {
+    volume: f64,
+    // number of bars in the window touching the bin, the bin is dropped at 0
+    bars: usize,
+}
+
+impl VolumeProfile {
+    pub fn new(period: usize, bin_size: f64, value_area: f64) -> Result<Self> {
+        if period == 0
+            || !(bin_size.is_finite() && bin_size > 0.0)
+            || !(value_area > 0.0 && value_area <= 1.0)
+        {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            period,
+            bin_size,
+            value_area,
+            index: 0,
+            count: 0,
+            deque: vec![BarBins::default(); period].into_boxed_slice(),
+            bins: BTreeMap::new(),
+            total: 0.0,
+        })
+    }
+
+    pub fn bin_size(&self) -> f64 {
+        self.bin_size
+    }
+
+    pub fn value_area(&self) -> f64 {
+        self.value_area
+    }
+
+    fn add(&mut self, bar: BarBins) {
+        for k in bar.low..=bar.high {
+            let bin = self.bins.entry(k).or_default();
+            bin.volume += bar.volume;
+            bin.bars += 1;
+        }
+        self.total += bar.volume * (bar.high - bar.low + 1) as f64;
+    }
+
+    fn remove(&mut self, bar: BarBins) {
+        for k in bar.low..=bar.high {
+            if let Some(bin) = self.bins.get_mut(&k) {
+                bin.bars -= 1;
+                if bin.bars == 0 {
+                    self.bins.remove(&k);
+                } else {
+                    bin.volume = (bin.volume - bar.volume).max(0.0);
+                }
+            }
+        }
+        self.total = (self.total - bar.volume * (bar.high - bar.low + 1) as f64).max(0.0);
+    }
+
+    fn profile(&self) -> VolumeProfileOutput {
+        let mut poc = (0, f64::NEG_INFINITY);
+        for (&k, bin) in self.bins.iter() {
+            if bin.volume > poc.1 {
+                poc = (k, bin.volume);
+            }
+        }
+
+        let target = self.total * self.value_area;
+        let (mut low, mut high, mut volume) = (poc.0, poc.0, poc.1);
+        while volume < target {
+            let below = self.bins.range(..low).next_back();
+            let above = self.bins.range(high + 1..).next();
+            match (below, above) {
+                (Some((&k, b)), Some((_, a))) if b.volume > a.volume => {
+                    low = k;
+                    volume += b.volume;
+                }
+                (_, Some((&k, a))) => {
+                    high = k;
+                    volume += a.volume;
+                }
+                (Some((&k, b)), None) => {
+                    low = k;
+                    volume += b.volume;
+                }
+                (None, None) => break,
+            }
+        }
+
+        VolumeProfileOutput {
+            poc: (poc.0 as f64 + 0.5) * self.bin_size,
+            value_area_high: (high + 1) as f64 * self.bin_size,
+            value_area_low: low as f64 * self.bin_size,
+        }
+    }
+}
+
+impl Period for VolumeProfile {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Volume> Nexta<&T> for VolumeProfile {
+    type Output = VolumeProfileOutput;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let low = (input.low() / self.bin_size).floor() as i64;
+        let high = (input.high() / self.bin_size).floor() as i64;
+        let bar = BarBins {
+            low,
+            high,
+            volume: input.volume() / (high - low + 1) as f64,
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            self.remove(self.deque[self.index]);
+        }
+        self.add(bar);
+
+        self.deque[self.index] = bar;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        self.profile()
+    }
+}
+
+impl Reset for VolumeProfile {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = BarBins::default();
+        }
+        self.bins.clear();
+        self.total = 0.0;
+    }
+}
+
+impl Default for VolumeProfile {
+    fn default() -> Self {
+        Self::new(20, 1.0, 0.7).unwrap()
+    }
+}
+
+impl fmt::Display for VolumeProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "VP({}, {}, {})",
+            self.period, self.bin_size, self.value_area
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, volume: f64) -> Bar {
+        Bar::new().high(high).low(low).volume(volume)
+    }
+
+    fn profile(poc: f64, value_area_low: f64, value_area_high: f64) -> VolumeProfileOutput {
+        VolumeProfileOutput {
+            poc,
+            value_area_high,
+            value_area_low,
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(VolumeProfile::new(0, 1.0, 0.7).is_err());
+        assert!(VolumeProfile::new(20, 0.0, 0.7).is_err());
+        assert!(VolumeProfile::new(20, f64::INFINITY, 0.7).is_err());
+        assert!(VolumeProfile::new(20, 1.0, 0.0).is_err());
+        assert!(VolumeProfile::new(20, 1.0, 1.1).is_err());
+        assert!(VolumeProfile::new(1, 0.25, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vp = VolumeProfile::new(3, 1.0, 0.7).unwrap();
+
+        // 100 in each of the bins 10, 11 and 12, the POC is the lowest of them
+        assert_eq!(vp.nexta(&bar(12.0, 10.0, 300.0)), profile(10.5, 10.0, 13.0));
+        // bin 11 holds 300 out of 500
+        assert_eq!(vp.nexta(&bar(11.5, 11.0, 200.0)), profile(11.5, 11.0, 13.0));
+        // bins 10: 100, 11: 300, 12: 150, 13: 50
+        assert_eq!(vp.nexta(&bar(13.9, 12.0, 100.0)), profile(11.5, 11.0, 13.0));
+        // the first bar leaves: bins 11: 200, 12: 50, 13: 450
+        assert_eq!(vp.nexta(&bar(13.0, 13.0, 400.0)), profile(13.5, 12.0, 14.0));
+    }
+
+    #[test]
+    fn test_next_bin_size() {
+        let mut vp = VolumeProfile::new(2, 0.5, 1.0).unwrap();
+
+        // bins 20 and 21
+        assert_eq!(
+            vp.nexta(&bar(10.9, 10.2, 100.0)),
+            profile(10.25, 10.0, 11.0)
+        );
+        // bins 23 and 24, the gap at bin 22 is stepped over
+        assert_eq!(
+            vp.nexta(&bar(12.3, 11.5, 300.0)),
+            profile(11.75, 10.0, 12.5)
+        );
+    }
+
+    #[test]
+    fn test_zero_volume() {
+        let mut vp = VolumeProfile::new(2, 1.0, 0.7).unwrap();
+
+        assert_eq!(vp.nexta(&bar(11.0, 10.0, 0.0)), profile(10.5, 10.0, 11.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vp = VolumeProfile::new(3, 1.0, 0.7).unwrap();
+
+        vp.nexta(&bar(12.0, 10.0, 300.0));
+        vp.nexta(&bar(11.5, 11.0, 200.0));
+        vp.reset();
+
+        assert_eq!(vp.nexta(&bar(20.5, 20.0, 100.0)), profile(20.5, 20.0, 21.0));
+    }
+
+    #[test]
+    fn test_default() {
+        VolumeProfile::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vp = VolumeProfile::new(30, 0.25, 0.68).unwrap();
+        assert_eq!(format!("{}", vp), "VP(30, 0.25, 0.68)");
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Nexta, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling weighted percentile (quantile).
+///
+/// Returns the _q_-th quantile of the last _period_ values, where each value counts with its
+/// weight. Fed with bars, the values are closes weighted by volume, so a _q_ of 0.5 is the
+/// volume-weighted median price and _q_ of 0.15 and 0.85 bound the prices where 70% of the volume
+/// traded. This gives value-area style levels without the binning of the
+/// [VolumeProfile](crate::indicators::VolumeProfile).
+///
+/// # Formula
+///
+/// With the window sorted ascending by value as x<sub>0</sub> ≤ … ≤ x<sub>n-1</sub> with
+/// weights w<sub>0</sub>, …, w<sub>n-1</sub> and W = Σ w<sub>i</sub>:
+///
+/// Q = x<sub>k</sub>, where k is the smallest index with w<sub>0</sub> + … + w<sub>k</sub> ≥ _q_ × W
+///
+/// Negative weights count as 0. When the total weight of the window is 0 every value counts with
+/// the same weight. Before the window is full, n is the number of values seen so far.
+///
+/// # Parameters
+///
+/// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
+/// * _q_ - quantile (number between 0 and 1). Default value is 0.5.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::WeightedPercentile;
+/// use tars::Nexta;
+///
+/// let mut percentile = WeightedPercentile::new(5, 0.5).unwrap();
+/// percentile.nexta((10.0, 1.0));
+/// percentile.nexta((20.0, 1.0));
+/// assert_eq!(percentile.nexta((30.0, 6.0)), 30.0);
+/// ```
+///
+/// # Links
+///
+/// * [Weighted percentile, Wikipedia](https://en.wikipedia.org/wiki/Percentile#Weighted_percentile)
+///
+#[doc(alias = "Volume-weighted quantile")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WeightedPercentile {
+    period: usize,
+    q: f64,
+    index: usize,
+    count: usize,
+    deque: Box<[(f64, f64)]>,
+    sorted: Vec<(f64, f64)>,
+}
+
+impl WeightedPercentile {
+    pub fn new(period: usize, q: f64) -> Result<Self> {
+        if period == 0 || !(0.0..=1.0).contains(&q) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            period,
+            q,
+            index: 0,
+            count: 0,
+            deque: vec![(0.0, 0.0); period].into_boxed_slice(),
+            sorted: Vec::with_capacity(period),
+        })
+    }
+
+    pub fn q(&self) -> f64 {
+        self.q
+    }
+}
+
+impl Period for WeightedPercentile {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<(f64, f64)> for WeightedPercentile {
+    type Output = f64;
+
+    fn nexta(&mut self, (value, weight): (f64, f64)) -> Self::Output {
+        self.deque[self.index] = (value, weight.max(0.0));
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        self.sorted.clear();
+        self.sorted.extend_from_slice(&self.deque[..self.count]);
+        self.sorted
+            .sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        let total: f64 = self.sorted.iter().map(|&(_, w)| w).sum();
+        let equal = total <= 0.0;
+        let target = self.q * if equal { self.count as f64 } else { total };
+
+        let mut cumulative = 0.0;
+        for &(x, w) in &self.sorted {
+            cumulative += if equal { 1.0 } else { w };
+            if cumulative >= target {
+                return x;
+            }
+        }
+
+        // Rounding can leave the cumulative weight just short of the total.
+        self.sorted[self.count - 1].0
+    }
+}
+
+impl<T: Close + Volume> Nexta<&T> for WeightedPercentile {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta((input.close(), input.volume()))
+    }
+}
+
+impl Reset for WeightedPercentile {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = (0.0, 0.0);
+        }
+    }
+}
+
+impl Default for WeightedPercentile {
+    fn default() -> Self {
+        Self::new(14, 0.5).unwrap()
+    }
+}
+
+impl fmt::Display for WeightedPercentile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WPERCENTILE({}, {})", self.period, self.q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(WeightedPercentile::new(0, 0.5).is_err());
+        assert!(WeightedPercentile::new(5, -0.1).is_err());
+        assert!(WeightedPercentile::new(5, 1.1).is_err());
+        assert!(WeightedPercentile::new(5, f64::NAN).is_err());
+        assert!(WeightedPercentile::new(1, 0.0).is_ok());
+        assert!(WeightedPercentile::new(1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut percentile = WeightedPercentile::new(4, 0.5).unwrap();
+
+        assert_eq!(percentile.nexta((10.0, 1.0)), 10.0);
+        assert_eq!(percentile.nexta((20.0, 1.0)), 10.0);
+        assert_eq!(percentile.nexta((30.0, 2.0)), 20.0);
+        assert_eq!(percentile.nexta((40.0, 4.0)), 30.0);
+        // (10.0, 1.0) leaves the window
+        assert_eq!(percentile.nexta((5.0, 10.0)), 5.0);
+    }
+
+    #[test]
+    fn test_extremes() {
+        let mut min = WeightedPercentile::new(3, 0.0).unwrap();
+        let mut max = WeightedPercentile::new(3, 1.0).unwrap();
+
+        for &input in &[(5.0, 1.0), (1.0, 2.0), (3.0, 1.0), (4.0, 3.0)] {
+            min.nexta(input);
+            max.nexta(input);
+        }
+        assert_eq!(min.nexta((2.0, 1.0)), 2.0);
+        assert_eq!(max.nexta((6.0, 0.5)), 6.0);
+    }
+
+    #[test]
+    fn test_zero_weights() {
+        let mut percentile = WeightedPercentile::new(3, 0.5).unwrap();
+
+        percentile.nexta((3.0, 0.0));
+        percentile.nexta((1.0, -5.0));
+        assert_eq!(percentile.nexta((2.0, 0.0)), 2.0);
+        assert_eq!(percentile.nexta((10.0, 1.0)), 10.0);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut percentile = WeightedPercentile::new(3, 0.5).unwrap();
+
+        percentile.nexta(&Bar::new().close(10).volume(100.0));
+        percentile.nexta(&Bar::new().close(12).volume(50.0));
+        assert_eq!(percentile.nexta(&Bar::new().close(11).volume(20.0)), 10.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut percentile = WeightedPercentile::new(3, 0.5).unwrap();
+
+        percentile.nexta((10.0, 5.0));
+        percentile.reset();
+        assert_eq!(percentile.nexta((4.0, 1.0)), 4.0);
+        assert_eq!(percentile.nexta((8.0, 2.0)), 8.0);
+    }
+
+    #[test]
+    fn test_default() {
+        WeightedPercentile::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let percentile = WeightedPercentile::new(10, 0.25).unwrap();
+        assert_eq!(format!("{}", percentile), "WPERCENTILE(10, 0.25)");
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams Fractals.
+///
+/// Developed by Bill Williams, a fractal marks a local turning point: an up fractal is a bar
+/// whose high is higher than the highs of the _period_ bars on each side, a down fractal is a
+/// bar whose low is lower than the lows of the _period_ bars on each side. Since the bars after
+/// the fractal have to be known, a fractal is only confirmed _period_ bars later; `nexta` returns
+/// the [Fractal](struct.Fractal.html) on the bar that confirms it, and `None` otherwise.
+///
+/// For `f64` input both the highs and the lows use the given value.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars on each side of the fractal (integer greater than 0). Default is
+///   2, the classic five-bar pattern.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::WilliamsFractals;
+/// use tars::Nexta;
+///
+/// let mut fractals = WilliamsFractals::new(2).unwrap();
+///
+/// assert_eq!(fractals.nexta(10.0), None);
+/// assert_eq!(fractals.nexta(11.0), None);
+/// assert_eq!(fractals.nexta(13.0), None);
+/// assert_eq!(fractals.nexta(12.0), None);
+///
+/// let fractal = fractals.nexta(11.5).unwrap();
+/// assert_eq!(fractal.up, Some(13.0));
+/// assert_eq!(fractal.down, None);
+/// assert_eq!(fractal.offset, 2);
+/// ```
+///
+/// # Links
+///
+/// * [Fractal, Investopedia](https://www.investopedia.com/terms/f/fractal.asp)
+///
+#[doc(alias = "Fractals")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WilliamsFractals {
+    period: usize,
+    index: usize,
+    count: usize,
+    highs: Box<[f64]>,
+    lows: Box<[f64]>,
+}
+
+/// Confirmed fractal. At least one of `up` and `down` is set; a bar with a wide range can be
+/// both.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fractal {
+    /// High of the up fractal bar.
+    pub up: Option<f64>,
+    /// Low of the down fractal bar.
+    pub down: Option<f64>,
+    /// Number of bars between the fractal and the bar that confirmed it.
+    pub offset: usize,
+}
+
+impl WilliamsFractals {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                highs: vec![0.0; 2 * period + 1].into_boxed_slice(),
+                lows: vec![0.0; 2 * period + 1].into_boxed_slice(),
+            }),
+        }
+    }
+
+    fn calculate(&mut self, high: f64, low: f64) -> Option<Fractal> {
+        let len = self.highs.len();
+
+        self.highs[self.index] = high;
+        self.lows[self.index] = low;
+        self.index = if self.index + 1 < len {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < len {
+            self.count += 1;
+            if self.count < len {
+                return None;
+            }
+        }
+
+        // the next slot holds the oldest bar, the candidate is in the middle
+        let middle = (self.index + self.period) % len;
+        let others = (0..len).filter(|&i| i != middle);
+
+        let mut is_up = true;
+        let mut is_down = true;
+        for i in others {
+            is_up &= self.highs[i] < self.highs[middle];
+            is_down &= self.lows[i] > self.lows[middle];
+        }
+
+        if !is_up && !is_down {
+            return None;
+        }
+
+        Some(Fractal {
+            up: if is_up {
+                Some(self.highs[middle])
+            } else {
+                None
+            },
+            down: if is_down {
+                Some(self.lows[middle])
+            } else {
+                None
+            },
+            offset: self.period,
+        })
+    }
+}
+
+impl Period for WilliamsFractals {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Nexta<f64> for WilliamsFractals {
+    type Output = Option<Fractal>;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        self.calculate(input, input)
+    }
+}
+
+impl<T: High + Low> Nexta<&T> for WilliamsFractals {
+    type Output = Option<Fractal>;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.calculate(input.high(), input.low())
+    }
+}
+
+impl Reset for WilliamsFractals {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.highs.len() {
+            self.highs[i] = 0.0;
+            self.lows[i] = 0.0;
+        }
+    }
+}
+
+impl Default for WilliamsFractals {
+    fn default() -> Self {
+        Self::new(2).unwrap()
+    }
+}
+
+impl fmt::Display for WilliamsFractals {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FRACTALS({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(WilliamsFractals);
+
+    fn bar(high: f64, low: f64) -> Bar {
+        Bar::new().high(high).low(low)
+    }
+
+    fn fractal(up: Option<f64>, down: Option<f64>, offset: usize) -> Option<Fractal> {
+        Some(Fractal { up, down, offset })
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(WilliamsFractals::new(0).is_err());
+        assert!(WilliamsFractals::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut fractals = WilliamsFractals::new(2).unwrap();
+
+        assert_eq!(fractals.nexta(&bar(10.0, 9.0)), None);
+        assert_eq!(fractals.nexta(&bar(11.0, 10.0)), None);
+        assert_eq!(fractals.nexta(&bar(13.0, 11.0)), None);
+        assert_eq!(fractals.nexta(&bar(12.0, 10.5)), None);
+        // 13.0 is higher than the two highs on each side
+        assert_eq!(
+            fractals.nexta(&bar(11.5, 9.5)),
+            fractal(Some(13.0), None, 2)
+        );
+        assert_eq!(fractals.nexta(&bar(11.0, 8.0)), None);
+        assert_eq!(fractals.nexta(&bar(12.5, 9.0)), None);
+        // 8.0 is lower than the two lows on each side
+        assert_eq!(
+            fractals.nexta(&bar(13.5, 10.0)),
+            fractal(None, Some(8.0), 2)
+        );
+    }
+
+    #[test]
+    fn test_next_equal_highs() {
+        let mut fractals = WilliamsFractals::new(1).unwrap();
+
+        fractals.nexta(10.0);
+        fractals.nexta(12.0);
+        assert_eq!(fractals.nexta(12.0), None);
+        assert_eq!(fractals.nexta(11.0), None);
+    }
+
+    #[test]
+    fn test_next_both() {
+        let mut fractals = WilliamsFractals::new(1).unwrap();
+
+        fractals.nexta(&bar(11.0, 9.0));
+        fractals.nexta(&bar(13.0, 7.0));
+        assert_eq!(
+            fractals.nexta(&bar(12.0, 8.0)),
+            fractal(Some(13.0), Some(7.0), 1)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fractals = WilliamsFractals::new(1).unwrap();
+
+        fractals.nexta(10.0);
+        fractals.nexta(12.0);
+        fractals.reset();
+
+        assert_eq!(fractals.nexta(11.0), None);
+        assert_eq!(fractals.nexta(9.0), None);
+        assert_eq!(fractals.nexta(10.0), fractal(None, Some(9.0), 1));
+    }
+
+    #[test]
+    fn test_default() {
+        WilliamsFractals::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let fractals = WilliamsFractals::default();
+        assert_eq!(format!("{}", fractals), "FRACTALS(2)");
+    }
+}
@@ -10,7 +10,6 @@ use serde::{Deserialize, Serialize};
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0)
-
 #[doc(alias = "WEMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -137,7 +136,6 @@ impl fmt::Display for WindowedExponentialMovingAverage {
 mod tests {
     use super::*;
     use crate::indicators::ExponentialMovingAverage;
-    use crate::test_helper::*;
 
     #[test]
     fn check_against_ema() {
@@ -0,0 +1,164 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::StandardDeviation as Sd;
+use crate::{Close, Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling z-score.
+///
+/// Returns how many standard deviations the current value is away from the mean of the last n
+/// values, the current one included. It is the usual normalization step of mean-reversion
+/// systems and machine learning features.
+///
+/// # Formula
+///
+/// Z = (x - μ) / σ
+///
+/// Where _μ_ and _σ_ are the mean and the [standard deviation](struct.StandardDeviation.html) of
+/// the last _period_ values. When _σ_ is 0 the z-score is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::ZScore;
+/// use tars::Nexta;
+///
+/// let mut z = ZScore::new(3).unwrap();
+/// assert_eq!(z.nexta(10.0), 0.0);
+/// assert_eq!(z.nexta(20.0), 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Standard score, Wikipedia](https://en.wikipedia.org/wiki/Standard_score)
+///
+#[doc(alias = "Z-Score")]
+#[doc(alias = "Standard score")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ZScore {
+    sd: Sd,
+}
+
+impl ZScore {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sd: Sd::new(period)?,
+        })
+    }
+}
+
+impl Period for ZScore {
+    fn period(&self) -> usize {
+        self.sd.period()
+    }
+}
+
+impl Nexta<f64> for ZScore {
+    type Output = f64;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let sd = self.sd.nexta(input);
+        if sd == 0.0 {
+            0.0
+        } else {
+            (input - self.sd.mean()) / sd
+        }
+    }
+}
+
+impl<T: Close> Nexta<&T> for ZScore {
+    type Output = f64;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        self.nexta(input.close())
+    }
+}
+
+impl Reset for ZScore {
+    fn reset(&mut self) {
+        self.sd.reset();
+    }
+}
+
+impl Default for ZScore {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for ZScore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ZSCORE({})", self.sd.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(ZScore);
+
+    #[test]
+    fn test_new() {
+        assert!(ZScore::new(0).is_err());
+        assert!(ZScore::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut z = ZScore::new(4).unwrap();
+
+        assert_eq!(z.nexta(10.0), 0.0);
+        assert_eq!(z.nexta(20.0), 1.0);
+        assert_eq!(round(z.nexta(30.0)), 1.225);
+        assert_eq!(round(z.nexta(20.0)), 0.0);
+        assert_eq!(round(z.nexta(10.0)), -1.414);
+        assert_eq!(round(z.nexta(100.0)), 1.697);
+    }
+
+    #[test]
+    fn test_next_same_values() {
+        let mut z = ZScore::new(3).unwrap();
+
+        assert_eq!(z.nexta(4.2), 0.0);
+        assert_eq!(z.nexta(4.2), 0.0);
+        assert_eq!(z.nexta(4.2), 0.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut z = ZScore::new(4).unwrap();
+
+        assert_eq!(z.nexta(&Bar::new().close(10)), 0.0);
+        assert_eq!(z.nexta(&Bar::new().close(20)), 1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut z = ZScore::new(4).unwrap();
+
+        z.nexta(10.0);
+        z.nexta(20.0);
+        z.reset();
+        assert_eq!(z.nexta(30.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        ZScore::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let z = ZScore::new(14).unwrap();
+        assert_eq!(format!("{}", z), "ZSCORE(14)");
+    }
+}
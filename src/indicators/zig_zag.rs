@@ -0,0 +1,355 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::AverageTrueRange as Atr;
+use crate::{Close, High, Low, Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// ZigZag.
+///
+/// Filters out price moves smaller than the reversal threshold and reports the confirmed swing
+/// highs and lows. A swing high is confirmed once the price falls from the highest high by at
+/// least the threshold, a swing low once the price rises from the lowest low by at least the
+/// threshold. Since a swing is only known in hindsight, `nexta` returns the
+/// [SwingPoint](struct.SwingPoint.html) on the bar that confirms it, together with the number of
+/// bars between the swing and the current bar.
+///
+/// For bars the swing highs are taken from the high and the swing lows from the low prices, for
+/// `f64` input both use the given value.
+///
+/// # Threshold
+///
+/// * [Percentage](enum.ZigZagThreshold.html#variant.Percentage) - percentage of the extreme price,
+///   e.g. 5.0 for 5%.
+/// * [Atr](enum.ZigZagThreshold.html#variant.Atr) - the current value of
+///   [ATR](struct.AverageTrueRange.html) with the given period. Until _period_ inputs are received
+///   no swing is confirmed.
+///
+/// # Parameters
+///
+/// * _threshold_ - reversal threshold. Default is 5%.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::{SwingKind, ZigZag, ZigZagThreshold};
+/// use tars::Nexta;
+///
+/// let mut zigzag = ZigZag::new(ZigZagThreshold::Percentage(10.0)).unwrap();
+///
+/// assert_eq!(zigzag.nexta(100.0), None);
+/// assert_eq!(zigzag.nexta(104.0), None);
+///
+/// let swing = zigzag.nexta(112.0).unwrap();
+/// assert_eq!(swing.kind, SwingKind::Low);
+/// assert_eq!(swing.price, 100.0);
+/// assert_eq!(swing.offset, 2);
+/// ```
+///
+/// # Links
+///
+/// * [ZigZag, Investopedia](https://www.investopedia.com/terms/z/zig_zag_indicator.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ZigZag {
+    threshold: ZigZagThreshold,
+    atr: Option<Atr>,
+    index: usize,
+    direction: Option<SwingKind>,
+    high: (f64, usize),
+    low: (f64, usize),
+}
+
+/// Reversal threshold of [ZigZag](struct.ZigZag.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZigZagThreshold {
+    /// Percentage of the extreme price (number greater than 0).
+    Percentage(f64),
+    /// ATR of the given period (integer greater than 0).
+    Atr(usize),
+}
+
+/// Kind of a swing point.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwingKind {
+    High,
+    Low,
+}
+
+/// Confirmed swing point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwingPoint {
+    pub kind: SwingKind,
+    pub price: f64,
+    /// Number of bars between the swing and the bar that confirmed it.
+    pub offset: usize,
+}
+
+impl ZigZag {
+    pub fn new(threshold: ZigZagThreshold) -> Result<Self> {
+        let atr = match threshold {
+            ZigZagThreshold::Percentage(percentage) => {
+                if !(percentage.is_finite() && percentage > 0.0) {
+                    return Err(TaError::InvalidParameter);
+                }
+                None
+            }
+            ZigZagThreshold::Atr(period) => Some(Atr::new(period)?),
+        };
+
+        Ok(Self {
+            threshold,
+            atr,
+            index: 0,
+            direction: None,
+            high: (f64::NEG_INFINITY, 0),
+            low: (f64::INFINITY, 0),
+        })
+    }
+
+    pub fn threshold(&self) -> ZigZagThreshold {
+        self.threshold
+    }
+
+    /// Returns the direction of the current swing: [High](enum.SwingKind.html#variant.High)
+    /// while looking for the next swing high, [Low](enum.SwingKind.html#variant.Low) while
+    /// looking for the next swing low and `None` before the first swing is confirmed.
+    pub fn direction(&self) -> Option<SwingKind> {
+        self.direction
+    }
+
+    fn distance(&self, price: f64, atr: Option<f64>) -> Option<f64> {
+        match (self.threshold, atr) {
+            (ZigZagThreshold::Percentage(percentage), _) => Some(price.abs() * percentage / 100.0),
+            (ZigZagThreshold::Atr(period), Some(atr)) if self.index >= period => Some(atr),
+            _ => None,
+        }
+    }
+
+    fn falls_from_high(&self, low: f64, atr: Option<f64>) -> bool {
+        matches!(self.distance(self.high.0, atr), Some(d) if low <= self.high.0 - d)
+    }
+
+    fn rises_from_low(&self, high: f64, atr: Option<f64>) -> bool {
+        matches!(self.distance(self.low.0, atr), Some(d) if high >= self.low.0 + d)
+    }
+
+    fn calculate(&mut self, high: f64, low: f64, atr: Option<f64>) -> Option<SwingPoint> {
+        self.index += 1;
+        let index = self.index;
+
+        let new_high = high > self.high.0;
+        let new_low = low < self.low.0;
+
+        let confirmed = match self.direction {
+            None => {
+                if new_high {
+                    self.high = (high, index);
+                }
+                if new_low {
+                    self.low = (low, index);
+                }
+
+                if self.falls_from_high(low, atr) {
+                    Some(SwingKind::High)
+                } else if self.rises_from_low(high, atr) {
+                    Some(SwingKind::Low)
+                } else {
+                    None
+                }
+            }
+            Some(SwingKind::High) if new_high => {
+                self.high = (high, index);
+                None
+            }
+            Some(SwingKind::High) if self.falls_from_high(low, atr) => Some(SwingKind::High),
+            Some(SwingKind::Low) if new_low => {
+                self.low = (low, index);
+                None
+            }
+            Some(SwingKind::Low) if self.rises_from_low(high, atr) => Some(SwingKind::Low),
+            Some(_) => None,
+        };
+
+        confirmed.map(|kind| {
+            let (price, swing_index) = match kind {
+                SwingKind::High => {
+                    let swing = self.high;
+                    self.direction = Some(SwingKind::Low);
+                    self.low = (low, index);
+                    swing
+                }
+                SwingKind::Low => {
+                    let swing = self.low;
+                    self.direction = Some(SwingKind::High);
+                    self.high = (high, index);
+                    swing
+                }
+            };
+
+            SwingPoint {
+                kind,
+                price,
+                offset: index - swing_index,
+            }
+        })
+    }
+}
+
+impl Nexta<f64> for ZigZag {
+    type Output = Option<SwingPoint>;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let atr = self.atr.as_mut().map(|atr| atr.nexta(input));
+        self.calculate(input, input, atr)
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for ZigZag {
+    type Output = Option<SwingPoint>;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let atr = self.atr.as_mut().map(|atr| atr.nexta(input));
+        self.calculate(input.high(), input.low(), atr)
+    }
+}
+
+impl Reset for ZigZag {
+    fn reset(&mut self) {
+        if let Some(atr) = self.atr.as_mut() {
+            atr.reset();
+        }
+        self.index = 0;
+        self.direction = None;
+        self.high = (f64::NEG_INFINITY, 0);
+        self.low = (f64::INFINITY, 0);
+    }
+}
+
+impl Default for ZigZag {
+    fn default() -> Self {
+        Self::new(ZigZagThreshold::Percentage(5.0)).unwrap()
+    }
+}
+
+impl fmt::Display for ZigZag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.threshold {
+            ZigZagThreshold::Percentage(percentage) => write!(f, "ZIGZAG({}%)", percentage),
+            ZigZagThreshold::Atr(period) => write!(f, "ZIGZAG(ATR({}))", period),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn swing(kind: SwingKind, price: f64, offset: usize) -> Option<SwingPoint> {
+        Some(SwingPoint {
+            kind,
+            price,
+            offset,
+        })
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ZigZag::new(ZigZagThreshold::Percentage(0.0)).is_err());
+        assert!(ZigZag::new(ZigZagThreshold::Percentage(-1.0)).is_err());
+        assert!(ZigZag::new(ZigZagThreshold::Percentage(f64::INFINITY)).is_err());
+        assert!(ZigZag::new(ZigZagThreshold::Atr(0)).is_err());
+        assert!(ZigZag::new(ZigZagThreshold::Percentage(1.0)).is_ok());
+        assert!(ZigZag::new(ZigZagThreshold::Atr(1)).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut zigzag = ZigZag::new(ZigZagThreshold::Percentage(10.0)).unwrap();
+
+        assert_eq!(zigzag.nexta(100.0), None);
+        assert_eq!(zigzag.nexta(105.0), None);
+        assert_eq!(zigzag.nexta(110.0), swing(SwingKind::Low, 100.0, 2));
+        assert_eq!(zigzag.direction(), Some(SwingKind::High));
+        assert_eq!(zigzag.nexta(120.0), None);
+        assert_eq!(zigzag.nexta(110.0), None);
+        assert_eq!(zigzag.nexta(108.0), swing(SwingKind::High, 120.0, 2));
+        assert_eq!(zigzag.direction(), Some(SwingKind::Low));
+        assert_eq!(zigzag.nexta(95.0), None);
+        assert_eq!(zigzag.nexta(104.0), None);
+        assert_eq!(zigzag.nexta(105.0), swing(SwingKind::Low, 95.0, 2));
+    }
+
+    #[test]
+    fn test_next_first_swing_high() {
+        let mut zigzag = ZigZag::new(ZigZagThreshold::Percentage(10.0)).unwrap();
+
+        assert_eq!(zigzag.nexta(100.0), None);
+        assert_eq!(zigzag.nexta(95.0), None);
+        assert_eq!(zigzag.nexta(90.0), swing(SwingKind::High, 100.0, 2));
+    }
+
+    #[test]
+    fn test_next_atr() {
+        let mut zigzag = ZigZag::new(ZigZagThreshold::Atr(2)).unwrap();
+
+        // ATR = 0, warming up
+        assert_eq!(zigzag.nexta(10.0), None);
+        // ATR = 0.667
+        assert_eq!(zigzag.nexta(11.0), swing(SwingKind::Low, 10.0, 1));
+        // ATR = 0.222
+        assert_eq!(zigzag.nexta(11.0), None);
+        // ATR = 0.741
+        assert_eq!(zigzag.nexta(10.0), swing(SwingKind::High, 11.0, 2));
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut zigzag = ZigZag::new(ZigZagThreshold::Percentage(10.0)).unwrap();
+
+        let bar1 = Bar::new().high(102).low(98).close(100);
+        let bar2 = Bar::new().high(112).low(104).close(110);
+        let bar3 = Bar::new().high(111).low(108).close(109);
+        let bar4 = Bar::new().high(104).low(100).close(101);
+
+        assert_eq!(zigzag.nexta(&bar1), None);
+        // 112 >= 98 + 9.8
+        assert_eq!(zigzag.nexta(&bar2), swing(SwingKind::Low, 98.0, 1));
+        assert_eq!(zigzag.nexta(&bar3), None);
+        // 100 <= 112 - 11.2
+        assert_eq!(zigzag.nexta(&bar4), swing(SwingKind::High, 112.0, 2));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut zigzag = ZigZag::new(ZigZagThreshold::Percentage(10.0)).unwrap();
+
+        zigzag.nexta(100.0);
+        zigzag.nexta(110.0);
+        assert_eq!(zigzag.direction(), Some(SwingKind::High));
+
+        zigzag.reset();
+        assert_eq!(zigzag.direction(), None);
+        assert_eq!(zigzag.nexta(50.0), None);
+        assert_eq!(zigzag.nexta(56.0), swing(SwingKind::Low, 50.0, 1));
+    }
+
+    #[test]
+    fn test_default() {
+        ZigZag::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let zigzag = ZigZag::new(ZigZagThreshold::Percentage(2.5)).unwrap();
+        assert_eq!(format!("{}", zigzag), "ZIGZAG(2.5%)");
+
+        let zigzag = ZigZag::new(ZigZagThreshold::Atr(14)).unwrap();
+        assert_eq!(format!("{}", zigzag), "ZIGZAG(ATR(14))");
+    }
+}
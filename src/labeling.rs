@@ -0,0 +1,434 @@
+//! Labels for supervised learning.
+//!
+//! [TripleBarrierLabeler](struct.TripleBarrierLabeler.html) labels entry events by the first of
+//! three barriers the price touches afterwards: a profit-taking barrier above the entry price, a
+//! stop-loss barrier below it and a time barrier after a maximum holding period.
+//!
+//! # Example
+//!
+//! ```
+//! use tars::indicators::{CusumEvent, CusumFilter};
+//! use tars::labeling::{BarrierScale, TripleBarrierLabeler};
+//! use tars::synthetic::{Process, SyntheticBars};
+//! use tars::Nexta;
+//!
+//! let process = Process::Gbm {
+//!     drift: 0.0,
+//!     volatility: 0.01,
+//! };
+//! let bars = SyntheticBars::new(process, 100.0, 7).unwrap();
+//!
+//! let mut cusum = CusumFilter::new(3.0).unwrap();
+//! let mut labeler = TripleBarrierLabeler::new(2.0, 1.0, 20, BarrierScale::Atr(14)).unwrap();
+//!
+//! let mut labels = Vec::new();
+//! for bar in bars.take(1000) {
+//!     labels.extend(labeler.nexta(&bar));
+//!     if cusum.nexta(&bar) == Some(CusumEvent::Up) {
+//!         labeler.enter();
+//!     }
+//! }
+//! assert!(!labels.is_empty());
+//! ```
+
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::AverageTrueRange;
+use crate::{Close, High, Low, Nexta, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Unit of the profit-taking and stop-loss widths of
+/// [TripleBarrierLabeler](struct.TripleBarrierLabeler.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BarrierScale {
+    /// Percentage of the entry price, e.g. 2.0 for 2%.
+    Percentage,
+    /// Multiple of the [ATR](../indicators/struct.AverageTrueRange.html) with the given period
+    /// (integer greater than 0) at the entry bar.
+    Atr(usize),
+}
+
+/// Barrier that ended a trade.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Barrier {
+    ProfitTaking,
+    StopLoss,
+    Time,
+}
+
+/// Label of an entry event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarrierLabel {
+    /// Index of the entry bar, counting from 0.
+    pub entry: usize,
+    /// Index of the bar that touched the barrier.
+    pub exit: usize,
+    pub barrier: Barrier,
+    /// Return from the entry price to the exit price: the barrier price for the profit-taking
+    /// and stop-loss barriers, the close for the time barrier.
+    pub ret: f64,
+    /// 1 for the profit-taking barrier, -1 for the stop-loss barrier and the sign of the return
+    /// (or 0) for the time barrier.
+    pub label: i8,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct OpenEntry {
+    index: usize,
+    price: f64,
+    upper: f64,
+    lower: f64,
+}
+
+/// Triple-barrier labeler.
+///
+/// Labels entry events the way described by Marcos López de Prado in _Advances in Financial
+/// Machine Learning_. Feed every bar to `nexta` and call `enter` right after the bar on which an
+/// entry event happens; the entry price is the close of that bar. Every following bar is checked
+/// against the barriers of all the open entries, and `nexta` returns the labels of the entries
+/// it closed.
+///
+/// # Barriers
+///
+/// * _Profit-taking_ - entry price + _profit taking_ × unit, touched when the high reaches it.
+/// * _Stop-loss_ - entry price - _stop loss_ × unit, touched when the low reaches it.
+/// * _Time_ - the close of the _max holding_-th bar after the entry.
+///
+/// The unit is given by the [BarrierScale](enum.BarrierScale.html). When a bar touches both the
+/// profit-taking and the stop-loss barriers, the stop-loss is assumed to be touched first.
+///
+/// # Parameters
+///
+/// * _profit_taking_ - width of the profit-taking barrier (number greater than 0).
+/// * _stop_loss_ - width of the stop-loss barrier (number greater than 0).
+/// * _max_holding_ - number of bars until the time barrier (integer greater than 0).
+/// * _scale_ - unit of the barrier widths.
+///
+/// # Example
+///
+/// ```
+/// use tars::labeling::{Barrier, BarrierScale, TripleBarrierLabeler};
+/// use tars::{DataItema, Nexta};
+///
+/// let mut labeler = TripleBarrierLabeler::new(5.0, 2.0, 10, BarrierScale::Percentage).unwrap();
+///
+/// let bar = |high: f64, low: f64, close: f64| {
+///     DataItema::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// labeler.nexta(&bar(101.0, 99.0, 100.0));
+/// labeler.enter();
+///
+/// assert!(labeler.nexta(&bar(103.0, 100.0, 102.0)).is_empty());
+/// let labels = labeler.nexta(&bar(106.0, 101.0, 105.0));
+/// assert_eq!(labels[0].barrier, Barrier::ProfitTaking);
+/// assert_eq!(labels[0].label, 1);
+/// ```
+///
+#[doc(alias = "Triple Barrier")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TripleBarrierLabeler {
+    profit_taking: f64,
+    stop_loss: f64,
+    max_holding: usize,
+    scale: BarrierScale,
+    atr: Option<AverageTrueRange>,
+    count: usize,
+    width: f64,
+    close: f64,
+    entries: Vec<OpenEntry>,
+}
+
+impl TripleBarrierLabeler {
+    pub fn new(
+        profit_taking: f64,
+        stop_loss: f64,
+        max_holding: usize,
+        scale: BarrierScale,
+    ) -> Result<Self> {
+        let valid = |width: f64| width.is_finite() && width > 0.0;
+        if !valid(profit_taking) || !valid(stop_loss) || max_holding == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let atr = match scale {
+            BarrierScale::Percentage => None,
+            BarrierScale::Atr(period) => Some(AverageTrueRange::new(period)?),
+        };
+
+        Ok(Self {
+            profit_taking,
+            stop_loss,
+            max_holding,
+            scale,
+            atr,
+            count: 0,
+            width: 0.0,
+            close: 0.0,
+            entries: Vec::new(),
+        })
+    }
+
+    pub fn profit_taking(&self) -> f64 {
+        self.profit_taking
+    }
+
+    pub fn stop_loss(&self) -> f64 {
+        self.stop_loss
+    }
+
+    pub fn max_holding(&self) -> usize {
+        self.max_holding
+    }
+
+    pub fn scale(&self) -> BarrierScale {
+        self.scale
+    }
+
+    /// Returns the number of entries that have not touched a barrier yet.
+    pub fn open_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Opens an entry at the close of the last bar. Does nothing before the first bar.
+    pub fn enter(&mut self) {
+        if self.count == 0 {
+            return;
+        }
+
+        let price = self.close;
+        let unit = match self.scale {
+            BarrierScale::Percentage => price.abs() / 100.0,
+            BarrierScale::Atr(_) => self.width,
+        };
+        self.entries.push(OpenEntry {
+            index: self.count - 1,
+            price,
+            upper: price + self.profit_taking * unit,
+            lower: price - self.stop_loss * unit,
+        });
+    }
+}
+
+impl<T: High + Low + Close> Nexta<&T> for TripleBarrierLabeler {
+    type Output = Vec<BarrierLabel>;
+
+    fn nexta(&mut self, input: &T) -> Self::Output {
+        let index = self.count;
+        self.count += 1;
+        self.close = input.close();
+        if let Some(atr) = self.atr.as_mut() {
+            self.width = atr.nexta(input);
+        }
+
+        let max_holding = self.max_holding;
+        let mut labels = Vec::new();
+        self.entries.retain(|entry| {
+            let (barrier, exit_price) = if input.low() <= entry.lower {
+                (Barrier::StopLoss, entry.lower)
+            } else if input.high() >= entry.upper {
+                (Barrier::ProfitTaking, entry.upper)
+            } else if index - entry.index >= max_holding {
+                (Barrier::Time, input.close())
+            } else {
+                return true;
+            };
+
+            let ret = exit_price / entry.price - 1.0;
+            let label = match barrier {
+                Barrier::ProfitTaking => 1,
+                Barrier::StopLoss => -1,
+                Barrier::Time if ret > 0.0 => 1,
+                Barrier::Time if ret < 0.0 => -1,
+                Barrier::Time => 0,
+            };
+            labels.push(BarrierLabel {
+                entry: entry.index,
+                exit: index,
+                barrier,
+                ret,
+                label,
+            });
+            false
+        });
+
+        labels
+    }
+}
+
+impl Reset for TripleBarrierLabeler {
+    fn reset(&mut self) {
+        if let Some(atr) = self.atr.as_mut() {
+            atr.reset();
+        }
+        self.count = 0;
+        self.width = 0.0;
+        self.close = 0.0;
+        self.entries.clear();
+    }
+}
+
+impl fmt::Display for TripleBarrierLabeler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.scale {
+            BarrierScale::Percentage => write!(
+                f,
+                "TBL({}%, {}%, {})",
+                self.profit_taking, self.stop_loss, self.max_holding
+            ),
+            BarrierScale::Atr(period) => write!(
+                f,
+                "TBL({}, {}, {}, ATR({}))",
+                self.profit_taking, self.stop_loss, self.max_holding, period
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    fn percentage(profit_taking: f64, stop_loss: f64, max_holding: usize) -> TripleBarrierLabeler {
+        TripleBarrierLabeler::new(
+            profit_taking,
+            stop_loss,
+            max_holding,
+            BarrierScale::Percentage,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let new = TripleBarrierLabeler::new;
+
+        assert!(new(0.0, 1.0, 10, BarrierScale::Percentage).is_err());
+        assert!(new(1.0, -1.0, 10, BarrierScale::Percentage).is_err());
+        assert!(new(1.0, 1.0, 0, BarrierScale::Percentage).is_err());
+        assert!(new(1.0, 1.0, 10, BarrierScale::Atr(0)).is_err());
+        assert!(new(f64::NAN, 1.0, 10, BarrierScale::Percentage).is_err());
+        assert!(new(1.0, 1.0, 1, BarrierScale::Atr(1)).is_ok());
+    }
+
+    #[test]
+    fn test_profit_taking_and_stop_loss() {
+        let mut labeler = percentage(5.0, 2.0, 10);
+
+        labeler.nexta(&bar(101.0, 99.0, 100.0));
+        labeler.enter();
+        labeler.nexta(&bar(102.0, 100.0, 101.0));
+        labeler.enter();
+        assert_eq!(labeler.open_entries(), 2);
+
+        // the stop-loss of the second entry is 98.98
+        let labels = labeler.nexta(&bar(100.0, 98.5, 99.0));
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].entry, 1);
+        assert_eq!(labels[0].exit, 2);
+        assert_eq!(labels[0].barrier, Barrier::StopLoss);
+        assert_eq!(round(labels[0].ret), -0.02);
+        assert_eq!(labels[0].label, -1);
+
+        let labels = labeler.nexta(&bar(105.5, 99.0, 105.0));
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].entry, 0);
+        assert_eq!(labels[0].barrier, Barrier::ProfitTaking);
+        assert_eq!(round(labels[0].ret), 0.05);
+        assert_eq!(labels[0].label, 1);
+        assert_eq!(labeler.open_entries(), 0);
+    }
+
+    #[test]
+    fn test_both_barriers_in_one_bar() {
+        let mut labeler = percentage(1.0, 1.0, 10);
+
+        labeler.nexta(&bar(100.0, 100.0, 100.0));
+        labeler.enter();
+        let labels = labeler.nexta(&bar(102.0, 98.0, 100.0));
+        assert_eq!(labels[0].barrier, Barrier::StopLoss);
+    }
+
+    #[test]
+    fn test_time_barrier() {
+        let mut labeler = percentage(10.0, 10.0, 2);
+
+        labeler.nexta(&bar(100.0, 100.0, 100.0));
+        labeler.enter();
+        assert!(labeler.nexta(&bar(101.0, 99.0, 101.0)).is_empty());
+
+        let labels = labeler.nexta(&bar(103.0, 100.0, 102.0));
+        assert_eq!(labels[0].barrier, Barrier::Time);
+        assert_eq!(labels[0].exit, 2);
+        assert_eq!(round(labels[0].ret), 0.02);
+        assert_eq!(labels[0].label, 1);
+
+        labeler.enter();
+        labeler.nexta(&bar(103.0, 101.0, 102.0));
+        let labels = labeler.nexta(&bar(103.0, 101.0, 102.0));
+        assert_eq!((labels[0].barrier, labels[0].label), (Barrier::Time, 0));
+    }
+
+    #[test]
+    fn test_atr_barriers() {
+        let mut labeler = TripleBarrierLabeler::new(2.0, 1.0, 10, BarrierScale::Atr(3)).unwrap();
+
+        // ATR = 2, barriers at 104 and 98
+        labeler.nexta(&bar(101.0, 99.0, 100.0));
+        labeler.enter();
+        assert!(labeler.nexta(&bar(103.5, 99.0, 103.0)).is_empty());
+
+        let labels = labeler.nexta(&bar(104.0, 102.0, 103.0));
+        assert_eq!(labels[0].barrier, Barrier::ProfitTaking);
+        assert_eq!(round(labels[0].ret), 0.04);
+    }
+
+    #[test]
+    fn test_enter_before_first_bar() {
+        let mut labeler = percentage(1.0, 1.0, 10);
+
+        labeler.enter();
+        assert_eq!(labeler.open_entries(), 0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut labeler = percentage(1.0, 1.0, 10);
+
+        labeler.nexta(&bar(100.0, 100.0, 100.0));
+        labeler.enter();
+        labeler.reset();
+        assert_eq!(labeler.open_entries(), 0);
+
+        labeler.nexta(&bar(50.0, 50.0, 50.0));
+        labeler.enter();
+        let labels = labeler.nexta(&bar(51.0, 50.0, 51.0));
+        assert_eq!((labels[0].entry, labels[0].exit), (0, 1));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", percentage(2.0, 1.5, 20)), "TBL(2%, 1.5%, 20)");
+
+        let labeler = TripleBarrierLabeler::new(2.0, 1.0, 10, BarrierScale::Atr(14)).unwrap();
+        assert_eq!(format!("{}", labeler), "TBL(2, 1, 10, ATR(14))");
+    }
+}
@@ -29,6 +29,18 @@
 //! * Trend
 //!   * [Exponential Moving Average (EMA)](crate::indicators::ExponentialMovingAverage)
 //!   * [Simple Moving Average (SMA)](crate::indicators::SimpleMovingAverage)
+//!   * [Tillson T3 Moving Average (T3)](indicators/struct.TillsonT3.html)
+//!   * [Linear Regression (LSMA, slope, R²)](indicators/struct.LinearRegression.html)
+//!   * [ZigZag](indicators/struct.ZigZag.html)
+//!   * [Williams Alligator](indicators/struct.Alligator.html)
+//!   * [Ehlers SuperSmoother (SSF)](indicators/struct.SuperSmoother.html)
+//!   * [Kalman Filter (KF)](indicators/struct.KalmanFilter.html)
+//!   * [STARC Bands](indicators/struct.StarcBands.html)
+//!   * [Bollinger Bands with %B and Bandwidth](indicators/struct.BollingerBandsExtended.html)
+//!   * [Vertical Horizontal Filter (VHF)](indicators/struct.VerticalHorizontalFilter.html)
+//!   * [Trend Intensity Index (TII)](indicators/struct.TrendIntensityIndex.html)
+//!   * [Average Directional Index (ADX)](indicators/struct.AverageDirectionalIndex.html)
+//!   * [Average Directional Movement Index Rating (ADXR)](indicators/struct.Adxr.html)
 //! * Oscillators
 //!   * [Relative Strength Index (RSI)](indicators/struct.RelativeStrengthIndex.html)
 //!   * [Fast Stochastic](indicators/struct.FastStochastic.html)
@@ -37,6 +49,22 @@
 //!   * [Percentage Price Oscillator (PPO)](indicators/struct.PercentagePriceOscillator.html)
 //!   * [Commodity Channel Index (CCI)](indicators/struct.CommodityChannelIndex.html)
 //!   * [Money Flow Index (MFI)](indicators/struct.MoneyFlowIndex.html)
+//!   * [Pretty Good Oscillator (PGO)](indicators/struct.PrettyGoodOscillator.html)
+//!   * [Adaptive Zones (AZ)](indicators/struct.AdaptiveZones.html)
+//!   * [Balance of Power (BOP)](indicators/struct.BalanceOfPower.html)
+//!   * [Ease of Movement (EMV)](indicators/struct.EaseOfMovement.html)
+//!   * [Schaff Trend Cycle (STC)](indicators/struct.SchaffTrendCycle.html)
+//!   * [Qstick](indicators/struct.Qstick.html)
+//!   * [Relative Vigor Index (RVI)](indicators/struct.RelativeVigorIndex.html)
+//!   * [Chande Momentum Oscillator (CMO)](indicators/struct.ChandeMomentumOscillator.html)
+//!   * [Stochastic Momentum Index (SMI)](indicators/struct.StochasticMomentumIndex.html)
+//!   * [Ehlers Center of Gravity (COG)](indicators/struct.CenterOfGravity.html)
+//!   * [Momentum (MOM)](indicators/struct.Momentum.html)
+//!   * [Divergence Detector (regular and hidden)](indicators/struct.DivergenceDetector.html)
+//!   * [Volatility-Normalized MACD (MACD-V)](indicators/struct.MacdV.html)
+//!   * [Percentage Volume Oscillator (PVO)](indicators/struct.PercentageVolumeOscillator.html)
+//!   * [Intraday Momentum Index (IMI)](indicators/struct.IntradayMomentumIndex.html)
+//!   * [Twiggs Money Flow (TMF)](indicators/struct.TwiggsMoneyFlow.html)
 //! * Other
 //!   * [Standard Deviation (SD)](indicators/struct.StandardDeviation.html)
 //!   * [Mean Absolute Deviation (MAD)](indicators/struct.MeanAbsoluteDeviation.html)
@@ -50,6 +78,54 @@
 //!   * [Efficiency Ratio (ER)](indicators/struct.EfficiencyRatio.html)
 //!   * [Rate of Change (ROC)](indicators/struct.RateOfChange.html)
 //!   * [On Balance Volume (OBV)](indicators/struct.OnBalanceVolume.html)
+//!   * [Gopalakrishnan Range Index (GAPO)](indicators/struct.GopalakrishnanRangeIndex.html)
+//!   * [Market Facilitation Index (BW MFI)](indicators/struct.MarketFacilitationIndex.html)
+//!   * [Score Aggregator (weighted voting)](indicators/struct.ScoreAggregator.html)
+//!   * [Pivot Points (PP)](indicators/struct.PivotPoints.html)
+//!   * [Heikin-Ashi (HA)](indicators/struct.HeikinAshi.html)
+//!   * [Opening Range Breakout (ORB)](indicators/struct.OpeningRange.html)
+//!   * [Session Statistics (VWAP, initial balance)](indicators/struct.SessionStats.html)
+//!   * [Expected Move (EM)](indicators/struct.ExpectedMove.html)
+//!   * [Mean Reversion Block (spread z-score)](indicators/struct.MeanReversionBlock.html)
+//!   * [Up/Down Volume Ratio (UDVR)](indicators/struct.UpDownVolumeRatio.html)
+//!   * [Klinger Volume Oscillator (KVO)](indicators/struct.KlingerVolumeOscillator.html)
+//!   * [Exponentially Weighted Correlation (EWCORR)](indicators/struct.ExponentiallyWeightedCorrelation.html)
+//!   * [EWMA Volatility (RiskMetrics)](indicators/struct.EwmaVolatility.html)
+//!   * [GARCH(1,1) Volatility](indicators/struct.Garch11.html)
+//!   * [Downside Deviation (DD)](indicators/struct.DownsideDeviation.html)
+//!   * [Realized Semivariance (RSV)](indicators/struct.RealizedSemivariance.html)
+//!   * [Omega Ratio](indicators/struct.OmegaRatio.html)
+//!   * [Gain to Pain Ratio (GPR)](indicators/struct.GainPainRatio.html)
+//!   * [Median](indicators/struct.Median.html)
+//!   * [Quantile](indicators/struct.Quantile.html)
+//!   * [Williams Fractals](indicators/struct.WilliamsFractals.html)
+//!   * [Regime Detector](indicators/struct.RegimeDetector.html)
+//!   * [Volume Profile (VP)](indicators/struct.VolumeProfile.html)
+//!   * [CUSUM Filter](indicators/struct.CusumFilter.html)
+//!   * [Variance (VAR)](indicators/struct.Variance.html)
+//!   * [Fractional Differentiation (FracDiff)](indicators/struct.FracDiff.html)
+//!   * [Rolling Mutual Information (MI)](indicators/struct.RollingMutualInformation.html)
+//!   * [Z-Score](indicators/struct.ZScore.html)
+//!   * [Lead-Lag Cross-Correlation (XCORR)](indicators/struct.CrossCorrelation.html)
+//!   * [Seasonal Decomposition (trend, seasonal, residual)](indicators/struct.SeasonalDecomposition.html)
+//!   * [Rolling Sharpe Ratio](indicators/struct.RollingSharpe.html)
+//!   * [Rolling Sortino Ratio](indicators/struct.RollingSortino.html)
+//!   * [Mean Absolute Error (MAE)](indicators/struct.RollingMae.html)
+//!   * [Root Mean Square Error (RMSE)](indicators/struct.RollingRmse.html)
+//!   * [Mean Absolute Percentage Error (MAPE)](indicators/struct.RollingMape.html)
+//!   * [Maximum Drawdown (MDD)](indicators/struct.MaxDrawdown.html)
+//!   * [Ulcer Index (UI)](indicators/struct.UlcerIndex.html)
+//!   * [Rolling Correlation (CORR)](indicators/struct.RollingCorrelation.html)
+//!   * [Rolling Beta](indicators/struct.RollingBeta.html)
+//!   * [Rolling Covariance (COV)](indicators/struct.RollingCovariance.html)
+//!   * [Pair Spread (spread, ratio, z-score)](indicators/struct.PairSpread.html)
+//!   * [Historical Volatility (HV)](indicators/struct.HistoricalVolatility.html)
+//!   * [Range Volatility (Parkinson, Garman-Klass, Rogers-Satchell)](indicators/struct.RangeVolatility.html)
+//!   * [Weighted Percentile (volume-weighted quantile)](indicators/struct.WeightedPercentile.html)
+//!   * [Level Book (touches and breaks of drawn levels)](indicators/struct.LevelBook.html)
+//!   * [Pivot Detector (pivot highs and lows)](indicators/struct.PivotDetector.html)
+//!   * [TTM Squeeze](indicators/struct.TtmSqueeze.html)
+//!   * [Prior Period Levels (open, high, low, close)](indicators/struct.PriorPeriodLevels.html)
 //!
 #[cfg(test)]
 #[macro_use]
@@ -57,8 +133,15 @@ mod test_helper;
 
 mod helpers;
 
+pub mod bars;
 pub mod errors;
+pub mod forecast;
 pub mod indicators;
+pub mod labeling;
+pub mod robustness;
+pub mod synthetic;
+#[cfg(feature = "timing")]
+pub mod timing;
 
 mod traits;
 pub use crate::traits::*;
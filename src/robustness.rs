@@ -0,0 +1,501 @@
+//! Robustness checks of indicators against adversarial inputs.
+//!
+//! [check_robustness](fn.check_robustness.html) feeds an indicator with a set of adversarial
+//! sequences: NaN, ±infinity, subnormal numbers, huge magnitudes, monotone ramps, constant and
+//! alternating series. It reports every violation of the declared
+//! [Invariants](struct.Invariants.html) instead of stopping at the first one, so it can be used
+//! in tests of the indicators of this crate as well as of custom ones.
+//!
+//! # Example
+//!
+//! ```
+//! use tars::indicators::{RelativeStrengthIndex, SimpleMovingAverage};
+//! use tars::robustness::{check_robustness, Invariants};
+//!
+//! let report = check_robustness(&SimpleMovingAverage::new(5).unwrap());
+//! assert!(report.is_ok(), "{}", report);
+//!
+//! let rsi = RelativeStrengthIndex::new(14).unwrap();
+//! let report = Invariants::new().bounds(0.0, 100.0).check(&rsi);
+//! assert!(report.is_ok(), "{}", report);
+//! ```
+
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{Nexta, Reset};
+
+// Number of values in every sequence, longer than the period of most indicators.
+const LENGTH: usize = 100;
+
+/// Output of an indicator that exposes its numeric values to the checks.
+///
+/// Implemented for `f64`, `bool`, pairs of values and `Option`s of them. Implement it for the
+/// output of a custom indicator to check the indicator.
+pub trait OutputValues {
+    /// Appends the numeric values of the output to `values`.
+    fn values(&self, values: &mut Vec<f64>);
+}
+
+impl OutputValues for f64 {
+    fn values(&self, values: &mut Vec<f64>) {
+        values.push(*self);
+    }
+}
+
+impl OutputValues for bool {
+    fn values(&self, _values: &mut Vec<f64>) {}
+}
+
+impl<A: OutputValues, B: OutputValues> OutputValues for (A, B) {
+    fn values(&self, values: &mut Vec<f64>) {
+        self.0.values(values);
+        self.1.values(values);
+    }
+}
+
+impl<T: OutputValues> OutputValues for Option<T> {
+    fn values(&self, values: &mut Vec<f64>) {
+        if let Some(output) = self {
+            output.values(values);
+        }
+    }
+}
+
+/// Kind of a violated invariant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// `nexta` or `reset` panicked.
+    Panic,
+    /// A finite input produced a NaN or infinite output.
+    NonFinite,
+    /// An output left the declared bounds.
+    OutOfBounds,
+    /// After a reset the indicator did not produce the outputs of a fresh one.
+    Reset,
+}
+
+/// Violation of an invariant found by a check.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// Name of the input sequence.
+    pub sequence: &'static str,
+    /// Index of the offending input in the sequence. After the reset the indicator is fed with
+    /// a ramp, and the index is the one in the ramp.
+    pub index: usize,
+    pub kind: ViolationKind,
+    /// Offending output value, NaN for panics.
+    pub value: f64,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self.kind {
+            ViolationKind::Panic => "panic",
+            ViolationKind::NonFinite => "non-finite output",
+            ViolationKind::OutOfBounds => "output out of bounds",
+            ViolationKind::Reset => "output differs after reset",
+        };
+        write!(f, "{} at input {} of {}", kind, self.index, self.sequence)?;
+        if self.kind != ViolationKind::Panic {
+            write!(f, ": {}", self.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Result of a robustness check.
+///
+/// Every invariant is reported at most once per sequence, at its first violation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RobustnessReport {
+    pub violations: Vec<Violation>,
+}
+
+impl RobustnessReport {
+    /// Returns `true` when no invariant was violated.
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl fmt::Display for RobustnessReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_ok() {
+            return write!(f, "no violations");
+        }
+        write!(f, "{} violation(s)", self.violations.len())?;
+        for violation in &self.violations {
+            write!(f, "\n  {}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+/// Invariants an indicator is checked against.
+///
+/// Whatever the invariants, `nexta` and `reset` must not panic on any sequence. By default the
+/// outputs of finite inputs must be finite, and an indicator that is reset after any sequence,
+/// including the non-finite ones, must produce the same outputs as a fresh one.
+///
+/// # Example
+///
+/// ```
+/// use tars::indicators::StandardDeviation;
+/// use tars::robustness::Invariants;
+///
+/// let sd = StandardDeviation::new(10).unwrap();
+/// let report = Invariants::new().bounds(0.0, f64::INFINITY).check(&sd);
+/// assert!(report.is_ok(), "{}", report);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Invariants {
+    finite: bool,
+    bounds: Option<(f64, f64)>,
+    reset: bool,
+}
+
+impl Invariants {
+    pub fn new() -> Self {
+        Self {
+            finite: true,
+            bounds: None,
+            reset: true,
+        }
+    }
+
+    /// Whether the outputs of finite inputs must be finite. Default is `true`.
+    pub fn finite(mut self, finite: bool) -> Self {
+        self.finite = finite;
+        self
+    }
+
+    /// Inclusive range that the outputs of finite inputs must stay in, e.g. 0 to 100 for an
+    /// oscillator. Not checked by default.
+    pub fn bounds(mut self, low: f64, high: f64) -> Self {
+        self.bounds = Some((low, high));
+        self
+    }
+
+    /// Whether a reset must restore the state of a fresh indicator. Default is `true`.
+    pub fn reset(mut self, reset: bool) -> Self {
+        self.reset = reset;
+        self
+    }
+
+    /// Checks the indicator against the invariants.
+    ///
+    /// The indicator itself is not modified: every sequence is fed to a clone of it, reset
+    /// first.
+    pub fn check<I>(&self, indicator: &I) -> RobustnessReport
+    where
+        I: Nexta<f64> + Reset + Clone,
+        I::Output: OutputValues,
+    {
+        let mut report = RobustnessReport::default();
+        let reference = ramp(LENGTH, true);
+        let expected = run(indicator, |i| feed(i, &reference));
+
+        for (name, sequence) in sequences() {
+            let mut violations = Vec::new();
+            let all_finite = sequence.iter().all(|v| v.is_finite());
+
+            let result = run(indicator, |i| {
+                let outputs = feed(i, &sequence);
+                i.reset();
+                (outputs, feed(i, &reference))
+            });
+
+            match result {
+                Err(index) => violations.push(Violation {
+                    sequence: name,
+                    index,
+                    kind: ViolationKind::Panic,
+                    value: f64::NAN,
+                }),
+                Ok((outputs, replayed)) => {
+                    if all_finite {
+                        self.check_outputs(name, &outputs, &mut violations);
+                    }
+                    if let (true, Ok(expected)) = (self.reset, &expected) {
+                        let mismatch = expected
+                            .iter()
+                            .zip(replayed.iter())
+                            .position(|(a, b)| !same_values(a, b));
+                        if let Some(index) = mismatch {
+                            violations.push(Violation {
+                                sequence: name,
+                                index,
+                                kind: ViolationKind::Reset,
+                                value: replayed[index].first().copied().unwrap_or(f64::NAN),
+                            });
+                        }
+                    }
+                }
+            }
+
+            report.violations.extend(violations);
+        }
+
+        report
+    }
+
+    fn check_outputs(
+        &self,
+        sequence: &'static str,
+        outputs: &[Vec<f64>],
+        violations: &mut Vec<Violation>,
+    ) {
+        let mut non_finite = !self.finite;
+        let mut out_of_bounds = self.bounds.is_none();
+        for (index, values) in outputs.iter().enumerate() {
+            for &value in values {
+                if !non_finite && !value.is_finite() {
+                    non_finite = true;
+                    violations.push(Violation {
+                        sequence,
+                        index,
+                        kind: ViolationKind::NonFinite,
+                        value,
+                    });
+                }
+                if let (false, Some((low, high))) = (out_of_bounds, self.bounds) {
+                    if value < low || value > high {
+                        out_of_bounds = true;
+                        violations.push(Violation {
+                            sequence,
+                            index,
+                            kind: ViolationKind::OutOfBounds,
+                            value,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Default for Invariants {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Checks the indicator against the default [Invariants](struct.Invariants.html).
+pub fn check_robustness<I>(indicator: &I) -> RobustnessReport
+where
+    I: Nexta<f64> + Reset + Clone,
+    I::Output: OutputValues,
+{
+    Invariants::new().check(indicator)
+}
+
+fn feed<I>(indicator: &mut I, inputs: &[f64]) -> Vec<Vec<f64>>
+where
+    I: Nexta<f64>,
+    I::Output: OutputValues,
+{
+    inputs
+        .iter()
+        .map(|&input| {
+            let mut values = Vec::new();
+            indicator.nexta(input).values(&mut values);
+            values
+        })
+        .collect()
+}
+
+// Runs `f` on a reset clone of the indicator. On a panic returns the number of inputs fed since
+// the last reset.
+fn run<I, F, R>(indicator: &I, f: F) -> Result<R, usize>
+where
+    I: Reset + Clone,
+    F: FnOnce(&mut Counted<I>) -> R,
+{
+    let mut counted = Counted {
+        inner: indicator.clone(),
+        index: 0,
+    };
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        counted.inner.reset();
+        f(&mut counted)
+    }));
+    result.map_err(|_| counted.index)
+}
+
+// Indicator wrapper counting the inputs fed to it since the last reset, so that a panic can be
+// located.
+struct Counted<I> {
+    inner: I,
+    index: usize,
+}
+
+impl<I: Nexta<f64>> Nexta<f64> for Counted<I> {
+    type Output = I::Output;
+
+    fn nexta(&mut self, input: f64) -> Self::Output {
+        let output = self.inner.nexta(input);
+        self.index += 1;
+        output
+    }
+}
+
+impl<I: Reset> Reset for Counted<I> {
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.index = 0;
+    }
+}
+
+fn same_values(a: &[f64], b: &[f64]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b.iter())
+            .all(|(x, y)| x == y || (x.is_nan() && y.is_nan()))
+}
+
+fn ramp(length: usize, up: bool) -> Vec<f64> {
+    (0..length)
+        .map(|i| {
+            let step = if up { i } else { length - i };
+            100.0 + step as f64
+        })
+        .collect()
+}
+
+fn sequences() -> Vec<(&'static str, Vec<f64>)> {
+    let with = |every: usize, value: f64| -> Vec<f64> {
+        ramp(LENGTH, true)
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| if i % every == every - 1 { value } else { v })
+            .collect()
+    };
+
+    vec![
+        ("constant series", vec![42.0; LENGTH]),
+        ("zeros", vec![0.0; LENGTH]),
+        ("ramp up", ramp(LENGTH, true)),
+        ("ramp down", ramp(LENGTH, false)),
+        (
+            "alternating series",
+            (0..LENGTH)
+                .map(|i| if i % 2 == 0 { 101.0 } else { 99.0 })
+                .collect(),
+        ),
+        (
+            "subnormal numbers",
+            (0..LENGTH).map(|i| f64::from_bits(i as u64 + 1)).collect(),
+        ),
+        (
+            "huge magnitudes",
+            (0..LENGTH)
+                .map(|i| if i % 2 == 0 { 1e150 } else { -1e150 })
+                .collect(),
+        ),
+        ("NaN", vec![f64::NAN; LENGTH]),
+        ("ramp with NaN", with(10, f64::NAN)),
+        ("ramp with infinity", with(10, f64::INFINITY)),
+        ("ramp with negative infinity", with(10, f64::NEG_INFINITY)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::*;
+
+    #[test]
+    fn test_indicators() {
+        assert!(check_robustness(&SimpleMovingAverage::new(9).unwrap()).is_ok());
+        assert!(check_robustness(&ExponentialMovingAverage::new(9).unwrap()).is_ok());
+        assert!(check_robustness(&Maximum::new(9).unwrap()).is_ok());
+        assert!(check_robustness(&Minimum::new(9).unwrap()).is_ok());
+
+        let non_negative = Invariants::new().bounds(0.0, f64::INFINITY);
+        assert!(non_negative
+            .check(&StandardDeviation::new(9).unwrap())
+            .is_ok());
+        assert!(non_negative.check(&UlcerIndex::new(9).unwrap()).is_ok());
+    }
+
+    #[derive(Debug, Clone)]
+    struct Faulty {
+        count: usize,
+    }
+
+    impl Nexta<f64> for Faulty {
+        type Output = f64;
+
+        fn nexta(&mut self, input: f64) -> f64 {
+            self.count += 1;
+            if input.is_nan() {
+                panic!("NaN input");
+            }
+            // never reset
+            input / (self.count as f64 - 1.0)
+        }
+    }
+
+    impl Reset for Faulty {
+        fn reset(&mut self) {}
+    }
+
+    #[test]
+    fn test_violations() {
+        let report = check_robustness(&Faulty { count: 0 });
+        assert!(!report.is_ok());
+
+        let find = |sequence: &str, kind: ViolationKind| -> Option<&Violation> {
+            report
+                .violations
+                .iter()
+                .find(|v| v.sequence == sequence && v.kind == kind)
+        };
+        assert_eq!(find("NaN", ViolationKind::Panic).unwrap().index, 0);
+        assert_eq!(
+            find("ramp with NaN", ViolationKind::Panic).unwrap().index,
+            9
+        );
+        let non_finite = find("constant series", ViolationKind::NonFinite).unwrap();
+        assert_eq!((non_finite.index, non_finite.value), (0, f64::INFINITY));
+        assert!(find("ramp up", ViolationKind::Reset).is_some());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let rsi = RelativeStrengthIndex::new(14).unwrap();
+
+        let report = Invariants::new().bounds(0.0, 50.0).check(&rsi);
+        assert!(report
+            .violations
+            .iter()
+            .all(|v| v.kind == ViolationKind::OutOfBounds));
+        assert!(report.violations.iter().any(|v| v.sequence == "ramp up"));
+
+        assert!(Invariants::new().bounds(0.0, 100.0).check(&rsi).is_ok());
+    }
+
+    #[test]
+    fn test_display() {
+        let violation = Violation {
+            sequence: "zeros",
+            index: 3,
+            kind: ViolationKind::NonFinite,
+            value: f64::NAN,
+        };
+        assert_eq!(
+            format!("{}", violation),
+            "non-finite output at input 3 of zeros: NaN"
+        );
+
+        let report = RobustnessReport {
+            violations: vec![violation],
+        };
+        assert_eq!(
+            format!("{}", report),
+            "1 violation(s)\n  non-finite output at input 3 of zeros: NaN"
+        );
+        assert_eq!(format!("{}", RobustnessReport::default()), "no violations");
+    }
+}
@@ -0,0 +1,702 @@
+//! Reproducible synthetic bar streams.
+//!
+//! [SyntheticBars](struct.SyntheticBars.html) generates an endless stream of OHLCV bars driven by
+//! one of the stochastic price [processes](enum.Process.html). The stream is fully determined by
+//! its parameters and seed, so it can be used to stress-test indicators and strategies without
+//! shipping large datasets.
+//!
+//! [BlockBootstrap](struct.BlockBootstrap.html) resamples a real bar series into alternative
+//! histories, e.g. to assess how robust a strategy is across plausible variations of the data.
+//!
+//! # Example
+//!
+//! ```
+//! use tars::indicators::SimpleMovingAverage;
+//! use tars::synthetic::{Process, SyntheticBars};
+//! use tars::Nexta;
+//!
+//! let process = Process::Gbm {
+//!     drift: 0.0001,
+//!     volatility: 0.01,
+//! };
+//! let bars = SyntheticBars::new(process, 100.0, 42).unwrap();
+//!
+//! let mut sma = SimpleMovingAverage::new(20).unwrap();
+//! for bar in bars.take(1000) {
+//!     sma.nexta(&bar);
+//! }
+//! ```
+
+use crate::errors::{Result, TaError};
+use crate::{Close, DataItema, High, Low, Open, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Number of simulated steps of every bar, used for high and low.
+const STEPS_PER_BAR: usize = 4;
+
+/// Volatility of the logarithm of volume.
+const VOLUME_VOLATILITY: f64 = 0.25;
+
+/// Stochastic process driving the price of [SyntheticBars](struct.SyntheticBars.html).
+///
+/// All the parameters are expressed per bar.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Process {
+    /// Geometric Brownian motion: log returns are normally distributed with mean
+    /// _drift_ - _volatility_² / 2 and standard deviation _volatility_.
+    Gbm { drift: f64, volatility: f64 },
+    /// Ornstein-Uhlenbeck process: the price is pulled towards _mean_ with the speed _reversion_
+    /// and disturbed by normal noise with standard deviation _volatility_. The price is floored
+    /// at 0.
+    OrnsteinUhlenbeck {
+        mean: f64,
+        reversion: f64,
+        volatility: f64,
+    },
+    /// Geometric Brownian motion whose parameters switch between _regimes_. At the end of every
+    /// bar the process moves to a different, randomly chosen regime with _switch_probability_.
+    RegimeSwitching {
+        regimes: Vec<Regime>,
+        switch_probability: f64,
+    },
+    /// Merton jump-diffusion: geometric Brownian motion with jumps arriving with intensity
+    /// _jump_intensity_ per bar. The logarithm of a jump is normally distributed with mean
+    /// _jump_mean_ and standard deviation _jump_volatility_.
+    JumpDiffusion {
+        drift: f64,
+        volatility: f64,
+        jump_intensity: f64,
+        jump_mean: f64,
+        jump_volatility: f64,
+    },
+}
+
+/// Parameters of a single regime of [Process::RegimeSwitching](enum.Process.html#variant.RegimeSwitching).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regime {
+    pub drift: f64,
+    pub volatility: f64,
+}
+
+impl Process {
+    fn validate(&self) -> Result<()> {
+        let valid = match self {
+            Process::Gbm { drift, volatility } => drift.is_finite() && is_non_negative(*volatility),
+            Process::OrnsteinUhlenbeck {
+                mean,
+                reversion,
+                volatility,
+            } => mean.is_finite() && is_non_negative(*reversion) && is_non_negative(*volatility),
+            Process::RegimeSwitching {
+                regimes,
+                switch_probability,
+            } => {
+                !regimes.is_empty()
+                    && regimes
+                        .iter()
+                        .all(|r| r.drift.is_finite() && is_non_negative(r.volatility))
+                    && (0.0..=1.0).contains(switch_probability)
+            }
+            Process::JumpDiffusion {
+                drift,
+                volatility,
+                jump_intensity,
+                jump_mean,
+                jump_volatility,
+            } => {
+                drift.is_finite()
+                    && is_non_negative(*volatility)
+                    && is_non_negative(*jump_intensity)
+                    && jump_mean.is_finite()
+                    && is_non_negative(*jump_volatility)
+            }
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(TaError::InvalidParameter)
+        }
+    }
+}
+
+fn is_non_negative(value: f64) -> bool {
+    value.is_finite() && value >= 0.0
+}
+
+/// Endless, reproducible stream of synthetic bars.
+///
+/// Every bar opens at the close of the previous one. Its path is simulated in a few steps, which
+/// give the high and the low of the bar. Volume is log-normally distributed around the base
+/// volume.
+///
+/// The random numbers come from a built-in SplitMix64 generator, so the same parameters and seed
+/// produce the same stream on every platform.
+///
+/// With extreme parameters the simulated price can leave the range of `f64`; the stream then
+/// ends instead of producing invalid bars.
+///
+/// # Parameters
+///
+/// * _process_ - price process.
+/// * _initial_price_ - price of the first open (number greater than 0).
+/// * _seed_ - seed of the random number generator.
+/// * _volume_ - base volume (number greater than or equal to 0). Default is 1000.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SyntheticBars {
+    process: Process,
+    rng: SplitMix64,
+    price: f64,
+    regime: usize,
+    volume: f64,
+}
+
+impl SyntheticBars {
+    pub fn new(process: Process, initial_price: f64, seed: u64) -> Result<Self> {
+        process.validate()?;
+        if !(initial_price.is_finite() && initial_price > 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            process,
+            rng: SplitMix64::new(seed),
+            price: initial_price,
+            regime: 0,
+            volume: 1000.0,
+        })
+    }
+
+    /// Sets the base volume.
+    pub fn with_volume(mut self, volume: f64) -> Result<Self> {
+        if !is_non_negative(volume) {
+            return Err(TaError::InvalidParameter);
+        }
+        self.volume = volume;
+        Ok(self)
+    }
+
+    pub fn process(&self) -> &Process {
+        &self.process
+    }
+
+    /// Returns the index of the regime used for the next bar. Always 0 unless the process is
+    /// [RegimeSwitching](enum.Process.html#variant.RegimeSwitching).
+    pub fn regime(&self) -> usize {
+        self.regime
+    }
+
+    fn step(&mut self, dt: f64) {
+        let gbm = |rng: &mut SplitMix64, price: f64, drift: f64, volatility: f64| {
+            let z = rng.next_normal();
+            price
+                * ((drift - volatility * volatility / 2.0) * dt + volatility * dt.sqrt() * z).exp()
+        };
+
+        self.price = match self.process {
+            Process::Gbm { drift, volatility } => gbm(&mut self.rng, self.price, drift, volatility),
+            Process::OrnsteinUhlenbeck {
+                mean,
+                reversion,
+                volatility,
+            } => {
+                let z = self.rng.next_normal();
+                let price =
+                    self.price + reversion * (mean - self.price) * dt + volatility * dt.sqrt() * z;
+                price.max(0.0)
+            }
+            Process::RegimeSwitching { ref regimes, .. } => {
+                let regime = regimes[self.regime];
+                gbm(&mut self.rng, self.price, regime.drift, regime.volatility)
+            }
+            Process::JumpDiffusion {
+                drift,
+                volatility,
+                jump_intensity,
+                jump_mean,
+                jump_volatility,
+            } => {
+                let price = gbm(&mut self.rng, self.price, drift, volatility);
+                if self.rng.next_f64() < jump_intensity * dt {
+                    price * (jump_mean + jump_volatility * self.rng.next_normal()).exp()
+                } else {
+                    price
+                }
+            }
+        };
+    }
+
+    fn switch_regime(&mut self) {
+        if let Process::RegimeSwitching {
+            ref regimes,
+            switch_probability,
+        } = self.process
+        {
+            let count = regimes.len();
+            if count > 1 && self.rng.next_f64() < switch_probability {
+                let offset = 1 + (self.rng.next_f64() * (count - 1) as f64) as usize;
+                self.regime = (self.regime + offset.min(count - 1)) % count;
+            }
+        }
+    }
+}
+
+impl Iterator for SyntheticBars {
+    type Item = DataItema;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let open = self.price;
+        let mut high = open;
+        let mut low = open;
+
+        let dt = 1.0 / STEPS_PER_BAR as f64;
+        for _ in 0..STEPS_PER_BAR {
+            self.step(dt);
+            high = high.max(self.price);
+            low = low.min(self.price);
+        }
+        let close = self.price;
+        let volume = self.volume * (VOLUME_VOLATILITY * self.rng.next_normal()).exp();
+
+        self.switch_regime();
+
+        if !(high.is_finite() && low.is_finite() && volume.is_finite()) {
+            return None;
+        }
+
+        DataItema::builder()
+            .open(open)
+            .high(high)
+            .low(low)
+            .close(close)
+            .volume(volume)
+            .build()
+            .ok()
+    }
+}
+
+/// Stationary block bootstrap of a historical bar series.
+///
+/// Generates an endless stream of alternative histories by resampling the bars of a real series
+/// in blocks of random length (Politis and Romano), which preserves the short-term
+/// autocorrelation of the original series. Every bar is resampled as its move relative to the
+/// previous close, so gaps and the shape of the bars are kept and the resampled prices stay
+/// continuous. The blocks wrap around the end of the series.
+///
+/// The first resampled bar opens at the last close of the series, the same seed always produces
+/// the same history.
+///
+/// # Parameters
+///
+/// * _bars_ - original series (at least 2 valid bars with positive closes).
+/// * _mean_block_length_ - mean length of the blocks (number greater than or equal to 1). Block
+///   lengths are geometrically distributed, 1 resamples the bars independently.
+/// * _seed_ - seed of the random number generator.
+///
+/// # Example
+///
+/// ```
+/// use tars::synthetic::{BlockBootstrap, Process, SyntheticBars};
+/// use tars::DataItema;
+///
+/// let process = Process::Gbm {
+///     drift: 0.0,
+///     volatility: 0.01,
+/// };
+/// let history: Vec<DataItema> = SyntheticBars::new(process, 100.0, 1).unwrap().take(500).collect();
+///
+/// let bootstrap = BlockBootstrap::new(&history, 20.0, 42).unwrap();
+/// let alternative: Vec<DataItema> = bootstrap.take(500).collect();
+/// assert_eq!(alternative.len(), 500);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BlockBootstrap {
+    moves: Vec<BarMove>,
+    mean_block_length: f64,
+    rng: SplitMix64,
+    price: f64,
+    position: usize,
+}
+
+/// Bar relative to the previous close.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+struct BarMove {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+}
+
+impl BlockBootstrap {
+    pub fn new<T: Open + High + Low + Close + Volume>(
+        bars: &[T],
+        mean_block_length: f64,
+        seed: u64,
+    ) -> Result<Self> {
+        if bars.len() < 2 || !(mean_block_length.is_finite() && mean_block_length >= 1.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let mut moves = Vec::with_capacity(bars.len() - 1);
+        for pair in bars.windows(2) {
+            let prev_close = pair[0].close();
+            if !(prev_close.is_finite() && prev_close > 0.0) {
+                return Err(TaError::InvalidParameter);
+            }
+            let bar = &pair[1];
+            let valid = bar.low() >= 0.0
+                && bar.low() <= bar.open().min(bar.close())
+                && bar.high() >= bar.open().max(bar.close())
+                && bar.volume() >= 0.0;
+            if !valid {
+                return Err(TaError::InvalidParameter);
+            }
+            moves.push(BarMove {
+                open: bar.open() / prev_close,
+                high: bar.high() / prev_close,
+                low: bar.low() / prev_close,
+                close: bar.close() / prev_close,
+                volume: bar.volume(),
+            });
+        }
+
+        let mut rng = SplitMix64::new(seed);
+        let position = rng.next_index(moves.len());
+        let price = bars[bars.len() - 1].close();
+        if !(price.is_finite() && price > 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            moves,
+            mean_block_length,
+            rng,
+            price,
+            position,
+        })
+    }
+
+    pub fn mean_block_length(&self) -> f64 {
+        self.mean_block_length
+    }
+}
+
+impl Iterator for BlockBootstrap {
+    type Item = DataItema;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let m = self.moves[self.position];
+        let bar = DataItema::builder()
+            .open(self.price * m.open)
+            .high(self.price * m.high)
+            .low(self.price * m.low)
+            .close(self.price * m.close)
+            .volume(m.volume)
+            .build()
+            .expect("resampled bar is valid when the original one is");
+        self.price = bar.close();
+
+        // a new block starts with probability 1 / mean block length
+        self.position = if self.rng.next_f64() < 1.0 / self.mean_block_length {
+            self.rng.next_index(self.moves.len())
+        } else {
+            (self.position + 1) % self.moves.len()
+        };
+
+        Some(bar)
+    }
+}
+
+/// SplitMix64 pseudo random number generator.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniformly distributed number in the open interval (0, 1).
+    fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    /// Returns a uniformly distributed index in 0..len.
+    fn next_index(&mut self, len: usize) -> usize {
+        ((self.next_f64() * len as f64) as usize).min(len - 1)
+    }
+
+    /// Returns a standard normally distributed number (Box-Muller transform).
+    fn next_normal(&mut self) -> f64 {
+        let u1 = self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn ohlcv(bar: &DataItema) -> (f64, f64, f64, f64, f64) {
+        (bar.open(), bar.high(), bar.low(), bar.close(), bar.volume())
+    }
+
+    fn processes() -> Vec<Process> {
+        vec![
+            Process::Gbm {
+                drift: 0.0,
+                volatility: 0.02,
+            },
+            Process::OrnsteinUhlenbeck {
+                mean: 100.0,
+                reversion: 0.1,
+                volatility: 2.0,
+            },
+            Process::RegimeSwitching {
+                regimes: vec![
+                    Regime {
+                        drift: 0.001,
+                        volatility: 0.01,
+                    },
+                    Regime {
+                        drift: -0.002,
+                        volatility: 0.04,
+                    },
+                ],
+                switch_probability: 0.05,
+            },
+            Process::JumpDiffusion {
+                drift: 0.0,
+                volatility: 0.01,
+                jump_intensity: 0.05,
+                jump_mean: -0.02,
+                jump_volatility: 0.05,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_new() {
+        let gbm = |drift, volatility| Process::Gbm { drift, volatility };
+
+        assert!(SyntheticBars::new(gbm(0.0, -0.1), 100.0, 1).is_err());
+        assert!(SyntheticBars::new(gbm(f64::NAN, 0.1), 100.0, 1).is_err());
+        assert!(SyntheticBars::new(gbm(0.0, 0.1), 0.0, 1).is_err());
+        assert!(SyntheticBars::new(gbm(0.0, 0.1), 100.0, 1).is_ok());
+
+        let regimes = |regimes, switch_probability| Process::RegimeSwitching {
+            regimes,
+            switch_probability,
+        };
+        let regime = Regime {
+            drift: 0.0,
+            volatility: 0.1,
+        };
+        assert!(SyntheticBars::new(regimes(vec![], 0.1), 100.0, 1).is_err());
+        assert!(SyntheticBars::new(regimes(vec![regime], 1.1), 100.0, 1).is_err());
+        assert!(SyntheticBars::new(regimes(vec![regime], 1.0), 100.0, 1).is_ok());
+
+        let bars = SyntheticBars::new(gbm(0.0, 0.1), 100.0, 1).unwrap();
+        assert!(bars.clone().with_volume(-1.0).is_err());
+        assert!(bars.with_volume(0.0).is_ok());
+    }
+
+    #[test]
+    fn test_reproducible() {
+        for process in processes() {
+            let a: Vec<_> = SyntheticBars::new(process.clone(), 100.0, 7)
+                .unwrap()
+                .take(100)
+                .map(|bar| ohlcv(&bar))
+                .collect();
+            let b: Vec<_> = SyntheticBars::new(process.clone(), 100.0, 7)
+                .unwrap()
+                .take(100)
+                .map(|bar| ohlcv(&bar))
+                .collect();
+            let c: Vec<_> = SyntheticBars::new(process, 100.0, 8)
+                .unwrap()
+                .take(100)
+                .map(|bar| ohlcv(&bar))
+                .collect();
+
+            assert_eq!(a, b);
+            assert_ne!(a, c);
+        }
+    }
+
+    #[test]
+    fn test_bars_are_consistent() {
+        for process in processes() {
+            let mut prev_close = 100.0;
+            for bar in SyntheticBars::new(process, 100.0, 3).unwrap().take(500) {
+                assert_eq!(bar.open(), prev_close);
+                assert!(bar.low() <= bar.open().min(bar.close()));
+                assert!(bar.high() >= bar.open().max(bar.close()));
+                assert!(bar.low() >= 0.0);
+                assert!(bar.volume() > 0.0);
+                prev_close = bar.close();
+            }
+        }
+    }
+
+    #[test]
+    fn test_gbm_without_volatility() {
+        let process = Process::Gbm {
+            drift: 0.01,
+            volatility: 0.0,
+        };
+        let mut bars = SyntheticBars::new(process, 100.0, 1).unwrap();
+
+        assert_eq!(round(bars.next().unwrap().close()), 101.005);
+        assert_eq!(round(bars.next().unwrap().close()), 102.02);
+    }
+
+    #[test]
+    fn test_price_overflow_ends_stream() {
+        let process = Process::Gbm {
+            drift: 4e6,
+            volatility: 2000.0,
+        };
+        let bars = SyntheticBars::new(process, 100.0, 1).unwrap();
+
+        let count = bars.take(1000).count();
+        assert!(count < 1000);
+    }
+
+    #[test]
+    fn test_ornstein_uhlenbeck_without_volatility() {
+        let process = Process::OrnsteinUhlenbeck {
+            mean: 50.0,
+            reversion: 0.5,
+            volatility: 0.0,
+        };
+        let mut bars = SyntheticBars::new(process, 100.0, 1).unwrap();
+
+        let bar = bars.next().unwrap();
+        assert_eq!((bar.high(), round(bar.low())), (100.0, 79.309));
+    }
+
+    #[test]
+    fn test_regime_switching() {
+        let regime = |drift| Regime {
+            drift,
+            volatility: 0.0,
+        };
+        let process = Process::RegimeSwitching {
+            regimes: vec![regime(0.1), regime(-0.1)],
+            switch_probability: 1.0,
+        };
+        let mut bars = SyntheticBars::new(process, 100.0, 1).unwrap();
+
+        assert_eq!(bars.regime(), 0);
+        assert_eq!(round(bars.next().unwrap().close()), 110.517);
+        assert_eq!(bars.regime(), 1);
+        assert_eq!(round(bars.next().unwrap().close()), 100.0);
+        assert_eq!(bars.regime(), 0);
+    }
+
+    #[test]
+    fn test_with_volume() {
+        let process = Process::Gbm {
+            drift: 0.0,
+            volatility: 0.01,
+        };
+        let mut bars = SyntheticBars::new(process, 100.0, 1)
+            .unwrap()
+            .with_volume(0.0)
+            .unwrap();
+        assert_eq!(bars.next().unwrap().volume(), 0.0);
+    }
+
+    fn history(closes: &[f64]) -> Vec<Bar> {
+        closes
+            .iter()
+            .map(|&close| {
+                Bar::new()
+                    .open(close)
+                    .high(close + 1.0)
+                    .low(close - 1.0)
+                    .close(close)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_block_bootstrap_new() {
+        let bars = history(&[100.0, 110.0]);
+
+        assert!(BlockBootstrap::new(&bars[..1], 5.0, 1).is_err());
+        assert!(BlockBootstrap::new(&bars, 0.5, 1).is_err());
+        assert!(BlockBootstrap::new(&bars, 1.0, 1).is_ok());
+        assert!(BlockBootstrap::new(&[Bar::new(), Bar::new()], 1.0, 1).is_err());
+
+        let invalid = vec![
+            Bar::new().close(100),
+            Bar::new().high(90).low(80).close(100),
+        ];
+        assert!(BlockBootstrap::new(&invalid, 1.0, 1).is_err());
+    }
+
+    #[test]
+    fn test_block_bootstrap() {
+        let bars = history(&[100.0, 110.0, 99.0, 108.9, 98.01]);
+
+        let resample = |seed| {
+            BlockBootstrap::new(&bars, 2.0, seed)
+                .unwrap()
+                .take(50)
+                .map(|bar| ohlcv(&bar))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(resample(5), resample(5));
+        assert_ne!(resample(5), resample(6));
+
+        let mut prev_close = 98.01;
+        for bar in BlockBootstrap::new(&bars, 2.0, 5).unwrap().take(50) {
+            let change = round(bar.close() / prev_close);
+            assert!(change == 1.1 || change == 0.9);
+            assert!(bar.low() <= bar.close() && bar.close() <= bar.high());
+            prev_close = bar.close();
+        }
+    }
+
+    #[test]
+    fn test_block_bootstrap_keeps_blocks() {
+        // alternating up and down moves
+        let bars = history(&[100.0, 110.0, 99.0, 108.9, 98.01]);
+        let bootstrap = BlockBootstrap::new(&bars, 1e9, 3).unwrap();
+
+        let mut prev_close = 98.01;
+        let mut prev_up = None;
+        for bar in bootstrap.take(8) {
+            let up = bar.close() > prev_close;
+            if let Some(prev_up) = prev_up {
+                assert_ne!(up, prev_up);
+            }
+            prev_up = Some(up);
+            prev_close = bar.close();
+        }
+    }
+}
@@ -20,10 +20,10 @@ impl Bar {
         }
     }
 
-    //pub fn open<T: Into<f64>>(mut self, val :T ) -> Self {
-    //    self.open = val.into();
-    //    self
-    //}
+    pub fn open<T: Into<f64>>(mut self, val: T) -> Self {
+        self.open = val.into();
+        self
+    }
 
     pub fn high<T: Into<f64>>(mut self, val: T) -> Self {
         self.high = val.into();
@@ -100,7 +100,7 @@ macro_rules! test_indicator {
             assert_eq!(indicator.nexta(12.3), first_output);
 
             // ensure Display is implemented
-            format!("{}", indicator);
+            let _ = format!("{}", indicator);
         }
     };
 }
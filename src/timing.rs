@@ -0,0 +1,315 @@
+//! Latency instrumentation of indicators.
+//!
+//! Available with the `timing` feature.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::{Nexta, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Every power of 2 is split into 8 linear sub-buckets, so a recorded value is within 12.5% of the
+// lower bound of its bucket. Values below 8 ns have a bucket each.
+const SUB_BUCKETS: u64 = 8;
+const SUB_BUCKET_BITS: u32 = 3;
+const BUCKETS: usize = (SUB_BUCKETS + (64 - SUB_BUCKET_BITS as u64) * SUB_BUCKETS) as usize;
+
+/// Wrapper that measures how long every call to `nexta` of the wrapped indicator takes.
+///
+/// The measured latencies are recorded in a [LatencyHistogram](struct.LatencyHistogram.html),
+/// which provides percentile statistics, e.g. to check that the processing of a bar fits into a
+/// time budget. Resetting the wrapper resets the wrapped indicator only, the statistics are
+/// cleared with [reset_stats](struct.Timed.html#method.reset_stats).
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use tars::indicators::ExponentialMovingAverage;
+/// use tars::timing::Timed;
+/// use tars::Nexta;
+///
+/// let mut ema = Timed::new(ExponentialMovingAverage::new(3).unwrap());
+///
+/// assert_eq!(ema.nexta(2.0), 2.0);
+/// assert_eq!(ema.nexta(5.0), 3.5);
+///
+/// let stats = ema.stats();
+/// assert_eq!(stats.count(), 2);
+/// assert!(stats.percentile(99.0).unwrap() < Duration::from_secs(1));
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Timed<I> {
+    inner: I,
+    stats: LatencyHistogram,
+}
+
+impl<I> Timed<I> {
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            stats: LatencyHistogram::new(),
+        }
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+
+    /// Consumes the wrapper, returning the wrapped indicator.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+
+    /// Returns the recorded latencies.
+    pub fn stats(&self) -> &LatencyHistogram {
+        &self.stats
+    }
+
+    /// Clears the recorded latencies.
+    pub fn reset_stats(&mut self) {
+        self.stats.clear();
+    }
+}
+
+impl<T, I: Nexta<T>> Nexta<T> for Timed<I> {
+    type Output = I::Output;
+
+    fn nexta(&mut self, input: T) -> Self::Output {
+        let start = Instant::now();
+        let output = self.inner.nexta(input);
+        self.stats.record(start.elapsed());
+        output
+    }
+}
+
+impl<I: Period> Period for Timed<I> {
+    fn period(&self) -> usize {
+        self.inner.period()
+    }
+}
+
+impl<I: Reset> Reset for Timed<I> {
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl<I: Default> Default for Timed<I> {
+    fn default() -> Self {
+        Self::new(I::default())
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Timed<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TIMED({})", self.inner)
+    }
+}
+
+/// Histogram of latencies with logarithmic buckets.
+///
+/// Count, minimum, maximum and mean are exact, percentiles are approximated by the lower bound
+/// of the bucket and are within 12.5% of the actual value.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LatencyHistogram {
+    buckets: Box<[u64]>,
+    count: u64,
+    total_nanos: u128,
+    min_nanos: u64,
+    max_nanos: u64,
+}
+
+impl LatencyHistogram {
+    pub fn new() -> Self {
+        Self {
+            buckets: vec![0; BUCKETS].into_boxed_slice(),
+            count: 0,
+            total_nanos: 0,
+            min_nanos: u64::MAX,
+            max_nanos: 0,
+        }
+    }
+
+    /// Records a single latency.
+    pub fn record(&mut self, latency: Duration) {
+        let nanos = u64::try_from(latency.as_nanos()).unwrap_or(u64::MAX);
+        self.buckets[bucket_index(nanos)] += 1;
+        self.count += 1;
+        self.total_nanos += u128::from(nanos);
+        self.min_nanos = self.min_nanos.min(nanos);
+        self.max_nanos = self.max_nanos.max(nanos);
+    }
+
+    /// Removes all the recorded latencies.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the number of recorded latencies.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn min(&self) -> Option<Duration> {
+        self.non_empty(self.min_nanos)
+    }
+
+    pub fn max(&self) -> Option<Duration> {
+        self.non_empty(self.max_nanos)
+    }
+
+    pub fn mean(&self) -> Option<Duration> {
+        let mean = self.total_nanos / u128::from(self.count.max(1));
+        self.non_empty(mean as u64)
+    }
+
+    /// Returns the latency below or at which the given percentage (0..=100) of the recorded
+    /// latencies are, or `None` if nothing has been recorded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if _percentile_ is not within 0..=100.
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        assert!(
+            (0.0..=100.0).contains(&percentile),
+            "percentile must be within 0..=100"
+        );
+        if self.count == 0 {
+            return None;
+        }
+
+        let rank = ((percentile / 100.0 * self.count as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (index, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                let nanos = bucket_lower_bound(index).clamp(self.min_nanos, self.max_nanos);
+                return Some(Duration::from_nanos(nanos));
+            }
+        }
+        self.max()
+    }
+
+    fn non_empty(&self, nanos: u64) -> Option<Duration> {
+        match self.count {
+            0 => None,
+            _ => Some(Duration::from_nanos(nanos)),
+        }
+    }
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn bucket_index(nanos: u64) -> usize {
+    if nanos < SUB_BUCKETS {
+        return nanos as usize;
+    }
+    let exponent = 63 - nanos.leading_zeros();
+    let shift = exponent - SUB_BUCKET_BITS;
+    let sub_bucket = (nanos >> shift) & (SUB_BUCKETS - 1);
+    (SUB_BUCKETS + u64::from(shift) * SUB_BUCKETS + sub_bucket) as usize
+}
+
+fn bucket_lower_bound(index: usize) -> u64 {
+    let index = index as u64;
+    if index < SUB_BUCKETS {
+        return index;
+    }
+    let shift = (index - SUB_BUCKETS) / SUB_BUCKETS;
+    let sub_bucket = (index - SUB_BUCKETS) % SUB_BUCKETS;
+    (SUB_BUCKETS + sub_bucket) << shift
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage as Sma;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_bucket_index() {
+        for nanos in [0, 1, 7, 8, 9, 15, 16, 17, 100, 1_000, 123_456_789, u64::MAX] {
+            let index = bucket_index(nanos);
+            assert!(index < BUCKETS);
+            assert!(bucket_lower_bound(index) <= nanos);
+            assert!(nanos - bucket_lower_bound(index) <= nanos / 8);
+        }
+        assert_eq!(bucket_index(7), 7);
+        assert_eq!(bucket_index(8), 8);
+        assert_eq!(bucket_index(16), 16);
+        assert_eq!(bucket_index(17), 16);
+        assert_eq!(bucket_index(18), 17);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let mut histogram = LatencyHistogram::new();
+        assert_eq!(histogram.percentile(50.0), None);
+        assert_eq!(histogram.mean(), None);
+
+        for micros in 1..=100 {
+            histogram.record(Duration::from_micros(micros));
+        }
+
+        assert_eq!(histogram.count(), 100);
+        assert_eq!(histogram.min(), Some(Duration::from_micros(1)));
+        assert_eq!(histogram.max(), Some(Duration::from_micros(100)));
+        assert_eq!(histogram.mean(), Some(Duration::from_nanos(50_500)));
+
+        assert_eq!(histogram.percentile(0.0), Some(Duration::from_micros(1)));
+        let median = histogram.percentile(50.0).unwrap().as_nanos() as f64;
+        assert!((43_750.0..=50_000.0).contains(&median));
+        let p99 = histogram.percentile(99.0).unwrap().as_nanos() as f64;
+        assert!((86_625.0..=99_000.0).contains(&p99));
+
+        histogram.clear();
+        assert_eq!(histogram.count(), 0);
+        assert_eq!(histogram.max(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_percentile_out_of_range() {
+        LatencyHistogram::new().percentile(101.0);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sma = Timed::new(Sma::new(2).unwrap());
+
+        assert_eq!(sma.nexta(4.0), 4.0);
+        assert_eq!(sma.nexta(&Bar::new().close(6)), 5.0);
+        assert_eq!(sma.stats().count(), 2);
+        assert!(sma.stats().min() <= sma.stats().max());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sma = Timed::new(Sma::new(2).unwrap());
+
+        sma.nexta(4.0);
+        sma.reset();
+        assert_eq!(sma.nexta(6.0), 6.0);
+        assert_eq!(sma.stats().count(), 2);
+
+        sma.reset_stats();
+        assert_eq!(sma.stats().count(), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        let sma = Timed::new(Sma::new(9).unwrap());
+        assert_eq!(format!("{}", sma), "TIMED(SMA(9))");
+    }
+}